@@ -2,8 +2,10 @@ use crate::args::Args;
 use crate::benchmark::BenchmarkKind;
 use crate::benchmark_result::BenchmarkResult;
 use crate::client_factory::ClientFactory;
+use sdk::compression::CompressionAlgorithm;
 use sdk::consumer_type::ConsumerType;
 use sdk::error::Error;
+use sdk::identifier::Identifier;
 use sdk::messages::poll_messages::{Format, Kind, PollMessages};
 use std::sync::Arc;
 use std::time::Duration;
@@ -29,14 +31,15 @@ pub async fn run(
     let mut command = PollMessages {
         consumer_type: ConsumerType::Consumer,
         consumer_id,
-        stream_id,
-        topic_id,
+        stream_id: Identifier::numeric(stream_id),
+        topic_id: Identifier::numeric(topic_id),
         partition_id,
         kind: Kind::Offset,
         value: 0,
         count: args.messages_per_batch,
         auto_commit: false,
         format: Format::Binary,
+        compression: CompressionAlgorithm::None,
     };
 
     let mut latencies: Vec<Duration> = Vec::with_capacity(args.message_batches as usize);
@@ -2,7 +2,9 @@ use crate::args::Args;
 use crate::benchmark::BenchmarkKind;
 use crate::benchmark_result::BenchmarkResult;
 use crate::client_factory::ClientFactory;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
+use sdk::identifier::Identifier;
 use sdk::messages::send_messages::{KeyKind, Message, SendMessages};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -29,12 +31,14 @@ pub async fn run(
     }
 
     let command = SendMessages {
-        stream_id,
-        topic_id,
+        stream_id: Identifier::numeric(stream_id),
+        topic_id: Identifier::numeric(topic_id),
         key_kind: KeyKind::PartitionId,
         key_value: partition_id,
         messages_count: args.messages_per_batch,
+        compression: CompressionAlgorithm::None,
         messages,
+        batch: None,
     };
 
     info!(
@@ -1,9 +1,11 @@
 use crate::args::Args;
 use crate::client_factory::ClientFactory;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
+use sdk::identifier::Identifier;
 use sdk::streams::create_stream::CreateStream;
 use sdk::streams::get_streams::GetStreams;
-use sdk::topics::create_topic::CreateTopic;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, CreateTopic, TopicConfigOverrides};
 use std::sync::Arc;
 use tracing::info;
 
@@ -22,7 +24,10 @@ pub async fn init_streams(
             info!("Creating the test stream {}", stream_id);
             let name = format!("stream {}", stream_id);
             client
-                .create_stream(&CreateStream { stream_id, name })
+                .create_stream(&CreateStream {
+                    stream_id: Some(stream_id),
+                    name,
+                })
                 .await?;
 
             info!(
@@ -32,9 +37,16 @@ pub async fn init_streams(
             let name = format!("topic {}", topic_id);
             client
                 .create_topic(&CreateTopic {
-                    stream_id,
-                    topic_id,
+                    stream_id: Identifier::numeric(stream_id),
+                    topic_id: Some(topic_id),
                     partitions_count,
+                    message_expiry: None,
+                    max_topic_size_bytes: None,
+                    cleanup_policy: CleanupPolicy::Delete,
+                    compression: CompressionAlgorithm::None,
+                    deduplication: None,
+                    checksum_policy: ChecksumPolicy::default(),
+                    overrides: TopicConfigOverrides::default(),
                     name,
                 })
                 .await?;
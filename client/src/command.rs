@@ -1,4 +1,4 @@
-use crate::{consumer_groups, messages, offsets, streams, system, topics};
+use crate::{consumer_groups, messages, offsets, partitions, streams, system, topics};
 use sdk::client::Client;
 use sdk::client_error::ClientError;
 use sdk::command::Command;
@@ -16,16 +16,22 @@ pub async fn handle(input: &str, client: &dyn Client) -> Result<(), ClientError>
         Command::GetClients(payload) => system::get_clients(&payload, client).await,
         Command::SendMessages(payload) => messages::send_messages(&payload, client).await,
         Command::PollMessages(payload) => messages::poll_messages(&payload, client).await,
+        Command::DeleteMessagesBefore(payload) => {
+            messages::delete_messages_before(&payload, client).await
+        }
         Command::StoreOffset(payload) => offsets::store_offset(&payload, client).await,
         Command::GetOffset(payload) => offsets::get_offset(&payload, client).await,
         Command::GetStream(payload) => streams::get_stream(&payload, client).await,
         Command::GetStreams(payload) => streams::get_streams(&payload, client).await,
         Command::CreateStream(payload) => streams::create_stream(&payload, client).await,
         Command::DeleteStream(payload) => streams::delete_stream(&payload, client).await,
+        Command::UpdateStream(payload) => streams::update_stream(&payload, client).await,
         Command::GetTopic(payload) => topics::get_topic(&payload, client).await,
         Command::GetTopics(payload) => topics::get_topics(&payload, client).await,
         Command::CreateTopic(payload) => topics::create_topic(&payload, client).await,
         Command::DeleteTopic(payload) => topics::delete_topic(&payload, client).await,
+        Command::PurgeTopic(payload) => topics::purge_topic(&payload, client).await,
+        Command::UpdateTopic(payload) => topics::update_topic(&payload, client).await,
         Command::GetGroup(payload) => consumer_groups::get_consumer_group(&payload, client).await,
         Command::GetGroups(payload) => consumer_groups::get_consumer_groups(&payload, client).await,
         Command::CreateGroup(payload) => {
@@ -38,5 +44,9 @@ pub async fn handle(input: &str, client: &dyn Client) -> Result<(), ClientError>
         Command::LeaveGroup(payload) => {
             consumer_groups::leave_consumer_group(&payload, client).await
         }
+        Command::RebuildIndexes(payload) => partitions::rebuild_indexes(&payload, client).await,
+        Command::PurgePartition(payload) => partitions::purge_partition(&payload, client).await,
+        Command::CreatePartitions(payload) => partitions::create_partitions(&payload, client).await,
+        Command::DeletePartitions(payload) => partitions::delete_partitions(&payload, client).await,
     }
 }
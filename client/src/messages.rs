@@ -1,5 +1,6 @@
 use sdk::client::Client;
 use sdk::client_error::ClientError;
+use sdk::messages::delete_messages_before::DeleteMessagesBefore;
 use sdk::messages::poll_messages::{Format, PollMessages};
 use sdk::messages::send_messages::SendMessages;
 use std::str::from_utf8;
@@ -10,6 +11,14 @@ pub async fn send_messages(command: &SendMessages, client: &dyn Client) -> Resul
     Ok(())
 }
 
+pub async fn delete_messages_before(
+    command: &DeleteMessagesBefore,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.delete_messages_before(command).await?;
+    Ok(())
+}
+
 pub async fn poll_messages(command: &PollMessages, client: &dyn Client) -> Result<(), ClientError> {
     let format = command.format;
     let messages = client.poll_messages(command).await?;
@@ -0,0 +1,48 @@
+use sdk::client::Client;
+use sdk::client_error::ClientError;
+use sdk::partitions::create_partitions::CreatePartitions;
+use sdk::partitions::delete_partitions::DeletePartitions;
+use sdk::partitions::purge_partition::PurgePartition;
+use sdk::partitions::rebuild_indexes::RebuildIndexes;
+use tracing::info;
+
+pub async fn rebuild_indexes(
+    command: &RebuildIndexes,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.rebuild_indexes(command).await?;
+    Ok(())
+}
+
+pub async fn purge_partition(
+    command: &PurgePartition,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.purge_partition(command).await?;
+    Ok(())
+}
+
+pub async fn create_partitions(
+    command: &CreatePartitions,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let created_partitions = client.create_partitions(command).await?;
+    info!(
+        "Partitions count: {} -> {}, the messages sent with the entity ID key are now appended to the partition with ID: entity_id % {} (or {} if equal to 0), instead of: entity_id % {} (or {} if equal to 0).",
+        created_partitions.previous_partitions_count,
+        created_partitions.partitions_count,
+        created_partitions.partitions_count,
+        created_partitions.partitions_count,
+        created_partitions.previous_partitions_count,
+        created_partitions.previous_partitions_count
+    );
+    Ok(())
+}
+
+pub async fn delete_partitions(
+    command: &DeletePartitions,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.delete_partitions(command).await?;
+    Ok(())
+}
@@ -4,6 +4,7 @@ use sdk::streams::create_stream::CreateStream;
 use sdk::streams::delete_stream::DeleteStream;
 use sdk::streams::get_stream::GetStream;
 use sdk::streams::get_streams::GetStreams;
+use sdk::streams::update_stream::UpdateStream;
 use tracing::info;
 
 pub async fn get_stream(command: &GetStream, client: &dyn Client) -> Result<(), ClientError> {
@@ -32,3 +33,8 @@ pub async fn delete_stream(command: &DeleteStream, client: &dyn Client) -> Resul
     client.delete_stream(command).await?;
     Ok(())
 }
+
+pub async fn update_stream(command: &UpdateStream, client: &dyn Client) -> Result<(), ClientError> {
+    client.update_stream(command).await?;
+    Ok(())
+}
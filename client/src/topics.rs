@@ -4,6 +4,8 @@ use sdk::topics::create_topic::CreateTopic;
 use sdk::topics::delete_topic::DeleteTopic;
 use sdk::topics::get_topic::GetTopic;
 use sdk::topics::get_topics::GetTopics;
+use sdk::topics::purge_topic::PurgeTopic;
+use sdk::topics::update_topic::UpdateTopic;
 use tracing::info;
 
 pub async fn get_topic(command: &GetTopic, client: &dyn Client) -> Result<(), ClientError> {
@@ -32,3 +34,13 @@ pub async fn delete_topic(command: &DeleteTopic, client: &dyn Client) -> Result<
     client.delete_topic(command).await?;
     Ok(())
 }
+
+pub async fn purge_topic(command: &PurgeTopic, client: &dyn Client) -> Result<(), ClientError> {
+    client.purge_topic(command).await?;
+    Ok(())
+}
+
+pub async fn update_topic(command: &UpdateTopic, client: &dyn Client) -> Result<(), ClientError> {
+    client.update_topic(command).await?;
+    Ok(())
+}
@@ -5,7 +5,9 @@ use samples::shared::messages::*;
 use sdk::client::Client;
 use sdk::client_provider;
 use sdk::client_provider::ClientProviderConfig;
+use sdk::compression::CompressionAlgorithm;
 use sdk::consumer_type::ConsumerType;
+use sdk::identifier::Identifier;
 use sdk::messages::poll_messages::{Format, Kind, PollMessages};
 use sdk::models::message::Message;
 use sdk::streams::get_stream::GetStream;
@@ -38,14 +40,15 @@ pub async fn consume_messages(args: &Args, client: &dyn Client) -> Result<(), Bo
             .poll_messages(&PollMessages {
                 consumer_type: ConsumerType::from_code(args.consumer_type)?,
                 consumer_id: args.consumer_id,
-                stream_id: args.stream_id,
-                topic_id: args.topic_id,
+                stream_id: Identifier::numeric(args.stream_id),
+                topic_id: Identifier::numeric(args.topic_id),
                 partition_id: args.partition_id,
                 kind: Kind::Next,
                 value: 0,
                 count: args.messages_per_batch,
                 auto_commit: true,
                 format: Format::None,
+                compression: CompressionAlgorithm::None,
             })
             .await?;
         if messages.is_empty() {
@@ -93,7 +96,11 @@ async fn validate_system(stream_id: u32, topic_id: u32, partition_id: u32, clien
     let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
     loop {
         info!("Validating if stream: {} exists..", stream_id);
-        let stream = client.get_stream(&GetStream { stream_id }).await;
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: Identifier::numeric(stream_id),
+            })
+            .await;
         if stream.is_ok() {
             info!("Stream: {} was found.", stream_id);
             break;
@@ -104,8 +111,8 @@ async fn validate_system(stream_id: u32, topic_id: u32, partition_id: u32, clien
         info!("Validating if topic: {} exists..", topic_id);
         let topic = client
             .get_topic(&GetTopic {
-                stream_id,
-                topic_id,
+                stream_id: Identifier::numeric(stream_id),
+                topic_id: Identifier::numeric(topic_id),
             })
             .await;
         if topic.is_err() {
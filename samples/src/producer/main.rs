@@ -7,10 +7,12 @@ use samples::shared::args::Args;
 use sdk::client::Client;
 use sdk::client_provider;
 use sdk::client_provider::ClientProviderConfig;
+use sdk::compression::CompressionAlgorithm;
+use sdk::identifier::Identifier;
 use sdk::messages::send_messages::{KeyKind, Message, SendMessages};
 use sdk::streams::create_stream::CreateStream;
 use sdk::streams::get_stream::GetStream;
-use sdk::topics::create_topic::CreateTopic;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, CreateTopic, TopicConfigOverrides};
 use std::error::Error;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -29,22 +31,29 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let client = client.as_ref();
     let stream = client
         .get_stream(&GetStream {
-            stream_id: args.stream_id,
+            stream_id: Identifier::numeric(args.stream_id),
         })
         .await;
     if stream.is_err() {
         info!("Stream does not exist, creating...");
         client
             .create_stream(&CreateStream {
-                stream_id: args.stream_id,
+                stream_id: Some(args.stream_id),
                 name: "sample".to_string(),
             })
             .await?;
         client
             .create_topic(&CreateTopic {
-                stream_id: args.stream_id,
-                topic_id: args.topic_id,
+                stream_id: Identifier::numeric(args.stream_id),
+                topic_id: Some(args.topic_id),
                 partitions_count: args.partition_id,
+                message_expiry: None,
+                max_topic_size_bytes: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                compression: CompressionAlgorithm::None,
+                deduplication: None,
+                checksum_policy: ChecksumPolicy::default(),
+                overrides: TopicConfigOverrides::default(),
                 name: "orders".to_string(),
             })
             .await?;
@@ -74,12 +83,14 @@ pub async fn produce_messages(args: &Args, client: &dyn Client) -> Result<(), Bo
         }
         client
             .send_messages(&SendMessages {
-                stream_id: args.stream_id,
-                topic_id: args.topic_id,
+                stream_id: Identifier::numeric(args.stream_id),
+                topic_id: Identifier::numeric(args.topic_id),
                 key_kind: KeyKind::PartitionId,
                 key_value: args.partition_id,
                 messages_count: messages.len() as u32,
+                compression: CompressionAlgorithm::None,
                 messages,
+                batch: None,
             })
             .await?;
         info!("Sent messages: {:#?}", serializable_messages);
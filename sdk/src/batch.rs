@@ -0,0 +1,467 @@
+use crate::compression::CompressionAlgorithm;
+use crate::error::Error;
+use bytes::Bytes;
+
+/*
+    The messages are stored and transferred in the batches, each one consisting of the header followed by the records.
+
+    | BASE OFFSET |  LENGTH  | FIRST TIMESTAMP | MAX TIMESTAMP |   CRC   | ATTRIBUTES | LAST OFFSET DELTA | RECORDS COUNT | RECORDS |
+    |   8 bytes   |  4 bytes |     8 bytes     |    8 bytes    | 4 bytes |   1 byte   |      4 bytes      |    4 bytes    |   ...   |
+
+    LENGTH:
+        - The size of the whole batch, including the header.
+
+    CRC:
+        - Covers everything that follows it, thus the base offset and the timestamps can be assigned by the server
+          without recalculating the CRC.
+
+    ATTRIBUTES:
+        - The lowest 3 bits store the compression codec of the records, which are compressed together as a single block.
+          The CRC covers the compressed records, so the batch can be validated without decompressing it.
+
+    Each record stores its offset and timestamp as the deltas of the base offset and the first timestamp of the batch.
+
+    | OFFSET DELTA | TIMESTAMP DELTA |    ID    |  LENGTH  | PAYLOAD |
+    |    4 bytes   |     4 bytes     | 16 bytes |  4 bytes |   ...   |
+*/
+
+pub const BATCH_HEADER_SIZE: u32 = 41;
+pub const RECORD_HEADER_SIZE: u32 = 28;
+const COMPRESSION_ATTRIBUTES_MASK: u8 = 0b111;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BatchHeader {
+    pub base_offset: u64,
+    pub length: u32,
+    pub first_timestamp: u64,
+    pub max_timestamp: u64,
+    pub crc: u32,
+    pub attributes: u8,
+    pub last_offset_delta: u32,
+    pub records_count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub offset_delta: u32,
+    pub timestamp_delta: u32,
+    pub id: u128,
+    pub payload: Bytes,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageBatch {
+    pub header: BatchHeader,
+    pub records: Vec<Record>,
+    compressed_records: Option<Bytes>,
+}
+
+impl BatchHeader {
+    pub fn get_last_offset(&self) -> u64 {
+        self.base_offset + self.last_offset_delta as u64
+    }
+
+    pub fn get_records_size_bytes(&self) -> u32 {
+        self.length - BATCH_HEADER_SIZE
+    }
+
+    pub fn get_compression(&self) -> Result<CompressionAlgorithm, Error> {
+        CompressionAlgorithm::from_code(self.attributes & COMPRESSION_ATTRIBUTES_MASK)
+    }
+
+    pub fn extend(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.base_offset.to_le_bytes());
+        bytes.extend(self.length.to_le_bytes());
+        bytes.extend(self.first_timestamp.to_le_bytes());
+        bytes.extend(self.max_timestamp.to_le_bytes());
+        bytes.extend(self.crc.to_le_bytes());
+        bytes.extend(self.attributes.to_le_bytes());
+        bytes.extend(self.last_offset_delta.to_le_bytes());
+        bytes.extend(self.records_count.to_le_bytes());
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < BATCH_HEADER_SIZE as usize {
+            return Err(Error::InvalidMessageBatch);
+        }
+
+        let header = BatchHeader {
+            base_offset: u64::from_le_bytes(bytes[..8].try_into()?),
+            length: u32::from_le_bytes(bytes[8..12].try_into()?),
+            first_timestamp: u64::from_le_bytes(bytes[12..20].try_into()?),
+            max_timestamp: u64::from_le_bytes(bytes[20..28].try_into()?),
+            crc: u32::from_le_bytes(bytes[28..32].try_into()?),
+            attributes: bytes[32],
+            last_offset_delta: u32::from_le_bytes(bytes[33..37].try_into()?),
+            records_count: u32::from_le_bytes(bytes[37..41].try_into()?),
+        };
+        if header.length < BATCH_HEADER_SIZE {
+            return Err(Error::InvalidMessageBatch);
+        }
+
+        Ok(header)
+    }
+
+    pub fn calculate_crc<'a>(&self, records: impl IntoIterator<Item = &'a [u8]>) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&[self.attributes]);
+        hasher.update(&self.last_offset_delta.to_le_bytes());
+        hasher.update(&self.records_count.to_le_bytes());
+        for bytes in records {
+            hasher.update(bytes);
+        }
+        hasher.finalize()
+    }
+}
+
+impl Record {
+    pub fn get_size_bytes(&self) -> u32 {
+        RECORD_HEADER_SIZE + self.payload.len() as u32
+    }
+
+    pub fn extend_header(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.offset_delta.to_le_bytes());
+        bytes.extend(self.timestamp_delta.to_le_bytes());
+        bytes.extend(self.id.to_le_bytes());
+        bytes.extend((self.payload.len() as u32).to_le_bytes());
+    }
+}
+
+impl MessageBatch {
+    pub fn new(
+        base_offset: u64,
+        first_timestamp: u64,
+        max_timestamp: u64,
+        attributes: u8,
+        records: Vec<Record>,
+    ) -> Self {
+        let mut header = BatchHeader {
+            base_offset,
+            length: BATCH_HEADER_SIZE + records.iter().map(Record::get_size_bytes).sum::<u32>(),
+            first_timestamp,
+            max_timestamp,
+            crc: 0,
+            attributes,
+            last_offset_delta: records.last().map_or(0, |record| record.offset_delta),
+            records_count: records.len() as u32,
+        };
+        let records_headers = Self::get_records_headers(&records);
+        header.crc = header.calculate_crc(Self::get_records_parts(&records_headers, &records));
+        MessageBatch {
+            header,
+            records,
+            compressed_records: None,
+        }
+    }
+
+    pub fn compress(self, compression: CompressionAlgorithm) -> Result<Self, Error> {
+        let batch = self.decompress();
+        if compression == CompressionAlgorithm::None {
+            return Ok(batch);
+        }
+
+        let mut records_bytes = Vec::new();
+        for record in &batch.records {
+            record.extend_header(&mut records_bytes);
+            records_bytes.extend(&record.payload);
+        }
+        let compressed_records = Bytes::from(compression.compress(&records_bytes)?);
+        let mut header = batch.header;
+        header.attributes =
+            (header.attributes & !COMPRESSION_ATTRIBUTES_MASK) | compression.as_code();
+        header.length = BATCH_HEADER_SIZE + compressed_records.len() as u32;
+        header.crc = header.calculate_crc([&compressed_records[..]]);
+        Ok(MessageBatch {
+            header,
+            records: batch.records,
+            compressed_records: Some(compressed_records),
+        })
+    }
+
+    pub fn decompress(self) -> Self {
+        if self.compressed_records.is_none() {
+            return self;
+        }
+
+        MessageBatch::new(
+            self.header.base_offset,
+            self.header.first_timestamp,
+            self.header.max_timestamp,
+            self.header.attributes & !COMPRESSION_ATTRIBUTES_MASK,
+            self.records,
+        )
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.compressed_records.is_some()
+    }
+
+    pub fn get_size_bytes(&self) -> u32 {
+        self.header.length
+    }
+
+    pub fn extend(&self, bytes: &mut Vec<u8>) {
+        self.header.extend(bytes);
+        if let Some(compressed_records) = &self.compressed_records {
+            bytes.extend(compressed_records);
+            return;
+        }
+
+        for record in &self.records {
+            record.extend_header(bytes);
+            bytes.extend(&record.payload);
+        }
+    }
+
+    pub fn get_buffers(&self) -> Vec<Bytes> {
+        let mut header_bytes = Vec::with_capacity(BATCH_HEADER_SIZE as usize);
+        self.header.extend(&mut header_bytes);
+        if let Some(compressed_records) = &self.compressed_records {
+            return vec![Bytes::from(header_bytes), compressed_records.clone()];
+        }
+
+        let records_headers = Bytes::from(Self::get_records_headers(&self.records));
+        let mut buffers = Vec::with_capacity(1 + 2 * self.records.len());
+        buffers.push(Bytes::from(header_bytes));
+        for (index, record) in self.records.iter().enumerate() {
+            let position = index * RECORD_HEADER_SIZE as usize;
+            buffers.push(records_headers.slice(position..position + RECORD_HEADER_SIZE as usize));
+            buffers.push(record.payload.clone());
+        }
+        buffers
+    }
+
+    // The CRC isn't validated, so that the caller can decide how to handle the corrupted batch.
+    pub fn from_bytes(bytes: &Bytes) -> Result<Self, Error> {
+        Self::from_bytes_with_limit(bytes, usize::MAX)
+    }
+
+    pub fn from_bytes_with_limit(
+        bytes: &Bytes,
+        max_records_size_bytes: usize,
+    ) -> Result<Self, Error> {
+        let header = BatchHeader::from_bytes(bytes)?;
+        if header.length as usize > bytes.len() {
+            return Err(Error::InvalidMessageBatch);
+        }
+
+        let records = bytes.slice(BATCH_HEADER_SIZE as usize..header.length as usize);
+        let compression = header.get_compression()?;
+        if compression == CompressionAlgorithm::None {
+            return Ok(MessageBatch {
+                header,
+                records: Self::decode_records(&header, &records)?,
+                compressed_records: None,
+            });
+        }
+
+        // The corrupted records might not be decompressed at all, which is reported the same way as the invalid CRC.
+        let decompressed_records =
+            match compression.decompress_with_limit(&records, max_records_size_bytes) {
+                Ok(decompressed_records) => Bytes::from(decompressed_records),
+                Err(Error::TooBigMessagePayload) => return Err(Error::TooBigMessagePayload),
+                Err(_) => {
+                    return Err(Error::InvalidBatchChecksum(
+                        header.calculate_crc([&records[..]]),
+                        header.crc,
+                        header.base_offset,
+                    ))
+                }
+            };
+        Ok(MessageBatch {
+            header,
+            records: Self::decode_records(&header, &decompressed_records)?,
+            compressed_records: Some(records),
+        })
+    }
+
+    fn decode_records(header: &BatchHeader, records: &Bytes) -> Result<Vec<Record>, Error> {
+        let mut position = 0;
+        let mut decoded_records = Vec::with_capacity(header.records_count.min(1024) as usize);
+        while position < records.len() {
+            if position + RECORD_HEADER_SIZE as usize > records.len() {
+                return Err(Error::InvalidMessageBatch);
+            }
+
+            let offset_delta = u32::from_le_bytes(records[position..position + 4].try_into()?);
+            let timestamp_delta =
+                u32::from_le_bytes(records[position + 4..position + 8].try_into()?);
+            let id = u128::from_le_bytes(records[position + 8..position + 24].try_into()?);
+            let length = u32::from_le_bytes(records[position + 24..position + 28].try_into()?);
+            let payload_position = position + RECORD_HEADER_SIZE as usize;
+            position = payload_position + length as usize;
+            if position > records.len() {
+                return Err(Error::InvalidMessageBatch);
+            }
+
+            decoded_records.push(Record {
+                offset_delta,
+                timestamp_delta,
+                id,
+                payload: records.slice(payload_position..position),
+            });
+        }
+
+        if decoded_records.len() != header.records_count as usize {
+            return Err(Error::InvalidMessageBatch);
+        }
+
+        Ok(decoded_records)
+    }
+
+    pub fn read_all(bytes: &Bytes) -> Result<Vec<Self>, Error> {
+        let mut batches = Vec::new();
+        let mut position = 0;
+        while position < bytes.len() {
+            let batch = Self::from_bytes(&bytes.slice(position..))?;
+            batch.validate()?;
+            position += batch.header.length as usize;
+            batches.push(batch);
+        }
+
+        Ok(batches)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        let crc = match &self.compressed_records {
+            Some(compressed_records) => self.header.calculate_crc([&compressed_records[..]]),
+            None => {
+                let records_headers = Self::get_records_headers(&self.records);
+                self.header
+                    .calculate_crc(Self::get_records_parts(&records_headers, &self.records))
+            }
+        };
+        if crc != self.header.crc {
+            return Err(Error::InvalidBatchChecksum(
+                crc,
+                self.header.crc,
+                self.header.base_offset,
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn get_records_headers(records: &[Record]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(records.len() * RECORD_HEADER_SIZE as usize);
+        for record in records {
+            record.extend_header(&mut bytes);
+        }
+        bytes
+    }
+
+    fn get_records_parts<'a>(
+        records_headers: &'a [u8],
+        records: &'a [Record],
+    ) -> impl Iterator<Item = &'a [u8]> {
+        records_headers
+            .chunks_exact(RECORD_HEADER_SIZE as usize)
+            .zip(records)
+            .flat_map(|(header, record)| [header, &record.payload[..]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_decoded_from_the_encoded_bytes() {
+        let batch = create_batch();
+        let mut bytes = Vec::new();
+        batch.extend(&mut bytes);
+
+        assert_eq!(bytes.len(), batch.get_size_bytes() as usize);
+        assert_eq!(bytes, batch.get_buffers().concat());
+        let decoded_batch = MessageBatch::from_bytes(&Bytes::from(bytes)).unwrap();
+        assert!(decoded_batch.validate().is_ok());
+        assert_eq!(decoded_batch, batch);
+        assert_eq!(decoded_batch.header.get_last_offset(), 12);
+    }
+
+    #[test]
+    fn should_keep_the_crc_when_the_offsets_and_timestamps_are_assigned() {
+        let batch = create_batch();
+        let mut bytes = Vec::new();
+        batch.extend(&mut bytes);
+        bytes[..8].copy_from_slice(&100u64.to_le_bytes());
+        bytes[12..20].copy_from_slice(&1000u64.to_le_bytes());
+        bytes[20..28].copy_from_slice(&1002u64.to_le_bytes());
+
+        let decoded_batch = MessageBatch::from_bytes(&Bytes::from(bytes)).unwrap();
+        assert!(decoded_batch.validate().is_ok());
+        assert_eq!(decoded_batch.header.base_offset, 100);
+        assert_eq!(decoded_batch.header.first_timestamp, 1000);
+    }
+
+    #[test]
+    fn should_fail_the_validation_of_the_corrupted_records() {
+        let batch = create_batch();
+        let mut bytes = Vec::new();
+        batch.extend(&mut bytes);
+        let last_position = bytes.len() - 1;
+        bytes[last_position] ^= 1;
+
+        let decoded_batch = MessageBatch::from_bytes(&Bytes::from(bytes)).unwrap();
+        assert!(matches!(
+            decoded_batch.validate(),
+            Err(Error::InvalidBatchChecksum(_, _, 10))
+        ));
+    }
+
+    #[test]
+    fn should_not_decode_the_truncated_batch() {
+        let batch = create_batch();
+        let mut bytes = Vec::new();
+        batch.extend(&mut bytes);
+        bytes.truncate(bytes.len() - 1);
+
+        assert!(MessageBatch::from_bytes(&Bytes::from(bytes)).is_err());
+    }
+
+    #[test]
+    fn should_decode_all_the_batches() {
+        let batch = create_batch();
+        let mut bytes = Vec::new();
+        batch.extend(&mut bytes);
+        batch.extend(&mut bytes);
+
+        let batches = MessageBatch::read_all(&Bytes::from(bytes)).unwrap();
+        assert_eq!(batches, vec![batch.clone(), batch]);
+    }
+
+    #[test]
+    fn should_be_decoded_from_the_compressed_bytes() {
+        let batch = create_batch();
+        for compression in [
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Gzip,
+        ] {
+            let compressed_batch = batch.clone().compress(compression).unwrap();
+            let mut bytes = Vec::new();
+            compressed_batch.extend(&mut bytes);
+
+            assert_eq!(bytes.len(), compressed_batch.get_size_bytes() as usize);
+            assert_eq!(bytes, compressed_batch.get_buffers().concat());
+            let decoded_batch = MessageBatch::from_bytes(&Bytes::from(bytes)).unwrap();
+            assert!(decoded_batch.validate().is_ok());
+            assert!(decoded_batch.is_compressed());
+            assert_eq!(decoded_batch.header.get_compression().unwrap(), compression);
+            assert_eq!(decoded_batch.records, batch.records);
+            assert_eq!(decoded_batch.decompress(), batch);
+        }
+    }
+
+    fn create_batch() -> MessageBatch {
+        let records = (0..3)
+            .map(|index| Record {
+                offset_delta: index,
+                timestamp_delta: index * 2,
+                id: index as u128 + 1,
+                payload: Bytes::from(format!("message {}", index)),
+            })
+            .collect();
+        MessageBatch::new(10, 100, 104, 0, records)
+    }
+}
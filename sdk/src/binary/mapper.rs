@@ -1,11 +1,15 @@
+use crate::batch::MessageBatch;
 use crate::error::Error;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::created_partitions::CreatedPartitions;
 use crate::models::message::Message;
 use crate::models::offset::Offset;
 use crate::models::partition::Partition;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::topics::create_topic::TopicConfigOverrides;
+use bytes::Bytes;
 use std::str::from_utf8;
 
 const EMPTY_MESSAGES: Vec<Message> = vec![];
@@ -23,6 +27,15 @@ pub fn map_offset(payload: &[u8]) -> Result<Offset, Error> {
     })
 }
 
+pub fn map_created_partitions(payload: &[u8]) -> Result<CreatedPartitions, Error> {
+    let previous_partitions_count = u32::from_le_bytes(payload[..4].try_into()?);
+    let partitions_count = u32::from_le_bytes(payload[4..8].try_into()?);
+    Ok(CreatedPartitions {
+        previous_partitions_count,
+        partitions_count,
+    })
+}
+
 pub fn map_client(payload: &[u8]) -> Result<ClientInfoDetails, Error> {
     let (client, mut position) = map_to_client_info(payload, 0)?;
     let mut consumer_groups = Vec::new();
@@ -76,36 +89,22 @@ pub fn map_messages(payload: &[u8]) -> Result<Vec<Message>, Error> {
         return Ok(EMPTY_MESSAGES);
     }
 
-    const PROPERTIES_SIZE: usize = 36;
-    let length = payload.len();
-    let mut position = 4;
-    let mut messages = Vec::new();
-    while position < length {
-        let offset = u64::from_le_bytes(payload[position..position + 8].try_into()?);
-        let timestamp = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
-        let id = u128::from_le_bytes(payload[position + 16..position + 32].try_into()?);
-        let message_length =
-            u32::from_le_bytes(payload[position + 32..position + PROPERTIES_SIZE].try_into()?);
-
-        let payload_range =
-            position + PROPERTIES_SIZE..position + PROPERTIES_SIZE + message_length as usize;
-        if payload_range.start > length || payload_range.end > length {
-            break;
-        }
-
-        let payload = payload[payload_range].to_vec();
-        let total_size = PROPERTIES_SIZE + message_length as usize;
-        position += total_size;
-        messages.push(Message {
-            offset,
-            timestamp,
-            id,
-            length: message_length,
-            payload,
-        });
-
-        if position + PROPERTIES_SIZE >= length {
-            break;
+    let batches = MessageBatch::read_all(&Bytes::copy_from_slice(payload))?;
+    let mut messages = Vec::with_capacity(
+        batches
+            .iter()
+            .map(|batch| batch.header.records_count as usize)
+            .sum(),
+    );
+    for batch in batches {
+        for record in batch.records {
+            messages.push(Message {
+                offset: batch.header.base_offset + record.offset_delta as u64,
+                timestamp: batch.header.first_timestamp + record.timestamp_delta as u64,
+                id: record.id,
+                length: record.payload.len() as u32,
+                payload: record.payload.to_vec(),
+            });
         }
     }
 
@@ -185,6 +184,8 @@ pub fn map_topics(payload: &[u8]) -> Result<Vec<Topic>, Error> {
 
 pub fn map_topic(payload: &[u8]) -> Result<TopicDetails, Error> {
     let (topic, mut position) = map_to_topic(payload, 0)?;
+    let overrides = TopicConfigOverrides::from_bytes(&payload[position..position + 13])?;
+    position += 13;
     let mut partitions = Vec::new();
     let length = payload.len();
     while position < length {
@@ -198,6 +199,7 @@ pub fn map_topic(payload: &[u8]) -> Result<TopicDetails, Error> {
         id: topic.id,
         name: topic.name,
         partitions_count: partitions.len() as u32,
+        overrides,
         partitions,
     };
     Ok(topic)
@@ -224,13 +226,16 @@ fn map_to_partition(payload: &[u8], position: usize) -> Result<(Partition, usize
     let segments_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
     let current_offset = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
     let size_bytes = u64::from_le_bytes(payload[position + 16..position + 24].try_into()?);
-    let read_bytes = 4 + 4 + 8 + 8;
+    let corrupted_messages_count =
+        u64::from_le_bytes(payload[position + 24..position + 32].try_into()?);
+    let read_bytes = 4 + 4 + 8 + 8 + 8;
     Ok((
         Partition {
             id,
             segments_count,
             current_offset,
             size_bytes,
+            corrupted_messages_count,
         },
         read_bytes,
     ))
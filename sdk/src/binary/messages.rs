@@ -1,8 +1,12 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::mapper;
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{GET_OFFSET_CODE, POLL_MESSAGES_CODE, SEND_MESSAGES_CODE, STORE_OFFSET_CODE};
+use crate::command::{
+    DELETE_MESSAGES_BEFORE_CODE, GET_OFFSET_CODE, POLL_MESSAGES_CODE, SEND_MESSAGES_CODE,
+    STORE_OFFSET_CODE,
+};
 use crate::error::Error;
+use crate::messages::delete_messages_before::DeleteMessagesBefore;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::message::Message;
@@ -27,6 +31,16 @@ pub async fn send_messages(client: &dyn BinaryClient, command: &SendMessages) ->
     Ok(())
 }
 
+pub async fn delete_messages_before(
+    client: &dyn BinaryClient,
+    command: &DeleteMessagesBefore,
+) -> Result<(), Error> {
+    client
+        .send_with_response(DELETE_MESSAGES_BEFORE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
 pub async fn store_offset(client: &dyn BinaryClient, command: &StoreOffset) -> Result<(), Error> {
     client
         .send_with_response(STORE_OFFSET_CODE, &command.as_bytes())
@@ -2,6 +2,7 @@ pub mod binary_client;
 pub mod consumer_groups;
 mod mapper;
 pub mod messages;
+pub mod partitions;
 pub mod streams;
 pub mod system;
 pub mod topics;
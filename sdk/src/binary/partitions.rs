@@ -0,0 +1,52 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::mapper;
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{
+    CREATE_PARTITIONS_CODE, DELETE_PARTITIONS_CODE, PURGE_PARTITION_CODE, REBUILD_INDEXES_CODE,
+};
+use crate::error::Error;
+use crate::models::created_partitions::CreatedPartitions;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::purge_partition::PurgePartition;
+use crate::partitions::rebuild_indexes::RebuildIndexes;
+
+pub async fn rebuild_indexes(
+    client: &dyn BinaryClient,
+    command: &RebuildIndexes,
+) -> Result<(), Error> {
+    client
+        .send_with_response(REBUILD_INDEXES_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn purge_partition(
+    client: &dyn BinaryClient,
+    command: &PurgePartition,
+) -> Result<(), Error> {
+    client
+        .send_with_response(PURGE_PARTITION_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn create_partitions(
+    client: &dyn BinaryClient,
+    command: &CreatePartitions,
+) -> Result<CreatedPartitions, Error> {
+    let response = client
+        .send_with_response(CREATE_PARTITIONS_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_created_partitions(&response)
+}
+
+pub async fn delete_partitions(
+    client: &dyn BinaryClient,
+    command: &DeletePartitions,
+) -> Result<(), Error> {
+    client
+        .send_with_response(DELETE_PARTITIONS_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
@@ -1,13 +1,18 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::mapper;
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, GET_TOPICS_CODE, GET_TOPIC_CODE};
+use crate::command::{
+    CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, GET_TOPICS_CODE, GET_TOPIC_CODE, PURGE_TOPIC_CODE,
+    UPDATE_TOPIC_CODE,
+};
 use crate::error::Error;
 use crate::models::topic::{Topic, TopicDetails};
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
 
 pub async fn get_topic(
     client: &dyn BinaryClient,
@@ -42,3 +47,17 @@ pub async fn delete_topic(client: &dyn BinaryClient, command: &DeleteTopic) -> R
         .await?;
     Ok(())
 }
+
+pub async fn purge_topic(client: &dyn BinaryClient, command: &PurgeTopic) -> Result<(), Error> {
+    client
+        .send_with_response(PURGE_TOPIC_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn update_topic(client: &dyn BinaryClient, command: &UpdateTopic) -> Result<(), Error> {
+    client
+        .send_with_response(UPDATE_TOPIC_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
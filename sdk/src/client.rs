@@ -5,20 +5,27 @@ use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
+use crate::messages::delete_messages_before::DeleteMessagesBefore;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::created_partitions::CreatedPartitions;
 use crate::models::message::Message;
 use crate::models::offset::Offset;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
 use crate::offsets::get_offset::GetOffset;
 use crate::offsets::store_offset::StoreOffset;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::purge_partition::PurgePartition;
+use crate::partitions::rebuild_indexes::RebuildIndexes;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
+use crate::streams::update_stream::UpdateStream;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -28,6 +35,8 @@ use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
 use async_trait::async_trait;
 use std::fmt::Debug;
 
@@ -38,6 +47,7 @@ pub trait Client:
     + TopicClient
     + MessageClient
     + ConsumerGroupClient
+    + PartitionClient
     + Sync
     + Send
     + Debug
@@ -61,6 +71,7 @@ pub trait StreamClient {
     async fn get_streams(&self, command: &GetStreams) -> Result<Vec<Stream>, Error>;
     async fn create_stream(&self, command: &CreateStream) -> Result<(), Error>;
     async fn delete_stream(&self, command: &DeleteStream) -> Result<(), Error>;
+    async fn update_stream(&self, command: &UpdateStream) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -69,12 +80,15 @@ pub trait TopicClient {
     async fn get_topics(&self, command: &GetTopics) -> Result<Vec<Topic>, Error>;
     async fn create_topic(&self, command: &CreateTopic) -> Result<(), Error>;
     async fn delete_topic(&self, command: &DeleteTopic) -> Result<(), Error>;
+    async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), Error>;
+    async fn update_topic(&self, command: &UpdateTopic) -> Result<(), Error>;
 }
 
 #[async_trait]
 pub trait MessageClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<Vec<Message>, Error>;
     async fn send_messages(&self, command: &SendMessages) -> Result<(), Error>;
+    async fn delete_messages_before(&self, command: &DeleteMessagesBefore) -> Result<(), Error>;
     async fn store_offset(&self, command: &StoreOffset) -> Result<(), Error>;
     async fn get_offset(&self, command: &GetOffset) -> Result<Offset, Error>;
 }
@@ -94,3 +108,14 @@ pub trait ConsumerGroupClient {
     async fn join_consumer_group(&self, command: &JoinConsumerGroup) -> Result<(), Error>;
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error>;
 }
+
+#[async_trait]
+pub trait PartitionClient {
+    async fn rebuild_indexes(&self, command: &RebuildIndexes) -> Result<(), Error>;
+    async fn purge_partition(&self, command: &PurgePartition) -> Result<(), Error>;
+    async fn create_partitions(
+        &self,
+        command: &CreatePartitions,
+    ) -> Result<CreatedPartitions, Error>;
+    async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), Error>;
+}
@@ -1,6 +1,8 @@
 use crate::client::{
-    Client, ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient,
+    Client, ConsumerGroupClient, MessageClient, PartitionClient, StreamClient, SystemClient,
+    TopicClient,
 };
+use crate::compression::CompressionAlgorithm;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
@@ -8,20 +10,28 @@ use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::messages::delete_messages_before::DeleteMessagesBefore;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::{KeyKind, SendMessages};
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::created_partitions::CreatedPartitions;
 use crate::models::message::Message;
 use crate::models::offset::Offset;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
 use crate::offsets::get_offset::GetOffset;
 use crate::offsets::store_offset::StoreOffset;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::purge_partition::PurgePartition;
+use crate::partitions::rebuild_indexes::RebuildIndexes;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
+use crate::streams::update_stream::UpdateStream;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -31,6 +41,8 @@ use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
 use async_trait::async_trait;
 use std::collections::VecDeque;
 use std::sync::Arc;
@@ -112,18 +124,20 @@ impl IggyClient {
                 }
 
                 let mut initialized = false;
-                let mut stream_id = 0;
-                let mut topic_id = 0;
+                let mut stream_id = Identifier::default();
+                let mut topic_id = Identifier::default();
                 let mut key_kind = KeyKind::PartitionId;
                 let mut key_value = 0;
+                let mut compression = CompressionAlgorithm::None;
                 let mut batch_messages = true;
 
                 for send_messages in send_messages_batch.send_messages.iter() {
                     if !initialized {
-                        stream_id = send_messages.stream_id;
-                        topic_id = send_messages.topic_id;
+                        stream_id = send_messages.stream_id.clone();
+                        topic_id = send_messages.topic_id.clone();
                         key_kind = send_messages.key_kind;
                         key_value = send_messages.key_value;
+                        compression = send_messages.compression;
                         initialized = true;
                     }
 
@@ -132,6 +146,7 @@ impl IggyClient {
                         || send_messages.topic_id != topic_id
                         || send_messages.key_kind != key_kind
                         || send_messages.key_value != key_value
+                        || send_messages.compression != compression
                     {
                         batch_messages = false;
                         break;
@@ -160,12 +175,14 @@ impl IggyClient {
 
                 while let Some(messages) = batches.pop_front() {
                     let send_messages = SendMessages {
-                        stream_id,
-                        topic_id,
+                        stream_id: stream_id.clone(),
+                        topic_id: topic_id.clone(),
                         messages_count: messages.len() as u32,
                         key_value,
                         key_kind,
+                        compression,
                         messages,
+                        batch: None,
                     };
 
                     if let Err(error) = client.read().await.send_messages(&send_messages).await {
@@ -233,6 +250,10 @@ impl StreamClient for IggyClient {
     async fn delete_stream(&self, command: &DeleteStream) -> Result<(), Error> {
         self.client.read().await.delete_stream(command).await
     }
+
+    async fn update_stream(&self, command: &UpdateStream) -> Result<(), Error> {
+        self.client.read().await.update_stream(command).await
+    }
 }
 
 #[async_trait]
@@ -252,6 +273,14 @@ impl TopicClient for IggyClient {
     async fn delete_topic(&self, command: &DeleteTopic) -> Result<(), Error> {
         self.client.read().await.delete_topic(command).await
     }
+
+    async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), Error> {
+        self.client.read().await.purge_topic(command).await
+    }
+
+    async fn update_topic(&self, command: &UpdateTopic) -> Result<(), Error> {
+        self.client.read().await.update_topic(command).await
+    }
 }
 
 #[async_trait]
@@ -269,11 +298,12 @@ impl MessageClient for IggyClient {
 
         let mut batch = self.send_messages_batch.lock().await;
         let send_messages = SendMessages {
-            stream_id: command.stream_id,
-            topic_id: command.topic_id,
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
             messages_count: command.messages_count,
             key_value: command.key_value,
             key_kind: command.key_kind,
+            compression: command.compression,
             messages: command
                 .messages
                 .iter()
@@ -283,11 +313,20 @@ impl MessageClient for IggyClient {
                     payload: message.payload.clone(),
                 })
                 .collect(),
+            batch: None,
         };
         batch.send_messages.push_back(send_messages);
         Ok(())
     }
 
+    async fn delete_messages_before(&self, command: &DeleteMessagesBefore) -> Result<(), Error> {
+        self.client
+            .read()
+            .await
+            .delete_messages_before(command)
+            .await
+    }
+
     async fn store_offset(&self, command: &StoreOffset) -> Result<(), Error> {
         self.client.read().await.store_offset(command).await
     }
@@ -337,3 +376,25 @@ impl ConsumerGroupClient for IggyClient {
         self.client.read().await.leave_consumer_group(command).await
     }
 }
+
+#[async_trait]
+impl PartitionClient for IggyClient {
+    async fn rebuild_indexes(&self, command: &RebuildIndexes) -> Result<(), Error> {
+        self.client.read().await.rebuild_indexes(command).await
+    }
+
+    async fn purge_partition(&self, command: &PurgePartition) -> Result<(), Error> {
+        self.client.read().await.purge_partition(command).await
+    }
+
+    async fn create_partitions(
+        &self,
+        command: &CreatePartitions,
+    ) -> Result<CreatedPartitions, Error> {
+        self.client.read().await.create_partitions(command).await
+    }
+
+    async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), Error> {
+        self.client.read().await.delete_partitions(command).await
+    }
+}
@@ -6,14 +6,20 @@ use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
+use crate::messages::delete_messages_before::DeleteMessagesBefore;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::offsets::get_offset::GetOffset;
 use crate::offsets::store_offset::StoreOffset;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::purge_partition::PurgePartition;
+use crate::partitions::rebuild_indexes::RebuildIndexes;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
+use crate::streams::update_stream::UpdateStream;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -23,6 +29,8 @@ use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -44,6 +52,8 @@ pub const STORE_OFFSET: &str = "offset.store";
 pub const STORE_OFFSET_CODE: u8 = 12;
 pub const GET_OFFSET: &str = "offset.get";
 pub const GET_OFFSET_CODE: u8 = 13;
+pub const DELETE_MESSAGES_BEFORE: &str = "message.delete_before";
+pub const DELETE_MESSAGES_BEFORE_CODE: u8 = 14;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u8 = 20;
 pub const GET_STREAMS: &str = "stream.list";
@@ -52,6 +62,8 @@ pub const CREATE_STREAM: &str = "stream.create";
 pub const CREATE_STREAM_CODE: u8 = 22;
 pub const DELETE_STREAM: &str = "stream.delete";
 pub const DELETE_STREAM_CODE: u8 = 23;
+pub const UPDATE_STREAM: &str = "stream.update";
+pub const UPDATE_STREAM_CODE: u8 = 24;
 pub const GET_TOPIC: &str = "topic.get";
 pub const GET_TOPIC_CODE: u8 = 30;
 pub const GET_TOPICS: &str = "topic.list";
@@ -60,6 +72,10 @@ pub const CREATE_TOPIC: &str = "topic.create";
 pub const CREATE_TOPIC_CODE: u8 = 32;
 pub const DELETE_TOPIC: &str = "topic.delete";
 pub const DELETE_TOPIC_CODE: u8 = 33;
+pub const PURGE_TOPIC: &str = "topic.purge";
+pub const PURGE_TOPIC_CODE: u8 = 34;
+pub const UPDATE_TOPIC: &str = "topic.update";
+pub const UPDATE_TOPIC_CODE: u8 = 35;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
 pub const GET_CONSUMER_GROUP_CODE: u8 = 40;
 pub const GET_CONSUMER_GROUPS: &str = "consumer_group.list";
@@ -72,6 +88,14 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u8 = 44;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u8 = 45;
+pub const REBUILD_INDEXES: &str = "partition.rebuild_indexes";
+pub const REBUILD_INDEXES_CODE: u8 = 50;
+pub const PURGE_PARTITION: &str = "partition.purge";
+pub const PURGE_PARTITION_CODE: u8 = 51;
+pub const CREATE_PARTITIONS: &str = "partition.create";
+pub const CREATE_PARTITIONS_CODE: u8 = 52;
+pub const DELETE_PARTITIONS: &str = "partition.delete";
+pub const DELETE_PARTITIONS_CODE: u8 = 53;
 
 #[derive(Debug, PartialEq)]
 pub enum Command {
@@ -82,22 +106,30 @@ pub enum Command {
     GetClients(GetClients),
     SendMessages(SendMessages),
     PollMessages(PollMessages),
+    DeleteMessagesBefore(DeleteMessagesBefore),
     GetOffset(GetOffset),
     StoreOffset(StoreOffset),
     GetStream(GetStream),
     GetStreams(GetStreams),
     CreateStream(CreateStream),
     DeleteStream(DeleteStream),
+    UpdateStream(UpdateStream),
     GetTopic(GetTopic),
     GetTopics(GetTopics),
     CreateTopic(CreateTopic),
     DeleteTopic(DeleteTopic),
+    PurgeTopic(PurgeTopic),
+    UpdateTopic(UpdateTopic),
     GetGroup(GetConsumerGroup),
     GetGroups(GetConsumerGroups),
     CreateGroup(CreateConsumerGroup),
     DeleteGroup(DeleteConsumerGroup),
     JoinGroup(JoinConsumerGroup),
     LeaveGroup(LeaveConsumerGroup),
+    RebuildIndexes(RebuildIndexes),
+    PurgePartition(PurgePartition),
+    CreatePartitions(CreatePartitions),
+    DeletePartitions(DeletePartitions),
 }
 
 pub trait CommandPayload: BytesSerializable + Display {}
@@ -112,16 +144,22 @@ impl BytesSerializable for Command {
             Command::GetClients(payload) => as_bytes(GET_CLIENTS_CODE, &payload.as_bytes()),
             Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, &payload.as_bytes()),
             Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, &payload.as_bytes()),
+            Command::DeleteMessagesBefore(payload) => {
+                as_bytes(DELETE_MESSAGES_BEFORE_CODE, &payload.as_bytes())
+            }
             Command::StoreOffset(payload) => as_bytes(STORE_OFFSET_CODE, &payload.as_bytes()),
             Command::GetOffset(payload) => as_bytes(GET_OFFSET_CODE, &payload.as_bytes()),
             Command::GetStream(payload) => as_bytes(GET_STREAM_CODE, &payload.as_bytes()),
             Command::GetStreams(payload) => as_bytes(GET_STREAMS_CODE, &payload.as_bytes()),
             Command::CreateStream(payload) => as_bytes(CREATE_STREAM_CODE, &payload.as_bytes()),
             Command::DeleteStream(payload) => as_bytes(DELETE_STREAM_CODE, &payload.as_bytes()),
+            Command::UpdateStream(payload) => as_bytes(UPDATE_STREAM_CODE, &payload.as_bytes()),
             Command::GetTopic(payload) => as_bytes(GET_TOPIC_CODE, &payload.as_bytes()),
             Command::GetTopics(payload) => as_bytes(GET_TOPICS_CODE, &payload.as_bytes()),
             Command::CreateTopic(payload) => as_bytes(CREATE_TOPIC_CODE, &payload.as_bytes()),
             Command::DeleteTopic(payload) => as_bytes(DELETE_TOPIC_CODE, &payload.as_bytes()),
+            Command::PurgeTopic(payload) => as_bytes(PURGE_TOPIC_CODE, &payload.as_bytes()),
+            Command::UpdateTopic(payload) => as_bytes(UPDATE_TOPIC_CODE, &payload.as_bytes()),
             Command::GetGroup(payload) => as_bytes(GET_CONSUMER_GROUP_CODE, &payload.as_bytes()),
             Command::GetGroups(payload) => as_bytes(GET_CONSUMER_GROUPS_CODE, &payload.as_bytes()),
             Command::CreateGroup(payload) => {
@@ -134,6 +172,14 @@ impl BytesSerializable for Command {
             Command::LeaveGroup(payload) => {
                 as_bytes(LEAVE_CONSUMER_GROUP_CODE, &payload.as_bytes())
             }
+            Command::RebuildIndexes(payload) => as_bytes(REBUILD_INDEXES_CODE, &payload.as_bytes()),
+            Command::PurgePartition(payload) => as_bytes(PURGE_PARTITION_CODE, &payload.as_bytes()),
+            Command::CreatePartitions(payload) => {
+                as_bytes(CREATE_PARTITIONS_CODE, &payload.as_bytes())
+            }
+            Command::DeletePartitions(payload) => {
+                as_bytes(DELETE_PARTITIONS_CODE, &payload.as_bytes())
+            }
         }
     }
 
@@ -148,16 +194,22 @@ impl BytesSerializable for Command {
             GET_CLIENTS_CODE => Ok(Command::GetClients(GetClients::from_bytes(payload)?)),
             SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
             POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
+            DELETE_MESSAGES_BEFORE_CODE => Ok(Command::DeleteMessagesBefore(
+                DeleteMessagesBefore::from_bytes(payload)?,
+            )),
             STORE_OFFSET_CODE => Ok(Command::StoreOffset(StoreOffset::from_bytes(payload)?)),
             GET_OFFSET_CODE => Ok(Command::GetOffset(GetOffset::from_bytes(payload)?)),
             GET_STREAM_CODE => Ok(Command::GetStream(GetStream::from_bytes(payload)?)),
             GET_STREAMS_CODE => Ok(Command::GetStreams(GetStreams::from_bytes(payload)?)),
             CREATE_STREAM_CODE => Ok(Command::CreateStream(CreateStream::from_bytes(payload)?)),
             DELETE_STREAM_CODE => Ok(Command::DeleteStream(DeleteStream::from_bytes(payload)?)),
+            UPDATE_STREAM_CODE => Ok(Command::UpdateStream(UpdateStream::from_bytes(payload)?)),
             GET_TOPIC_CODE => Ok(Command::GetTopic(GetTopic::from_bytes(payload)?)),
             GET_TOPICS_CODE => Ok(Command::GetTopics(GetTopics::from_bytes(payload)?)),
             CREATE_TOPIC_CODE => Ok(Command::CreateTopic(CreateTopic::from_bytes(payload)?)),
             DELETE_TOPIC_CODE => Ok(Command::DeleteTopic(DeleteTopic::from_bytes(payload)?)),
+            PURGE_TOPIC_CODE => Ok(Command::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            UPDATE_TOPIC_CODE => Ok(Command::UpdateTopic(UpdateTopic::from_bytes(payload)?)),
             GET_CONSUMER_GROUP_CODE => {
                 Ok(Command::GetGroup(GetConsumerGroup::from_bytes(payload)?))
             }
@@ -176,6 +228,18 @@ impl BytesSerializable for Command {
             LEAVE_CONSUMER_GROUP_CODE => Ok(Command::LeaveGroup(LeaveConsumerGroup::from_bytes(
                 payload,
             )?)),
+            REBUILD_INDEXES_CODE => Ok(Command::RebuildIndexes(RebuildIndexes::from_bytes(
+                payload,
+            )?)),
+            PURGE_PARTITION_CODE => Ok(Command::PurgePartition(PurgePartition::from_bytes(
+                payload,
+            )?)),
+            CREATE_PARTITIONS_CODE => Ok(Command::CreatePartitions(CreatePartitions::from_bytes(
+                payload,
+            )?)),
+            DELETE_PARTITIONS_CODE => Ok(Command::DeletePartitions(DeletePartitions::from_bytes(
+                payload,
+            )?)),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -200,16 +264,22 @@ impl FromStr for Command {
             GET_CLIENTS => Ok(Command::GetClients(GetClients::from_str(payload)?)),
             SEND_MESSAGES => Ok(Command::SendMessages(SendMessages::from_str(payload)?)),
             POLL_MESSAGES => Ok(Command::PollMessages(PollMessages::from_str(payload)?)),
+            DELETE_MESSAGES_BEFORE => Ok(Command::DeleteMessagesBefore(
+                DeleteMessagesBefore::from_str(payload)?,
+            )),
             STORE_OFFSET => Ok(Command::StoreOffset(StoreOffset::from_str(payload)?)),
             GET_OFFSET => Ok(Command::GetOffset(GetOffset::from_str(payload)?)),
             GET_STREAM => Ok(Command::GetStream(GetStream::from_str(payload)?)),
             GET_STREAMS => Ok(Command::GetStreams(GetStreams::from_str(payload)?)),
             CREATE_STREAM => Ok(Command::CreateStream(CreateStream::from_str(payload)?)),
             DELETE_STREAM => Ok(Command::DeleteStream(DeleteStream::from_str(payload)?)),
+            UPDATE_STREAM => Ok(Command::UpdateStream(UpdateStream::from_str(payload)?)),
             GET_TOPIC => Ok(Command::GetTopic(GetTopic::from_str(payload)?)),
             GET_TOPICS => Ok(Command::GetTopics(GetTopics::from_str(payload)?)),
             CREATE_TOPIC => Ok(Command::CreateTopic(CreateTopic::from_str(payload)?)),
             DELETE_TOPIC => Ok(Command::DeleteTopic(DeleteTopic::from_str(payload)?)),
+            PURGE_TOPIC => Ok(Command::PurgeTopic(PurgeTopic::from_str(payload)?)),
+            UPDATE_TOPIC => Ok(Command::UpdateTopic(UpdateTopic::from_str(payload)?)),
             GET_CONSUMER_GROUP => Ok(Command::GetGroup(GetConsumerGroup::from_str(payload)?)),
             GET_CONSUMER_GROUPS => Ok(Command::GetGroups(GetConsumerGroups::from_str(payload)?)),
             CREATE_CONSUMER_GROUP => Ok(Command::CreateGroup(CreateConsumerGroup::from_str(
@@ -220,6 +290,14 @@ impl FromStr for Command {
             )?)),
             JOIN_CONSUMER_GROUP => Ok(Command::JoinGroup(JoinConsumerGroup::from_str(payload)?)),
             LEAVE_CONSUMER_GROUP => Ok(Command::LeaveGroup(LeaveConsumerGroup::from_str(payload)?)),
+            REBUILD_INDEXES => Ok(Command::RebuildIndexes(RebuildIndexes::from_str(payload)?)),
+            PURGE_PARTITION => Ok(Command::PurgePartition(PurgePartition::from_str(payload)?)),
+            CREATE_PARTITIONS => Ok(Command::CreatePartitions(CreatePartitions::from_str(
+                payload,
+            )?)),
+            DELETE_PARTITIONS => Ok(Command::DeletePartitions(DeletePartitions::from_str(
+                payload,
+            )?)),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -237,12 +315,18 @@ impl Display for Command {
             Command::GetStreams(payload) => write!(formatter, "{}|{}", GET_STREAMS, payload),
             Command::CreateStream(payload) => write!(formatter, "{}|{}", CREATE_STREAM, payload),
             Command::DeleteStream(payload) => write!(formatter, "{}|{}", DELETE_STREAM, payload),
+            Command::UpdateStream(payload) => write!(formatter, "{}|{}", UPDATE_STREAM, payload),
             Command::GetTopic(payload) => write!(formatter, "{}|{}", GET_TOPIC, payload),
             Command::GetTopics(payload) => write!(formatter, "{}|{}", GET_TOPICS, payload),
             Command::CreateTopic(payload) => write!(formatter, "{}|{}", CREATE_TOPIC, payload),
             Command::DeleteTopic(payload) => write!(formatter, "{}|{}", DELETE_TOPIC, payload),
+            Command::PurgeTopic(payload) => write!(formatter, "{}|{}", PURGE_TOPIC, payload),
+            Command::UpdateTopic(payload) => write!(formatter, "{}|{}", UPDATE_TOPIC, payload),
             Command::PollMessages(payload) => write!(formatter, "{}|{}", POLL_MESSAGES, payload),
             Command::SendMessages(payload) => write!(formatter, "{}|{}", SEND_MESSAGES, payload),
+            Command::DeleteMessagesBefore(payload) => {
+                write!(formatter, "{}|{}", DELETE_MESSAGES_BEFORE, payload)
+            }
             Command::StoreOffset(payload) => write!(formatter, "{}|{}", STORE_OFFSET, payload),
             Command::GetOffset(payload) => write!(formatter, "{}|{}", GET_OFFSET, payload),
             Command::GetGroup(payload) => write!(formatter, "{}|{}", GET_CONSUMER_GROUP, payload),
@@ -257,6 +341,18 @@ impl Display for Command {
             Command::LeaveGroup(payload) => {
                 write!(formatter, "{}|{}", LEAVE_CONSUMER_GROUP, payload)
             }
+            Command::RebuildIndexes(payload) => {
+                write!(formatter, "{}|{}", REBUILD_INDEXES, payload)
+            }
+            Command::PurgePartition(payload) => {
+                write!(formatter, "{}|{}", PURGE_PARTITION, payload)
+            }
+            Command::CreatePartitions(payload) => {
+                write!(formatter, "{}|{}", CREATE_PARTITIONS, payload)
+            }
+            Command::DeletePartitions(payload) => {
+                write!(formatter, "{}|{}", DELETE_PARTITIONS, payload)
+            }
         }
     }
 }
@@ -302,6 +398,11 @@ mod tests {
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteMessagesBefore(DeleteMessagesBefore::default()),
+            DELETE_MESSAGES_BEFORE_CODE,
+            &DeleteMessagesBefore::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::StoreOffset(StoreOffset::default()),
             STORE_OFFSET_CODE,
@@ -332,6 +433,11 @@ mod tests {
             DELETE_STREAM_CODE,
             &DeleteStream::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateStream(UpdateStream::default()),
+            UPDATE_STREAM_CODE,
+            &UpdateStream::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetTopic(GetTopic::default()),
             GET_TOPIC_CODE,
@@ -352,6 +458,16 @@ mod tests {
             DELETE_TOPIC_CODE,
             &DeleteTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::PurgeTopic(PurgeTopic::default()),
+            PURGE_TOPIC_CODE,
+            &PurgeTopic::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateTopic(UpdateTopic::default()),
+            UPDATE_TOPIC_CODE,
+            &UpdateTopic::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -382,6 +498,26 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RebuildIndexes(RebuildIndexes::default()),
+            REBUILD_INDEXES_CODE,
+            &RebuildIndexes::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::PurgePartition(PurgePartition::default()),
+            PURGE_PARTITION_CODE,
+            &PurgePartition::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreatePartitions(CreatePartitions::default()),
+            CREATE_PARTITIONS_CODE,
+            &CreatePartitions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeletePartitions(DeletePartitions::default()),
+            DELETE_PARTITIONS_CODE,
+            &DeletePartitions::default(),
+        );
     }
 
     #[test]
@@ -409,6 +545,11 @@ mod tests {
             POLL_MESSAGES,
             &PollMessages::default(),
         );
+        assert_read_from_string(
+            &Command::DeleteMessagesBefore(DeleteMessagesBefore::default()),
+            DELETE_MESSAGES_BEFORE,
+            &DeleteMessagesBefore::default(),
+        );
         assert_read_from_string(
             &Command::StoreOffset(StoreOffset::default()),
             STORE_OFFSET,
@@ -439,6 +580,11 @@ mod tests {
             DELETE_STREAM,
             &DeleteStream::default(),
         );
+        assert_read_from_string(
+            &Command::UpdateStream(UpdateStream::default()),
+            UPDATE_STREAM,
+            &UpdateStream::default(),
+        );
         assert_read_from_string(
             &Command::GetTopic(GetTopic::default()),
             GET_TOPIC,
@@ -459,6 +605,16 @@ mod tests {
             DELETE_TOPIC,
             &DeleteTopic::default(),
         );
+        assert_read_from_string(
+            &Command::PurgeTopic(PurgeTopic::default()),
+            PURGE_TOPIC,
+            &PurgeTopic::default(),
+        );
+        assert_read_from_string(
+            &Command::UpdateTopic(UpdateTopic::default()),
+            UPDATE_TOPIC,
+            &UpdateTopic::default(),
+        );
         assert_read_from_string(
             &Command::GetGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP,
@@ -489,6 +645,26 @@ mod tests {
             LEAVE_CONSUMER_GROUP,
             &LeaveConsumerGroup::default(),
         );
+        assert_read_from_string(
+            &Command::RebuildIndexes(RebuildIndexes::default()),
+            REBUILD_INDEXES,
+            &RebuildIndexes::default(),
+        );
+        assert_read_from_string(
+            &Command::PurgePartition(PurgePartition::default()),
+            PURGE_PARTITION,
+            &PurgePartition::default(),
+        );
+        assert_read_from_string(
+            &Command::CreatePartitions(CreatePartitions::default()),
+            CREATE_PARTITIONS,
+            &CreatePartitions::default(),
+        );
+        assert_read_from_string(
+            &Command::DeletePartitions(DeletePartitions::default()),
+            DELETE_PARTITIONS,
+            &DeletePartitions::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
@@ -0,0 +1,199 @@
+use crate::error::Error;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionAlgorithm {
+    #[default]
+    None,
+    Lz4,
+    Zstd,
+    Gzip,
+}
+
+impl CompressionAlgorithm {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CompressionAlgorithm::None => 0,
+            CompressionAlgorithm::Lz4 => 1,
+            CompressionAlgorithm::Zstd => 2,
+            CompressionAlgorithm::Gzip => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0 => Ok(CompressionAlgorithm::None),
+            1 => Ok(CompressionAlgorithm::Lz4),
+            2 => Ok(CompressionAlgorithm::Zstd),
+            3 => Ok(CompressionAlgorithm::Gzip),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+
+    pub fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, Error> {
+        match self {
+            CompressionAlgorithm::None => Ok(bytes.to_vec()),
+            CompressionAlgorithm::Lz4 => Ok(lz4_flex::compress_prepend_size(bytes)),
+            CompressionAlgorithm::Zstd => zstd::encode_all(bytes, ZSTD_COMPRESSION_LEVEL)
+                .map_err(|_| Error::CannotCompressData),
+            CompressionAlgorithm::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                if encoder.write_all(bytes).is_err() {
+                    return Err(Error::CannotCompressData);
+                }
+                encoder.finish().map_err(|_| Error::CannotCompressData)
+            }
+        }
+    }
+
+    pub fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, Error> {
+        self.decompress_with_limit(bytes, usize::MAX)
+    }
+
+    pub fn decompress_with_limit(
+        &self,
+        bytes: &[u8],
+        max_size_bytes: usize,
+    ) -> Result<Vec<u8>, Error> {
+        match self {
+            CompressionAlgorithm::None => {
+                if bytes.len() > max_size_bytes {
+                    return Err(Error::TooBigMessagePayload);
+                }
+                Ok(bytes.to_vec())
+            }
+            CompressionAlgorithm::Lz4 => {
+                if bytes.len() < 4 {
+                    return Err(Error::CannotDecompressData);
+                }
+
+                // The size is prepended by the client, so it's checked before the data is allocated.
+                let size = u32::from_le_bytes(bytes[..4].try_into()?) as usize;
+                if size > max_size_bytes {
+                    return Err(Error::TooBigMessagePayload);
+                }
+                lz4_flex::decompress_size_prepended(bytes).map_err(|_| Error::CannotDecompressData)
+            }
+            CompressionAlgorithm::Zstd => {
+                let decoder = zstd::stream::read::Decoder::new(bytes)
+                    .map_err(|_| Error::CannotDecompressData)?;
+                read_with_limit(decoder, max_size_bytes)
+            }
+            CompressionAlgorithm::Gzip => read_with_limit(GzDecoder::new(bytes), max_size_bytes),
+        }
+    }
+}
+
+// One byte more than the limit is read, to tell whether the data exceeds it.
+fn read_with_limit(reader: impl Read, max_size_bytes: usize) -> Result<Vec<u8>, Error> {
+    let mut decompressed = Vec::new();
+    if reader
+        .take((max_size_bytes as u64).saturating_add(1))
+        .read_to_end(&mut decompressed)
+        .is_err()
+    {
+        return Err(Error::CannotDecompressData);
+    }
+
+    if decompressed.len() > max_size_bytes {
+        return Err(Error::TooBigMessagePayload);
+    }
+
+    Ok(decompressed)
+}
+
+impl FromStr for CompressionAlgorithm {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(CompressionAlgorithm::None),
+            "lz4" => Ok(CompressionAlgorithm::Lz4),
+            "zstd" => Ok(CompressionAlgorithm::Zstd),
+            "gzip" => Ok(CompressionAlgorithm::Gzip),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for CompressionAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompressionAlgorithm::None => write!(f, "none"),
+            CompressionAlgorithm::Lz4 => write!(f, "lz4"),
+            CompressionAlgorithm::Zstd => write!(f, "zstd"),
+            CompressionAlgorithm::Gzip => write!(f, "gzip"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_decompress_the_compressed_data() {
+        let bytes = "hello world ".repeat(100).into_bytes();
+        for algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Gzip,
+        ] {
+            let compressed = algorithm.compress(&bytes).unwrap();
+            let decompressed = algorithm.decompress(&compressed).unwrap();
+            assert_eq!(decompressed, bytes);
+        }
+    }
+
+    #[test]
+    fn should_not_decompress_the_data_exceeding_the_limit() {
+        let bytes = "hello world ".repeat(100).into_bytes();
+        for algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Gzip,
+        ] {
+            let compressed = algorithm.compress(&bytes).unwrap();
+            assert!(matches!(
+                algorithm.decompress_with_limit(&compressed, bytes.len() - 1),
+                Err(Error::TooBigMessagePayload)
+            ));
+            assert_eq!(
+                algorithm
+                    .decompress_with_limit(&compressed, bytes.len())
+                    .unwrap(),
+                bytes
+            );
+        }
+
+        let mut compressed = CompressionAlgorithm::Lz4.compress(&bytes).unwrap();
+        compressed[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            CompressionAlgorithm::Lz4.decompress_with_limit(&compressed, bytes.len()),
+            Err(Error::TooBigMessagePayload)
+        ));
+    }
+
+    #[test]
+    fn should_be_read_from_code() {
+        for algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Gzip,
+        ] {
+            let code = algorithm.as_code();
+            assert_eq!(CompressionAlgorithm::from_code(code).unwrap(), algorithm);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
+use crate::identifier::Identifier;
 use crate::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -8,8 +9,8 @@ use std::str::FromStr;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct DeleteConsumerGroup {
-    pub stream_id: u32,
-    pub topic_id: u32,
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
     pub consumer_group_id: u32,
 }
 
@@ -18,8 +19,8 @@ impl CommandPayload for DeleteConsumerGroup {}
 impl Default for DeleteConsumerGroup {
     fn default() -> Self {
         DeleteConsumerGroup {
-            stream_id: 1,
-            topic_id: 1,
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
             consumer_group_id: 1,
         }
     }
@@ -27,11 +28,11 @@ impl Default for DeleteConsumerGroup {
 
 impl Validatable for DeleteConsumerGroup {
     fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+        if !self.stream_id.is_valid() {
             return Err(Error::InvalidStreamId);
         }
 
-        if self.topic_id == 0 {
+        if !self.topic_id.is_valid() {
             return Err(Error::InvalidTopicId);
         }
 
@@ -51,8 +52,8 @@ impl FromStr for DeleteConsumerGroup {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = parts[0].parse::<u32>()?;
-        let topic_id = parts[1].parse::<u32>()?;
+        let stream_id = Identifier::from_str(parts[0])?;
+        let topic_id = Identifier::from_str(parts[1])?;
         let consumer_group_id = parts[2].parse::<u32>()?;
         let command = DeleteConsumerGroup {
             stream_id,
@@ -66,21 +67,25 @@ impl FromStr for DeleteConsumerGroup {
 
 impl BytesSerializable for DeleteConsumerGroup {
     fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(12);
-        bytes.extend(self.stream_id.to_le_bytes());
-        bytes.extend(self.topic_id.to_le_bytes());
+        let mut bytes = Vec::with_capacity(
+            4 + self.stream_id.get_size_bytes() + self.topic_id.get_size_bytes(),
+        );
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
         bytes.extend(self.consumer_group_id.to_le_bytes());
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<DeleteConsumerGroup, Error> {
-        if bytes.len() != 12 {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes();
+        if bytes.len() != position + 4 {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into()?);
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into()?);
-        let consumer_group_id = u32::from_le_bytes(bytes[8..12].try_into()?);
+        let consumer_group_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
         let command = DeleteConsumerGroup {
             stream_id,
             topic_id,
@@ -108,15 +113,18 @@ mod tests {
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = DeleteConsumerGroup {
-            stream_id: 1,
-            topic_id: 2,
+            stream_id: Identifier::numeric(1),
+            topic_id: Identifier::numeric(2),
             consumer_group_id: 3,
         };
 
         let bytes = command.as_bytes();
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
-        let consumer_group_id = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes();
+        let consumer_group_id =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -126,13 +134,13 @@ mod tests {
 
     #[test]
     fn should_be_deserialized_from_bytes() {
-        let stream_id = 1u32;
-        let topic_id = 2u32;
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::numeric(2);
         let consumer_group_id = 3u32;
         let bytes = [
-            stream_id.to_le_bytes(),
-            topic_id.to_le_bytes(),
-            consumer_group_id.to_le_bytes(),
+            stream_id.as_bytes(),
+            topic_id.as_bytes(),
+            consumer_group_id.to_le_bytes().to_vec(),
         ]
         .concat();
         let command = DeleteConsumerGroup::from_bytes(&bytes);
@@ -146,8 +154,8 @@ mod tests {
 
     #[test]
     fn should_be_read_from_string() {
-        let stream_id = 1u32;
-        let topic_id = 2u32;
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::numeric(2);
         let consumer_group_id = 3u32;
         let input = format!("{}|{}|{}", stream_id, topic_id, consumer_group_id);
         let command = DeleteConsumerGroup::from_str(&input);
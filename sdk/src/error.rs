@@ -208,6 +208,52 @@ pub enum Error {
     ClientNotFound(u32),
     #[error("Invalid client ID")]
     InvalidClientId,
+    #[error("Invalid topic message expiry")]
+    InvalidTopicMessageExpiry,
+    #[error("Invalid topic max size")]
+    InvalidTopicMaxSize,
+    #[error("Segment with start offset: {0} and partition with ID: {1} is not closed")]
+    SegmentNotClosed(u64, u32),
+    #[error("Cannot compress data")]
+    CannotCompressData,
+    #[error("Cannot decompress data")]
+    CannotDecompressData,
+    #[error("Cannot read block index: {0}")]
+    CannotReadBlockIndex(String),
+    #[error("Cannot save block index to segment")]
+    CannotSaveBlockIndexToSegment,
+    #[error("Invalid storage version: {0}, supported version: {1}")]
+    InvalidStorageVersion(u32, u32),
+    #[error("Cannot migrate storage from version: {0} to version: {1}")]
+    CannotMigrateStorage(u32, u32),
+    #[error("Cannot archive segment with start offset: {0} for partition with ID: {1}")]
+    CannotArchiveSegment(u64, u32),
+    #[error("Cannot restore archived segment with start offset: {0} for partition with ID: {1}")]
+    CannotRestoreArchivedSegment(u64, u32),
+    #[error("Invalid message batch")]
+    InvalidMessageBatch,
+    #[error("Invalid batch checksum: {0}, expected: {1}, for base offset: {2}")]
+    InvalidBatchChecksum(u32, u32, u64),
+    #[error("Duplicate message ID: {0} for partition with ID: {1}")]
+    DuplicateMessageId(u128, u32),
+    #[error("Invalid topic deduplication window")]
+    InvalidTopicDeduplication,
+    #[error(
+        "Partition with ID: {0} for topic with ID: {1} is draining and cannot accept new messages"
+    )]
+    PartitionDraining(u32, u32),
+    #[error("Stream with name: '{0}' was not found.")]
+    StreamNameNotFound(String),
+    #[error("Stream with name: '{0}' already exists.")]
+    StreamNameAlreadyExists(String),
+    #[error("Topic with name: '{0}' for stream with ID: {1} was not found.")]
+    TopicNameNotFound(String, u32),
+    #[error("Topic with name: '{0}' for stream with ID: {1} already exists.")]
+    TopicNameAlreadyExists(String, u32),
+    #[error("Invalid topic config overrides")]
+    InvalidTopicConfigOverrides,
+    #[error("Partitions of topic with ID: {0} for stream with ID: {1} are draining, thus the new partitions cannot be created yet")]
+    TopicPartitionsDraining(u32, u32),
 }
 
 impl Error {
@@ -296,6 +342,28 @@ impl Error {
             Error::CannotDeleteConsumerGroupInfo(_, _, _) => 81,
             Error::ClientNotFound(_) => 82,
             Error::InvalidClientId => 83,
+            Error::InvalidTopicMessageExpiry => 84,
+            Error::InvalidTopicMaxSize => 85,
+            Error::SegmentNotClosed(_, _) => 86,
+            Error::CannotCompressData => 87,
+            Error::CannotDecompressData => 88,
+            Error::CannotReadBlockIndex(_) => 89,
+            Error::CannotSaveBlockIndexToSegment => 90,
+            Error::InvalidStorageVersion(_, _) => 91,
+            Error::CannotMigrateStorage(_, _) => 92,
+            Error::CannotArchiveSegment(_, _) => 93,
+            Error::CannotRestoreArchivedSegment(_, _) => 94,
+            Error::InvalidMessageBatch => 95,
+            Error::InvalidBatchChecksum(_, _, _) => 96,
+            Error::DuplicateMessageId(_, _) => 98,
+            Error::InvalidTopicDeduplication => 99,
+            Error::PartitionDraining(_, _) => 100,
+            Error::StreamNameNotFound(_) => 101,
+            Error::StreamNameAlreadyExists(_) => 102,
+            Error::TopicNameNotFound(_, _) => 103,
+            Error::TopicNameAlreadyExists(_, _) => 104,
+            Error::InvalidTopicConfigOverrides => 105,
+            Error::TopicPartitionsDraining(_, _) => 106,
             _ => 255,
         }
     }
@@ -392,6 +460,28 @@ impl Error {
             Error::CannotDeleteConsumerGroupInfo(_, _, _) => "cannot_delete_consumer_group_info",
             Error::ClientNotFound(_) => "client_not_found",
             Error::InvalidClientId => "invalid_client_id",
+            Error::InvalidTopicMessageExpiry => "invalid_topic_message_expiry",
+            Error::InvalidTopicMaxSize => "invalid_topic_max_size",
+            Error::SegmentNotClosed(_, _) => "segment_not_closed",
+            Error::CannotCompressData => "cannot_compress_data",
+            Error::CannotDecompressData => "cannot_decompress_data",
+            Error::CannotReadBlockIndex(_) => "cannot_read_block_index",
+            Error::CannotSaveBlockIndexToSegment => "cannot_save_block_index_to_segment",
+            Error::InvalidStorageVersion(_, _) => "invalid_storage_version",
+            Error::CannotMigrateStorage(_, _) => "cannot_migrate_storage",
+            Error::CannotArchiveSegment(_, _) => "cannot_archive_segment",
+            Error::CannotRestoreArchivedSegment(_, _) => "cannot_restore_archived_segment",
+            Error::InvalidMessageBatch => "invalid_message_batch",
+            Error::InvalidBatchChecksum(_, _, _) => "invalid_batch_checksum",
+            Error::DuplicateMessageId(_, _) => "duplicate_message_id",
+            Error::InvalidTopicDeduplication => "invalid_topic_deduplication",
+            Error::PartitionDraining(_, _) => "partition_draining",
+            Error::StreamNameNotFound(_) => "stream_name_not_found",
+            Error::StreamNameAlreadyExists(_) => "stream_name_already_exists",
+            Error::TopicNameNotFound(_, _) => "topic_name_not_found",
+            Error::TopicNameAlreadyExists(_, _) => "topic_name_already_exists",
+            Error::InvalidTopicConfigOverrides => "invalid_topic_config_overrides",
+            Error::TopicPartitionsDraining(_, _) => "topic_partitions_draining",
             _ => "error",
         }
     }
@@ -7,6 +7,7 @@ use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::identifier::Identifier;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use async_trait::async_trait;
 
@@ -19,7 +20,7 @@ impl ConsumerGroupClient for HttpClient {
         let response = self
             .get(&format!(
                 "{}/{}",
-                get_path(command.stream_id, command.topic_id),
+                get_path(&command.stream_id, &command.topic_id),
                 command.stream_id
             ))
             .await?;
@@ -32,14 +33,14 @@ impl ConsumerGroupClient for HttpClient {
         command: &GetConsumerGroups,
     ) -> Result<Vec<ConsumerGroup>, Error> {
         let response = self
-            .get(&get_path(command.stream_id, command.topic_id))
+            .get(&get_path(&command.stream_id, &command.topic_id))
             .await?;
         let consumer_groups = response.json().await?;
         Ok(consumer_groups)
     }
 
     async fn create_consumer_group(&self, command: &CreateConsumerGroup) -> Result<(), Error> {
-        self.post(&get_path(command.stream_id, command.topic_id), &command)
+        self.post(&get_path(&command.stream_id, &command.topic_id), &command)
             .await?;
         Ok(())
     }
@@ -47,7 +48,7 @@ impl ConsumerGroupClient for HttpClient {
     async fn delete_consumer_group(&self, command: &DeleteConsumerGroup) -> Result<(), Error> {
         let path = format!(
             "{}/{}",
-            get_path(command.stream_id, command.topic_id),
+            get_path(&command.stream_id, &command.topic_id),
             command.consumer_group_id
         );
         self.delete(&path).await?;
@@ -63,6 +64,6 @@ impl ConsumerGroupClient for HttpClient {
     }
 }
 
-fn get_path(stream_id: u32, topic_id: u32) -> String {
+fn get_path(stream_id: &Identifier, topic_id: &Identifier) -> String {
     format!("streams/{}/topics/{}/consumer_groups", stream_id, topic_id)
 }
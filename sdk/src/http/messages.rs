@@ -1,33 +1,79 @@
 use crate::client::MessageClient;
+use crate::compression::CompressionAlgorithm;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::identifier::Identifier;
+use crate::messages::delete_messages_before::DeleteMessagesBefore;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::send_messages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::message::Message;
 use crate::models::offset::Offset;
 use crate::offsets::get_offset::GetOffset;
 use crate::offsets::store_offset::StoreOffset;
 use async_trait::async_trait;
+use bytes::Bytes;
 
 #[async_trait]
 impl MessageClient for HttpClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<Vec<Message>, Error> {
         let response = self
-            .get_with_query(&get_path(command.stream_id, command.topic_id), &command)
+            .get_with_query(&get_path(&command.stream_id, &command.topic_id), &command)
             .await?;
-        let messages = response.json().await?;
+        let mut messages: Vec<Message> = response.json().await?;
+        if command.compression != CompressionAlgorithm::None {
+            for message in &mut messages {
+                message.payload = command.compression.decompress(&message.payload)?;
+                message.length = message.payload.len() as u32;
+            }
+        }
         Ok(messages)
     }
 
     async fn send_messages(&self, command: &SendMessages) -> Result<(), Error> {
-        self.post(&get_path(command.stream_id, command.topic_id), &command)
+        if command.compression == CompressionAlgorithm::None {
+            self.post(&get_path(&command.stream_id, &command.topic_id), &command)
+                .await?;
+            return Ok(());
+        }
+
+        // There's no batch in the JSON body, thus each payload is compressed on its own.
+        let mut messages = Vec::with_capacity(command.messages.len());
+        for message in &command.messages {
+            let payload = Bytes::from(command.compression.compress(&message.payload)?);
+            messages.push(send_messages::Message {
+                id: message.id,
+                length: payload.len() as u32,
+                payload,
+            });
+        }
+        let compressed_command = SendMessages {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+            key_kind: command.key_kind,
+            key_value: command.key_value,
+            messages_count: command.messages_count,
+            compression: command.compression,
+            messages,
+            batch: None,
+        };
+        self.post(
+            &get_path(&command.stream_id, &command.topic_id),
+            &compressed_command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_messages_before(&self, command: &DeleteMessagesBefore) -> Result<(), Error> {
+        self.delete_with_query(&get_path(&command.stream_id, &command.topic_id), &command)
             .await?;
         Ok(())
     }
 
     async fn store_offset(&self, command: &StoreOffset) -> Result<(), Error> {
         self.put(
-            &get_offsets_path(command.stream_id, command.topic_id),
+            &get_offsets_path(&command.stream_id, &command.topic_id),
             &command,
         )
         .await?;
@@ -37,7 +83,7 @@ impl MessageClient for HttpClient {
     async fn get_offset(&self, command: &GetOffset) -> Result<Offset, Error> {
         let response = self
             .get_with_query(
-                &get_offsets_path(command.stream_id, command.topic_id),
+                &get_offsets_path(&command.stream_id, &command.topic_id),
                 &command,
             )
             .await?;
@@ -46,10 +92,10 @@ impl MessageClient for HttpClient {
     }
 }
 
-fn get_offsets_path(stream_id: u32, topic_id: u32) -> String {
+fn get_offsets_path(stream_id: &Identifier, topic_id: &Identifier) -> String {
     format!("{}/offsets", get_path(stream_id, topic_id))
 }
 
-fn get_path(stream_id: u32, topic_id: u32) -> String {
+fn get_path(stream_id: &Identifier, topic_id: &Identifier) -> String {
     format!("streams/{}/topics/{}/messages", stream_id, topic_id)
 }
@@ -0,0 +1,54 @@
+use crate::client::PartitionClient;
+use crate::error::Error;
+use crate::http::client::HttpClient;
+use crate::identifier::Identifier;
+use crate::models::created_partitions::CreatedPartitions;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::purge_partition::PurgePartition;
+use crate::partitions::rebuild_indexes::RebuildIndexes;
+use async_trait::async_trait;
+
+#[async_trait]
+impl PartitionClient for HttpClient {
+    async fn rebuild_indexes(&self, command: &RebuildIndexes) -> Result<(), Error> {
+        let path = format!(
+            "{}/{}/rebuild_indexes",
+            get_path(&command.stream_id, &command.topic_id),
+            command.partition_id
+        );
+        self.post(&path, &command).await?;
+        Ok(())
+    }
+
+    async fn purge_partition(&self, command: &PurgePartition) -> Result<(), Error> {
+        let path = format!(
+            "{}/{}/purge",
+            get_path(&command.stream_id, &command.topic_id),
+            command.partition_id
+        );
+        self.post(&path, &command).await?;
+        Ok(())
+    }
+
+    async fn create_partitions(
+        &self,
+        command: &CreatePartitions,
+    ) -> Result<CreatedPartitions, Error> {
+        let response = self
+            .post(&get_path(&command.stream_id, &command.topic_id), &command)
+            .await?;
+        let created_partitions = response.json().await?;
+        Ok(created_partitions)
+    }
+
+    async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), Error> {
+        self.delete_with_query(&get_path(&command.stream_id, &command.topic_id), &command)
+            .await?;
+        Ok(())
+    }
+}
+
+fn get_path(stream_id: &Identifier, topic_id: &Identifier) -> String {
+    format!("streams/{}/topics/{}/partitions", stream_id, topic_id)
+}
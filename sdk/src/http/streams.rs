@@ -6,6 +6,7 @@ use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
+use crate::streams::update_stream::UpdateStream;
 use async_trait::async_trait;
 
 const PATH: &str = "/streams";
@@ -34,4 +35,10 @@ impl StreamClient for HttpClient {
         self.delete(&path).await?;
         Ok(())
     }
+
+    async fn update_stream(&self, command: &UpdateStream) -> Result<(), Error> {
+        let path = format!("{}/{}", PATH, command.stream_id);
+        self.put(&path, &command).await?;
+        Ok(())
+    }
 }
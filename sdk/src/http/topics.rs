@@ -1,11 +1,14 @@
 use crate::client::TopicClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::identifier::Identifier;
 use crate::models::topic::{Topic, TopicDetails};
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -14,7 +17,7 @@ impl TopicClient for HttpClient {
         let response = self
             .get(&format!(
                 "{}/{}",
-                get_path(command.stream_id),
+                get_path(&command.stream_id),
                 command.topic_id
             ))
             .await?;
@@ -23,23 +26,39 @@ impl TopicClient for HttpClient {
     }
 
     async fn get_topics(&self, command: &GetTopics) -> Result<Vec<Topic>, Error> {
-        let response = self.get(&get_path(command.stream_id)).await?;
+        let response = self.get(&get_path(&command.stream_id)).await?;
         let topics = response.json().await?;
         Ok(topics)
     }
 
     async fn create_topic(&self, command: &CreateTopic) -> Result<(), Error> {
-        self.post(&get_path(command.stream_id), &command).await?;
+        self.post(&get_path(&command.stream_id), &command).await?;
         Ok(())
     }
 
     async fn delete_topic(&self, command: &DeleteTopic) -> Result<(), Error> {
-        let path = format!("{}/{}", get_path(command.stream_id), command.topic_id);
+        let path = format!("{}/{}", get_path(&command.stream_id), command.topic_id);
         self.delete(&path).await?;
         Ok(())
     }
+
+    async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), Error> {
+        let path = format!(
+            "{}/{}/purge",
+            get_path(&command.stream_id),
+            command.topic_id
+        );
+        self.post(&path, &command).await?;
+        Ok(())
+    }
+
+    async fn update_topic(&self, command: &UpdateTopic) -> Result<(), Error> {
+        let path = format!("{}/{}", get_path(&command.stream_id), command.topic_id);
+        self.put(&path, &command).await?;
+        Ok(())
+    }
 }
 
-fn get_path(stream_id: u32) -> String {
+fn get_path(stream_id: &Identifier) -> String {
     format!("streams/{}/topics", stream_id)
 }
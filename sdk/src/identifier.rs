@@ -0,0 +1,160 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+pub const MAX_NAME_LENGTH: usize = 255;
+const NUMERIC_KIND: u8 = 1;
+const NAME_KIND: u8 = 2;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(untagged)]
+pub enum Identifier {
+    Numeric(u32),
+    Name(String),
+}
+
+impl Identifier {
+    pub fn numeric(id: u32) -> Self {
+        Identifier::Numeric(id)
+    }
+
+    pub fn named(name: &str) -> Self {
+        Identifier::Name(name.to_string())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Identifier::Numeric(id) => *id > 0,
+            Identifier::Name(name) => !name.is_empty() && name.len() <= MAX_NAME_LENGTH,
+        }
+    }
+
+    pub fn get_size_bytes(&self) -> usize {
+        match self {
+            Identifier::Numeric(_) => 6,
+            Identifier::Name(name) => 2 + name.len(),
+        }
+    }
+}
+
+// The name can't consist of the digits only, as it would be read from the string as the numeric ID instead.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_NAME_LENGTH
+        && !name.chars().all(|character| character.is_ascii_digit())
+}
+
+impl Default for Identifier {
+    fn default() -> Self {
+        Identifier::Numeric(1)
+    }
+}
+
+impl From<u32> for Identifier {
+    fn from(id: u32) -> Self {
+        Identifier::Numeric(id)
+    }
+}
+
+impl FromStr for Identifier {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.parse::<u32>() {
+            Ok(id) => Ok(Identifier::Numeric(id)),
+            Err(_) => Ok(Identifier::Name(input.to_string())),
+        }
+    }
+}
+
+impl BytesSerializable for Identifier {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.get_size_bytes());
+        match self {
+            Identifier::Numeric(id) => {
+                bytes.push(NUMERIC_KIND);
+                bytes.push(4);
+                bytes.extend(id.to_le_bytes());
+            }
+            Identifier::Name(name) => {
+                bytes.push(NAME_KIND);
+                bytes.push(name.len() as u8);
+                bytes.extend(name.as_bytes());
+            }
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 3 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let length = bytes[1] as usize;
+        if bytes.len() < 2 + length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let value = &bytes[2..2 + length];
+        match bytes[0] {
+            NUMERIC_KIND if length == 4 => {
+                Ok(Identifier::Numeric(u32::from_le_bytes(value.try_into()?)))
+            }
+            NAME_KIND => Ok(Identifier::Name(from_utf8(value)?.to_string())),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Identifier::Numeric(id) => write!(f, "{}", id),
+            Identifier::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
+        for identifier in [Identifier::numeric(1), Identifier::named("test")] {
+            let mut bytes = identifier.as_bytes();
+            assert_eq!(bytes.len(), identifier.get_size_bytes());
+            bytes.extend(10u32.to_le_bytes());
+
+            let deserialized_identifier = Identifier::from_bytes(&bytes).unwrap();
+            assert_eq!(deserialized_identifier, identifier);
+        }
+    }
+
+    #[test]
+    fn should_be_read_from_string_as_numeric_id_or_name() {
+        assert_eq!(Identifier::from_str("1").unwrap(), Identifier::numeric(1));
+        assert_eq!(
+            Identifier::from_str("test").unwrap(),
+            Identifier::named("test")
+        );
+    }
+
+    #[test]
+    fn should_not_be_valid_given_zero_id_or_empty_name() {
+        assert!(!Identifier::numeric(0).is_valid());
+        assert!(!Identifier::named("").is_valid());
+        assert!(!Identifier::named(&"a".repeat(MAX_NAME_LENGTH + 1)).is_valid());
+        assert!(Identifier::named("test").is_valid());
+    }
+
+    #[test]
+    fn should_not_be_valid_name_given_empty_too_long_or_all_digit_name() {
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name(&"a".repeat(MAX_NAME_LENGTH + 1)));
+        assert!(!is_valid_name("123"));
+        assert!(is_valid_name("123a"));
+        assert!(is_valid_name("test"));
+    }
+}
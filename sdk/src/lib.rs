@@ -1,4 +1,5 @@
 pub mod args;
+pub mod batch;
 pub mod binary;
 pub mod bytes_serializable;
 pub mod client;
@@ -6,13 +7,16 @@ pub mod client_error;
 pub mod client_provider;
 pub mod clients;
 pub mod command;
+pub mod compression;
 pub mod consumer_groups;
 pub mod consumer_type;
 pub mod error;
 pub mod http;
+pub mod identifier;
 pub mod messages;
 pub mod models;
 pub mod offsets;
+pub mod partitions;
 pub mod quic;
 pub mod streams;
 pub mod system;
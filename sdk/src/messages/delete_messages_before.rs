@@ -0,0 +1,181 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DeleteMessagesBefore {
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    pub partition_id: u32,
+    pub offset: u64,
+}
+
+impl Default for DeleteMessagesBefore {
+    fn default() -> Self {
+        DeleteMessagesBefore {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            offset: 0,
+        }
+    }
+}
+
+impl CommandPayload for DeleteMessagesBefore {}
+
+impl Validatable for DeleteMessagesBefore {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.stream_id.is_valid() {
+            return Err(Error::InvalidStreamId);
+        }
+
+        if !self.topic_id.is_valid() {
+            return Err(Error::InvalidTopicId);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for DeleteMessagesBefore {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_str(parts[0])?;
+        let topic_id = Identifier::from_str(parts[1])?;
+        let partition_id = parts[2].parse::<u32>()?;
+        let offset = parts[3].parse::<u64>()?;
+        let command = DeleteMessagesBefore {
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for DeleteMessagesBefore {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            12 + self.stream_id.get_size_bytes() + self.topic_id.get_size_bytes(),
+        );
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
+        bytes.extend(self.partition_id.to_le_bytes());
+        bytes.extend(self.offset.to_le_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteMessagesBefore, Error> {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes();
+        if bytes.len() != position + 12 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into()?);
+        let command = DeleteMessagesBefore {
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for DeleteMessagesBefore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.offset
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = DeleteMessagesBefore {
+            stream_id: Identifier::numeric(1),
+            topic_id: Identifier::numeric(2),
+            partition_id: 3,
+            offset: 4,
+        };
+
+        let bytes = command.as_bytes();
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(offset, command.offset);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::numeric(2);
+        let partition_id = 3u32;
+        let offset = 4u64;
+
+        let mut bytes = Vec::with_capacity(20);
+        bytes.extend(stream_id.as_bytes());
+        bytes.extend(topic_id.as_bytes());
+        bytes.extend(partition_id.to_le_bytes());
+        bytes.extend(offset.to_le_bytes());
+
+        let command = DeleteMessagesBefore::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.offset, offset);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::numeric(2);
+        let partition_id = 3u32;
+        let offset = 4u64;
+        let input = format!("{}|{}|{}|{}", stream_id, topic_id, partition_id, offset);
+        let command = DeleteMessagesBefore::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.offset, offset);
+    }
+}
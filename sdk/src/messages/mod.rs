@@ -1,2 +1,3 @@
+pub mod delete_messages_before;
 pub mod poll_messages;
 pub mod send_messages;
@@ -1,7 +1,9 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
+use crate::compression::CompressionAlgorithm;
 use crate::consumer_type::ConsumerType;
 use crate::error::Error;
+use crate::identifier::Identifier;
 use crate::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -14,9 +16,9 @@ pub struct PollMessages {
     #[serde(default = "default_consumer_id")]
     pub consumer_id: u32,
     #[serde(skip)]
-    pub stream_id: u32,
+    pub stream_id: Identifier,
     #[serde(skip)]
-    pub topic_id: u32,
+    pub topic_id: Identifier,
     #[serde(default = "default_partition_id")]
     pub partition_id: u32,
     #[serde(default = "default_kind")]
@@ -29,6 +31,8 @@ pub struct PollMessages {
     pub auto_commit: bool,
     #[serde(skip)]
     pub format: Format,
+    #[serde(default)]
+    pub compression: CompressionAlgorithm,
 }
 
 impl Default for PollMessages {
@@ -36,14 +40,15 @@ impl Default for PollMessages {
         Self {
             consumer_type: default_consumer_type(),
             consumer_id: default_consumer_id(),
-            stream_id: 1,
-            topic_id: 1,
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
             partition_id: default_partition_id(),
             kind: default_kind(),
             value: default_value(),
             count: default_count(),
             auto_commit: false,
             format: Format::None,
+            compression: CompressionAlgorithm::default(),
         }
     }
 }
@@ -95,11 +100,11 @@ fn default_count() -> u32 {
 
 impl Validatable for PollMessages {
     fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+        if !self.stream_id.is_valid() {
             return Err(Error::InvalidStreamId);
         }
 
-        if self.topic_id == 0 {
+        if !self.topic_id.is_valid() {
             return Err(Error::InvalidTopicId);
         }
 
@@ -166,8 +171,8 @@ impl FromStr for PollMessages {
 
         let consumer_type = ConsumerType::from_str(parts[0])?;
         let consumer_id = parts[1].parse::<u32>()?;
-        let stream_id = parts[2].parse::<u32>()?;
-        let topic_id = parts[3].parse::<u32>()?;
+        let stream_id = Identifier::from_str(parts[2])?;
+        let topic_id = Identifier::from_str(parts[3])?;
         let partition_id = parts[4].parse::<u32>()?;
         let kind = Kind::from_str(parts[5])?;
         let value = parts[6].parse::<u64>()?;
@@ -188,6 +193,10 @@ impl FromStr for PollMessages {
             },
             None => Format::None,
         };
+        let compression = match parts.get(10) {
+            Some(compression) => CompressionAlgorithm::from_str(compression)?,
+            None => CompressionAlgorithm::None,
+        };
 
         let command = PollMessages {
             consumer_type,
@@ -200,6 +209,7 @@ impl FromStr for PollMessages {
             count,
             auto_commit,
             format,
+            compression,
         };
         command.validate()?;
         Ok(command)
@@ -208,11 +218,13 @@ impl FromStr for PollMessages {
 
 impl BytesSerializable for PollMessages {
     fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(31);
+        let mut bytes = Vec::with_capacity(
+            24 + self.stream_id.get_size_bytes() + self.topic_id.get_size_bytes(),
+        );
         bytes.extend(self.consumer_type.as_code().to_le_bytes());
         bytes.extend(self.consumer_id.to_le_bytes());
-        bytes.extend(self.stream_id.to_le_bytes());
-        bytes.extend(self.topic_id.to_le_bytes());
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
         bytes.extend(self.partition_id.to_le_bytes());
         bytes.extend(self.kind.as_code().to_le_bytes());
         bytes.extend(self.value.to_le_bytes());
@@ -222,30 +234,42 @@ impl BytesSerializable for PollMessages {
         } else {
             bytes.extend(0u8.to_le_bytes());
         }
+        bytes.extend(self.compression.as_code().to_le_bytes());
 
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        if bytes.len() != 31 {
+        if bytes.len() < 5 {
             return Err(Error::InvalidCommand);
         }
 
         let consumer_type = ConsumerType::from_code(bytes[0])?;
         let consumer_id = u32::from_le_bytes(bytes[1..5].try_into()?);
-        let stream_id = u32::from_le_bytes(bytes[5..9].try_into()?);
-        let topic_id = u32::from_le_bytes(bytes[9..13].try_into()?);
-        let partition_id = u32::from_le_bytes(bytes[13..17].try_into()?);
-        let kind = Kind::from_code(bytes[17])?;
-        let value = u64::from_le_bytes(bytes[18..26].try_into()?);
-        let count = u32::from_le_bytes(bytes[26..30].try_into()?);
-        let auto_commit = bytes[30];
+        let stream_id = Identifier::from_bytes(&bytes[5..])?;
+        let mut position = 5 + stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes();
+        // The command of the older clients ends before the compression, which then defaults to none.
+        if bytes.len() != position + 18 && bytes.len() != position + 19 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let kind = Kind::from_code(bytes[position + 4])?;
+        let value = u64::from_le_bytes(bytes[position + 5..position + 13].try_into()?);
+        let count = u32::from_le_bytes(bytes[position + 13..position + 17].try_into()?);
+        let auto_commit = bytes[position + 17];
         let auto_commit = match auto_commit {
             0 => false,
             1 => true,
             _ => false,
         };
         let format = Format::None;
+        let compression = match bytes.get(position + 18) {
+            Some(code) => CompressionAlgorithm::from_code(*code)?,
+            None => CompressionAlgorithm::None,
+        };
 
         let command = PollMessages {
             consumer_type,
@@ -258,6 +282,7 @@ impl BytesSerializable for PollMessages {
             count,
             auto_commit,
             format,
+            compression,
         };
         command.validate()?;
         Ok(command)
@@ -299,31 +324,35 @@ mod tests {
         let command = PollMessages {
             consumer_type: ConsumerType::Consumer,
             consumer_id: 1,
-            stream_id: 2,
-            topic_id: 3,
+            stream_id: Identifier::numeric(2),
+            topic_id: Identifier::named("topic"),
             partition_id: 4,
             kind: Kind::Offset,
             value: 2,
             count: 3,
             auto_commit: true,
             format: Format::Binary,
+            compression: CompressionAlgorithm::Lz4,
         };
 
         let bytes = command.as_bytes();
         let consumer_type = ConsumerType::from_code(bytes[0]).unwrap();
         let consumer_id = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
-        let stream_id = u32::from_le_bytes(bytes[5..9].try_into().unwrap());
-        let topic_id = u32::from_le_bytes(bytes[9..13].try_into().unwrap());
-        let partition_id = u32::from_le_bytes(bytes[13..17].try_into().unwrap());
-        let kind = Kind::from_code(bytes[17]).unwrap();
-        let value = u64::from_le_bytes(bytes[18..26].try_into().unwrap());
-        let count = u32::from_le_bytes(bytes[26..30].try_into().unwrap());
-        let auto_commit = bytes[30];
+        let stream_id = Identifier::from_bytes(&bytes[5..]).unwrap();
+        let mut position = 5 + stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let kind = Kind::from_code(bytes[position + 4]).unwrap();
+        let value = u64::from_le_bytes(bytes[position + 5..position + 13].try_into().unwrap());
+        let count = u32::from_le_bytes(bytes[position + 13..position + 17].try_into().unwrap());
+        let auto_commit = bytes[position + 17];
         let auto_commit = match auto_commit {
             0 => false,
             1 => true,
             _ => false,
         };
+        let compression = CompressionAlgorithm::from_code(bytes[position + 18]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer_type, command.consumer_type);
@@ -335,29 +364,32 @@ mod tests {
         assert_eq!(value, command.value);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert_eq!(compression, command.compression);
     }
 
     #[test]
     fn should_be_deserialized_from_bytes() {
         let consumer_type = ConsumerType::Consumer;
         let consumer_id = 1u32;
-        let stream_id = 2u32;
-        let topic_id = 3u32;
+        let stream_id = Identifier::numeric(2);
+        let topic_id = Identifier::named("topic");
         let partition_id = 4u32;
         let kind = Kind::Offset;
         let value = 2u64;
         let count = 3u32;
         let auto_commit = 1u8;
-        let mut bytes = Vec::with_capacity(31);
+        let compression = CompressionAlgorithm::Gzip;
+        let mut bytes = Vec::with_capacity(32);
         bytes.extend(consumer_type.as_code().to_le_bytes());
         bytes.extend(consumer_id.to_le_bytes());
-        bytes.extend(stream_id.to_le_bytes());
-        bytes.extend(topic_id.to_le_bytes());
+        bytes.extend(stream_id.as_bytes());
+        bytes.extend(topic_id.as_bytes());
         bytes.extend(partition_id.to_le_bytes());
         bytes.extend(kind.as_code().to_le_bytes());
         bytes.extend(value.to_le_bytes());
         bytes.extend(count.to_le_bytes());
         bytes.extend(auto_commit.to_le_bytes());
+        bytes.extend(compression.as_code().to_le_bytes());
 
         let command = PollMessages::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -378,14 +410,30 @@ mod tests {
         assert_eq!(command.value, value);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.compression, compression);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_compression() {
+        let command = PollMessages {
+            compression: CompressionAlgorithm::Gzip,
+            ..PollMessages::default()
+        };
+        let mut bytes = command.as_bytes();
+        bytes.pop();
+
+        let deserialized_command = PollMessages::from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized_command.compression, CompressionAlgorithm::None);
+        assert_eq!(deserialized_command.count, command.count);
+        assert!(PollMessages::from_bytes(&bytes[..bytes.len() - 1]).is_err());
     }
 
     #[test]
     fn should_be_read_from_string() {
         let consumer_type = ConsumerType::Consumer;
         let consumer_id = 1u32;
-        let stream_id = 2u32;
-        let topic_id = 3u32;
+        let stream_id = Identifier::numeric(2);
+        let topic_id = Identifier::named("topic");
         let partition_id = 4u32;
         let kind = Kind::Timestamp;
         let value = 2u64;
@@ -1,6 +1,9 @@
+use crate::batch::{MessageBatch, Record, BATCH_HEADER_SIZE};
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
+use crate::compression::CompressionAlgorithm;
 use crate::error::Error;
+use crate::identifier::Identifier;
 use crate::validatable::Validatable;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
@@ -9,19 +12,24 @@ use serde_with::serde_as;
 use std::fmt::Display;
 use std::str::FromStr;
 
-const MAX_PAYLOAD_SIZE: u32 = 10 * 1024 * 1024;
+pub const MAX_PAYLOAD_SIZE: u32 = 10 * 1024 * 1024;
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SendMessages {
     #[serde(skip)]
-    pub stream_id: u32,
+    pub stream_id: Identifier,
     #[serde(skip)]
-    pub topic_id: u32,
+    pub topic_id: Identifier,
     pub key_kind: KeyKind,
     pub key_value: u32,
     #[serde(skip)]
     pub messages_count: u32,
+    #[serde(default)]
+    pub compression: CompressionAlgorithm,
     pub messages: Vec<Message>,
+    // The batch as it was received, so that it can be stored without encoding the messages again.
+    #[serde(skip)]
+    pub batch: Option<MessageBatch>,
 }
 
 #[serde_as]
@@ -50,25 +58,45 @@ fn default_message_id() -> u128 {
 impl Default for SendMessages {
     fn default() -> Self {
         SendMessages {
-            stream_id: 1,
-            topic_id: 1,
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
             key_kind: KeyKind::default(),
             key_value: 1,
             messages_count: 1,
+            compression: CompressionAlgorithm::default(),
             messages: vec![Message::default()],
+            batch: None,
         }
     }
 }
 
 impl CommandPayload for SendMessages {}
 
-impl Validatable for SendMessages {
-    fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+// The received batch is only the encoding of the messages, thus it isn't compared.
+impl PartialEq for SendMessages {
+    fn eq(&self, other: &Self) -> bool {
+        self.stream_id == other.stream_id
+            && self.topic_id == other.topic_id
+            && self.key_kind == other.key_kind
+            && self.key_value == other.key_value
+            && self.messages_count == other.messages_count
+            && self.compression == other.compression
+            && self.messages == other.messages
+    }
+}
+
+impl SendMessages {
+    // The empty payloads (tombstones) are validated by the server, once the cleanup policy of the topic is known.
+    pub fn validate_allowing_tombstones(&self) -> Result<(), Error> {
+        self.validate_messages(true)
+    }
+
+    fn validate_messages(&self, allow_tombstones: bool) -> Result<(), Error> {
+        if !self.stream_id.is_valid() {
             return Err(Error::InvalidStreamId);
         }
 
-        if self.topic_id == 0 {
+        if !self.topic_id.is_valid() {
             return Err(Error::InvalidTopicId);
         }
 
@@ -78,13 +106,17 @@ impl Validatable for SendMessages {
 
         let mut payload_size = 0;
         for message in &self.messages {
+            if message.payload.is_empty() && !allow_tombstones {
+                return Err(Error::EmptyMessagePayload);
+            }
+
             payload_size += message.payload.len() as u32;
             if payload_size > MAX_PAYLOAD_SIZE {
                 return Err(Error::TooBigMessagePayload);
             }
         }
 
-        if payload_size == 0 {
+        if payload_size == 0 && !allow_tombstones {
             return Err(Error::EmptyMessagePayload);
         }
 
@@ -92,6 +124,12 @@ impl Validatable for SendMessages {
     }
 }
 
+impl Validatable for SendMessages {
+    fn validate(&self) -> Result<(), Error> {
+        self.validate_messages(false)
+    }
+}
+
 impl KeyKind {
     pub fn as_code(&self) -> u8 {
         match self {
@@ -107,6 +145,15 @@ impl KeyKind {
             _ => Err(Error::InvalidCommand),
         }
     }
+
+    pub fn calculate_entity_partition_id(entity_id: u32, partitions_count: u32) -> u32 {
+        let partition_id = entity_id % partitions_count;
+        if partition_id == 0 {
+            return partitions_count;
+        }
+
+        partition_id
+    }
 }
 
 impl FromStr for KeyKind {
@@ -120,13 +167,6 @@ impl FromStr for KeyKind {
     }
 }
 
-impl Message {
-    pub fn get_size_bytes(&self) -> u32 {
-        // ID + Length + Payload
-        16 + 4 + self.payload.len() as u32
-    }
-}
-
 impl Default for Message {
     fn default() -> Self {
         let payload = Bytes::from("hello world");
@@ -144,39 +184,6 @@ impl Display for Message {
     }
 }
 
-impl BytesSerializable for Message {
-    fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(self.get_size_bytes() as usize);
-        bytes.extend(self.id.to_le_bytes());
-        bytes.extend(self.length.to_le_bytes());
-        bytes.extend(&self.payload);
-        bytes
-    }
-
-    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        if bytes.len() < 20 {
-            return Err(Error::InvalidCommand);
-        }
-
-        let id = u128::from_le_bytes(bytes[..16].try_into()?);
-        let length = u32::from_le_bytes(bytes[16..20].try_into()?);
-        if length == 0 {
-            return Err(Error::EmptyMessagePayload);
-        }
-
-        let payload = Bytes::from(bytes[20..20 + length as usize].to_vec());
-        if payload.len() != length as usize {
-            return Err(Error::InvalidMessagePayloadLength);
-        }
-
-        Ok(Message {
-            id,
-            length,
-            payload,
-        })
-    }
-}
-
 impl FromStr for Message {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
@@ -210,8 +217,8 @@ impl FromStr for SendMessages {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = parts[0].parse::<u32>()?;
-        let topic_id = parts[1].parse::<u32>()?;
+        let stream_id = Identifier::from_str(parts[0])?;
+        let topic_id = Identifier::from_str(parts[1])?;
         let key_kind = parts[2];
         let key_kind = KeyKind::from_str(key_kind)?;
         let key_value = parts[3].parse::<u32>()?;
@@ -232,7 +239,9 @@ impl FromStr for SendMessages {
             key_kind,
             key_value,
             messages_count,
+            compression: CompressionAlgorithm::default(),
             messages: vec![message],
+            batch: None,
         };
         command.validate()?;
         Ok(command)
@@ -240,56 +249,83 @@ impl FromStr for SendMessages {
 }
 
 impl BytesSerializable for SendMessages {
+    // The messages are sent as a single batch, with the offsets and timestamps assigned by the server.
     fn as_bytes(&self) -> Vec<u8> {
-        let messages_size = self
+        let records = self
             .messages
             .iter()
-            .map(|message| message.get_size_bytes())
-            .sum::<u32>();
+            .enumerate()
+            .map(|(index, message)| Record {
+                offset_delta: index as u32,
+                timestamp_delta: 0,
+                id: message.id,
+                payload: message.payload.clone(),
+            })
+            .collect();
+        let batch = MessageBatch::new(0, 0, 0, 0, records);
+        // The compression of the in-memory records doesn't fail, but if it did, the batch would be sent uncompressed.
+        let batch = match self.compression {
+            CompressionAlgorithm::None => batch,
+            compression => batch.clone().compress(compression).unwrap_or(batch),
+        };
 
-        let mut bytes = Vec::with_capacity(17 + messages_size as usize);
-        bytes.extend(self.stream_id.to_le_bytes());
-        bytes.extend(self.topic_id.to_le_bytes());
+        let mut bytes = Vec::with_capacity(
+            5 + self.stream_id.get_size_bytes()
+                + self.topic_id.get_size_bytes()
+                + batch.get_size_bytes() as usize,
+        );
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
         bytes.extend(self.key_kind.as_code().to_le_bytes());
         bytes.extend(self.key_value.to_le_bytes());
-        bytes.extend(self.messages_count.to_le_bytes());
-        for message in &self.messages {
-            bytes.extend(message.id.to_le_bytes());
-            bytes.extend(message.length.to_le_bytes());
-            bytes.extend(&message.payload);
-        }
-
+        batch.extend(&mut bytes);
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<SendMessages, Error> {
-        if bytes.len() < 18 {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes();
+        if bytes.len() < position + 5 + BATCH_HEADER_SIZE as usize {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into()?);
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into()?);
-        let key_kind = KeyKind::from_code(bytes[8])?;
-        let key_value = u32::from_le_bytes(bytes[9..13].try_into()?);
-        let messages_count = u32::from_le_bytes(bytes[13..17].try_into()?);
-        let messages_payloads = &bytes[17..];
-        let mut position = 0;
-        let mut messages = Vec::with_capacity(messages_count as usize);
-        while position < messages_payloads.len() {
-            let message = Message::from_bytes(&messages_payloads[position..])?;
-            position += message.get_size_bytes() as usize;
-            messages.push(message);
+        let key_kind = KeyKind::from_code(bytes[position])?;
+        let key_value = u32::from_le_bytes(bytes[position + 1..position + 5].try_into()?);
+        position += 5;
+        // The batch is copied once, and the payloads of the messages point to it.
+        let batch = MessageBatch::from_bytes_with_limit(
+            &Bytes::copy_from_slice(&bytes[position..]),
+            MAX_PAYLOAD_SIZE as usize,
+        )?;
+        if batch.get_size_bytes() as usize != bytes.len() - position {
+            return Err(Error::InvalidCommand);
         }
 
+        batch.validate()?;
+        let compression = batch.header.get_compression()?;
+        let messages = batch
+            .records
+            .iter()
+            .map(|record| Message {
+                id: record.id,
+                length: record.payload.len() as u32,
+                payload: record.payload.clone(),
+            })
+            .collect();
+
         let command = SendMessages {
             stream_id,
             topic_id,
             key_kind,
             key_value,
-            messages_count,
+            messages_count: batch.header.records_count,
+            compression,
             messages,
+            batch: Some(batch),
         };
-        command.validate()?;
+        command.validate_allowing_tombstones()?;
         Ok(command)
     }
 }
@@ -332,94 +368,141 @@ mod tests {
         let message_3 = Message::from_str("3|hello 3").unwrap();
         let messages = vec![message_1, message_2, message_3];
         let command = SendMessages {
-            stream_id: 1,
-            topic_id: 2,
+            stream_id: Identifier::numeric(1),
+            topic_id: Identifier::named("topic"),
             key_kind: KeyKind::PartitionId,
             key_value: 4,
             messages_count: messages.len() as u32,
+            compression: CompressionAlgorithm::None,
             messages,
+            batch: None,
         };
 
         let bytes = command.as_bytes();
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
-        let key_kind = KeyKind::from_code(bytes[8]).unwrap();
-        let key_value = u32::from_le_bytes(bytes[9..13].try_into().unwrap());
-        let messages_count = u32::from_le_bytes(bytes[13..17].try_into().unwrap());
-        let messages = &bytes[17..];
-        let command_messages = &command
-            .messages
-            .iter()
-            .map(|message| message.as_bytes())
-            .collect::<Vec<Vec<u8>>>()
-            .concat();
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes();
+        let key_kind = KeyKind::from_code(bytes[position]).unwrap();
+        let key_value = u32::from_le_bytes(bytes[position + 1..position + 5].try_into().unwrap());
+        position += 5;
+        let batch = MessageBatch::from_bytes(&Bytes::copy_from_slice(&bytes[position..])).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(key_kind, command.key_kind);
         assert_eq!(key_value, command.key_value);
-        assert_eq!(messages_count, command.messages_count);
-        assert_eq!(messages, command_messages);
+        assert!(batch.validate().is_ok());
+        assert_eq!(batch.get_size_bytes() as usize, bytes.len() - position);
+        assert_eq!(batch.header.records_count, command.messages_count);
+        for (index, record) in batch.records.iter().enumerate() {
+            let message = &command.messages[index];
+            assert_eq!(record.offset_delta, index as u32);
+            assert_eq!(record.id, message.id);
+            assert_eq!(record.payload, message.payload);
+        }
     }
 
     #[test]
     fn should_be_deserialized_from_bytes() {
-        let stream_id = 1u32;
-        let topic_id = 2u32;
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::named("topic");
         let key_kind = KeyKind::PartitionId;
         let key_value = 4u32;
-        let messages_count = 3u32;
-
-        let message_1 = Message::from_str("hello 1").unwrap();
-        let message_2 = Message::from_str("2|hello 2").unwrap();
-        let message_3 = Message::from_str("3|hello 3").unwrap();
         let messages = vec![
-            message_1.as_bytes(),
-            message_2.as_bytes(),
-            message_3.as_bytes(),
-        ]
-        .concat();
-
-        let mut bytes: Vec<u8> = [stream_id.to_le_bytes(), topic_id.to_le_bytes()].concat();
-
+            Message::from_str("hello 1").unwrap(),
+            Message::from_str("2|hello 2").unwrap(),
+            Message::from_str("3|hello 3").unwrap(),
+        ];
+        let records = messages
+            .iter()
+            .enumerate()
+            .map(|(index, message)| Record {
+                offset_delta: index as u32,
+                timestamp_delta: 0,
+                id: message.id,
+                payload: message.payload.clone(),
+            })
+            .collect();
+        let batch = MessageBatch::new(0, 0, 0, 0, records);
+
+        let mut bytes: Vec<u8> = [stream_id.as_bytes(), topic_id.as_bytes()].concat();
         bytes.extend(key_kind.as_code().to_le_bytes());
         bytes.extend(key_value.to_le_bytes());
-        bytes.extend(messages_count.to_le_bytes());
-        bytes.extend(messages);
+        batch.extend(&mut bytes);
 
         let command = SendMessages::from_bytes(&bytes);
         assert!(command.is_ok());
 
-        let messages_payloads = &bytes[17..];
-        let mut position = 0;
-        let mut messages = Vec::with_capacity(messages_count as usize);
-        while position < messages_payloads.len() {
-            let message = Message::from_bytes(&messages_payloads[position..]).unwrap();
-            position += message.get_size_bytes() as usize;
-            messages.push(message);
-        }
-
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.key_kind, key_kind);
         assert_eq!(command.key_value, key_value);
-        assert_eq!(command.messages_count, messages_count);
-        for i in 0..command.messages_count {
-            let message = &messages[i as usize];
-            let command_message = &command.messages[i as usize];
-            assert_eq!(command_message.id, message.id);
-            assert_eq!(command_message.length, message.length);
-            assert_eq!(command_message.payload, message.payload);
-        }
+        assert_eq!(command.messages_count, messages.len() as u32);
+        assert_eq!(command.messages, messages);
+        assert_eq!(command.batch, Some(batch));
+    }
+
+    #[test]
+    fn should_be_deserialized_from_compressed_bytes() {
+        let command = SendMessages {
+            messages_count: 2,
+            compression: CompressionAlgorithm::Zstd,
+            messages: vec![
+                Message::from_str("1|hello 1").unwrap(),
+                Message::from_str("2|hello 2").unwrap(),
+            ],
+            ..SendMessages::default()
+        };
+
+        let bytes = command.as_bytes();
+        // The default numeric identifiers of the stream and the topic take 6 bytes each.
+        let batch = MessageBatch::from_bytes(&Bytes::copy_from_slice(&bytes[17..])).unwrap();
+        assert!(batch.is_compressed());
+
+        let deserialized_command = SendMessages::from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_empty_payload_failing_validation() {
+        let command = SendMessages {
+            messages: vec![Message {
+                id: 1,
+                length: 0,
+                payload: Bytes::new(),
+            }],
+            ..SendMessages::default()
+        };
+
+        let deserialized_command = SendMessages::from_bytes(&command.as_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
+        assert!(matches!(
+            deserialized_command.validate(),
+            Err(Error::EmptyMessagePayload)
+        ));
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_bytes_with_invalid_checksum() {
+        let command = SendMessages::default();
+        let mut bytes = command.as_bytes();
+        let last_position = bytes.len() - 1;
+        bytes[last_position] ^= 1;
+
+        assert!(matches!(
+            SendMessages::from_bytes(&bytes),
+            Err(Error::InvalidBatchChecksum(_, _, _))
+        ));
     }
 
     // For now, we only support a single payload.
     #[test]
     fn should_be_read_from_string() {
-        let stream_id = 1u32;
-        let topic_id = 2u32;
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::named("topic");
         let key_kind = KeyKind::PartitionId;
         let key_value = 4u32;
         let messages_count = 1u32;
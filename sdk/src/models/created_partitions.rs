@@ -0,0 +1,45 @@
+use crate::messages::send_messages::KeyKind;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatedPartitions {
+    pub previous_partitions_count: u32,
+    pub partitions_count: u32,
+}
+
+impl CreatedPartitions {
+    pub fn get_previous_partition_id(&self, entity_id: u32) -> u32 {
+        KeyKind::calculate_entity_partition_id(entity_id, self.previous_partitions_count)
+    }
+
+    pub fn get_partition_id(&self, entity_id: u32) -> u32 {
+        KeyKind::calculate_entity_partition_id(entity_id, self.partitions_count)
+    }
+
+    pub fn is_partition_changed(&self, entity_id: u32) -> bool {
+        self.get_previous_partition_id(entity_id) != self.get_partition_id(entity_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_calculate_previous_and_current_partition_id_for_entity_id() {
+        let created_partitions = CreatedPartitions {
+            previous_partitions_count: 2,
+            partitions_count: 3,
+        };
+
+        assert_eq!(created_partitions.get_previous_partition_id(1), 1);
+        assert_eq!(created_partitions.get_partition_id(1), 1);
+        assert!(!created_partitions.is_partition_changed(1));
+        assert_eq!(created_partitions.get_previous_partition_id(3), 1);
+        assert_eq!(created_partitions.get_partition_id(3), 3);
+        assert!(created_partitions.is_partition_changed(3));
+        assert_eq!(created_partitions.get_previous_partition_id(4), 2);
+        assert_eq!(created_partitions.get_partition_id(4), 1);
+        assert!(created_partitions.is_partition_changed(4));
+    }
+}
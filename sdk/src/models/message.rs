@@ -16,7 +16,7 @@ pub struct Message {
 
 impl Message {
     pub fn get_size_bytes(&self) -> u32 {
-        // Offset + Timestamp + ID + Length + Payload
-        8 + 8 + 16 + 4 + self.payload.len() as u32
+        // Offset delta + Timestamp delta + ID + Length + Payload
+        4 + 4 + 16 + 4 + self.payload.len() as u32
     }
 }
@@ -1,5 +1,6 @@
 pub mod client_info;
 pub mod consumer_group;
+pub mod created_partitions;
 pub mod message;
 pub mod offset;
 pub mod partition;
@@ -1,4 +1,5 @@
 use crate::models::partition::Partition;
+use crate::topics::create_topic::TopicConfigOverrides;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,5 +14,6 @@ pub struct TopicDetails {
     pub id: u32,
     pub name: String,
     pub partitions_count: u32,
+    pub overrides: TopicConfigOverrides,
     pub partitions: Vec<Partition>,
 }
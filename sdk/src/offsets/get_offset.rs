@@ -2,6 +2,7 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::consumer_type::ConsumerType;
 use crate::error::Error;
+use crate::identifier::Identifier;
 use crate::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -14,9 +15,9 @@ pub struct GetOffset {
     #[serde(default = "default_consumer_id")]
     pub consumer_id: u32,
     #[serde(skip)]
-    pub stream_id: u32,
+    pub stream_id: Identifier,
     #[serde(skip)]
-    pub topic_id: u32,
+    pub topic_id: Identifier,
     #[serde(default = "default_partition_id")]
     pub partition_id: u32,
 }
@@ -26,8 +27,8 @@ impl Default for GetOffset {
         GetOffset {
             consumer_type: default_consumer_type(),
             consumer_id: default_consumer_id(),
-            stream_id: 1,
-            topic_id: 1,
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
             partition_id: default_partition_id(),
         }
     }
@@ -49,11 +50,11 @@ fn default_partition_id() -> u32 {
 
 impl Validatable for GetOffset {
     fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+        if !self.stream_id.is_valid() {
             return Err(Error::InvalidStreamId);
         }
 
-        if self.topic_id == 0 {
+        if !self.topic_id.is_valid() {
             return Err(Error::InvalidTopicId);
         }
 
@@ -71,8 +72,8 @@ impl FromStr for GetOffset {
 
         let consumer_type = ConsumerType::from_str(parts[0])?;
         let consumer_id = parts[1].parse::<u32>()?;
-        let stream_id = parts[2].parse::<u32>()?;
-        let topic_id = parts[3].parse::<u32>()?;
+        let stream_id = Identifier::from_str(parts[2])?;
+        let topic_id = Identifier::from_str(parts[3])?;
         let partition_id = parts[4].parse::<u32>()?;
         let command = GetOffset {
             consumer_type,
@@ -88,25 +89,33 @@ impl FromStr for GetOffset {
 
 impl BytesSerializable for GetOffset {
     fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(17);
+        let mut bytes = Vec::with_capacity(
+            9 + self.stream_id.get_size_bytes() + self.topic_id.get_size_bytes(),
+        );
         bytes.extend(self.consumer_type.as_code().to_le_bytes());
         bytes.extend(self.consumer_id.to_le_bytes());
-        bytes.extend(self.stream_id.to_le_bytes());
-        bytes.extend(self.topic_id.to_le_bytes());
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
         bytes.extend(self.partition_id.to_le_bytes());
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<GetOffset, Error> {
-        if bytes.len() != 17 {
+        if bytes.len() < 5 {
             return Err(Error::InvalidCommand);
         }
 
         let consumer_type = ConsumerType::from_code(bytes[0])?;
         let consumer_id = u32::from_le_bytes(bytes[1..5].try_into()?);
-        let stream_id = u32::from_le_bytes(bytes[5..9].try_into()?);
-        let topic_id = u32::from_le_bytes(bytes[9..13].try_into()?);
-        let partition_id = u32::from_le_bytes(bytes[13..17].try_into()?);
+        let stream_id = Identifier::from_bytes(&bytes[5..])?;
+        let mut position = 5 + stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes();
+        if bytes.len() != position + 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
         let command = GetOffset {
             consumer_type,
             consumer_id,
@@ -138,17 +147,19 @@ mod tests {
         let command = GetOffset {
             consumer_type: ConsumerType::Consumer,
             consumer_id: 1,
-            stream_id: 2,
-            topic_id: 3,
+            stream_id: Identifier::numeric(2),
+            topic_id: Identifier::named("topic"),
             partition_id: 4,
         };
 
         let bytes = command.as_bytes();
         let consumer_type = ConsumerType::from_code(bytes[0]).unwrap();
         let consumer_id = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
-        let stream_id = u32::from_le_bytes(bytes[5..9].try_into().unwrap());
-        let topic_id = u32::from_le_bytes(bytes[9..13].try_into().unwrap());
-        let partition_id = u32::from_le_bytes(bytes[13..17].try_into().unwrap());
+        let stream_id = Identifier::from_bytes(&bytes[5..]).unwrap();
+        let mut position = 5 + stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer_type, command.consumer_type);
@@ -162,15 +173,15 @@ mod tests {
     fn should_be_deserialized_from_bytes() {
         let consumer_type = ConsumerType::Consumer;
         let consumer_id = 1u32;
-        let stream_id = 2u32;
-        let topic_id = 3u32;
+        let stream_id = Identifier::numeric(2);
+        let topic_id = Identifier::named("topic");
         let partition_id = 4u32;
 
         let mut bytes = Vec::with_capacity(17);
         bytes.extend(consumer_type.as_code().to_le_bytes());
         bytes.extend(consumer_id.to_le_bytes());
-        bytes.extend(stream_id.to_le_bytes());
-        bytes.extend(topic_id.to_le_bytes());
+        bytes.extend(stream_id.as_bytes());
+        bytes.extend(topic_id.as_bytes());
         bytes.extend(partition_id.to_le_bytes());
 
         let command = GetOffset::from_bytes(&bytes);
@@ -188,8 +199,8 @@ mod tests {
     fn should_be_read_from_string() {
         let consumer_type = ConsumerType::Consumer;
         let consumer_id = 1u32;
-        let stream_id = 2u32;
-        let topic_id = 3u32;
+        let stream_id = Identifier::numeric(2);
+        let topic_id = Identifier::named("topic");
         let partition_id = 4u32;
         let input = format!(
             "{}|{}|{}|{}|{}",
@@ -0,0 +1,4 @@
+pub mod create_partitions;
+pub mod delete_partitions;
+pub mod purge_partition;
+pub mod rebuild_indexes;
@@ -0,0 +1,167 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RebuildIndexes {
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    #[serde(skip)]
+    pub partition_id: u32,
+}
+
+impl CommandPayload for RebuildIndexes {}
+
+impl Default for RebuildIndexes {
+    fn default() -> Self {
+        RebuildIndexes {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+        }
+    }
+}
+
+impl Validatable for RebuildIndexes {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.stream_id.is_valid() {
+            return Err(Error::InvalidStreamId);
+        }
+
+        if !self.topic_id.is_valid() {
+            return Err(Error::InvalidTopicId);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for RebuildIndexes {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 3 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_str(parts[0])?;
+        let topic_id = Identifier::from_str(parts[1])?;
+        let partition_id = parts[2].parse::<u32>()?;
+        let command = RebuildIndexes {
+            stream_id,
+            topic_id,
+            partition_id,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for RebuildIndexes {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            4 + self.stream_id.get_size_bytes() + self.topic_id.get_size_bytes(),
+        );
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
+        bytes.extend(self.partition_id.to_le_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<RebuildIndexes, Error> {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes();
+        if bytes.len() != position + 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let command = RebuildIndexes {
+            stream_id,
+            topic_id,
+            partition_id,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RebuildIndexes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RebuildIndexes {
+            stream_id: Identifier::numeric(1),
+            topic_id: Identifier::numeric(2),
+            partition_id: 3,
+        };
+
+        let bytes = command.as_bytes();
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::numeric(2);
+        let partition_id = 3u32;
+        let bytes = [
+            stream_id.as_bytes(),
+            topic_id.as_bytes(),
+            partition_id.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let command = RebuildIndexes::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::numeric(2);
+        let partition_id = 3u32;
+        let input = format!("{}|{}|{}", stream_id, topic_id, partition_id);
+        let command = RebuildIndexes::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+    }
+}
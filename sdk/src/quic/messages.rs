@@ -1,6 +1,7 @@
 use crate::binary;
 use crate::client::MessageClient;
 use crate::error::Error;
+use crate::messages::delete_messages_before::DeleteMessagesBefore;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::message::Message;
@@ -20,6 +21,10 @@ impl MessageClient for QuicClient {
         binary::messages::send_messages(self, command).await
     }
 
+    async fn delete_messages_before(&self, command: &DeleteMessagesBefore) -> Result<(), Error> {
+        binary::messages::delete_messages_before(self, command).await
+    }
+
     async fn store_offset(&self, command: &StoreOffset) -> Result<(), Error> {
         binary::messages::store_offset(self, command).await
     }
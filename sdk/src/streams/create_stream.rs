@@ -1,16 +1,19 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
+use crate::identifier;
+use crate::topics::create_topic::as_optional;
 use crate::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::{from_utf8, FromStr};
 
-const MAX_NAME_LENGTH: usize = 1000;
+pub const MAX_NAME_LENGTH: usize = identifier::MAX_NAME_LENGTH;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateStream {
-    pub stream_id: u32,
+    #[serde(default)]
+    pub stream_id: Option<u32>,
     pub name: String,
 }
 
@@ -19,7 +22,7 @@ impl CommandPayload for CreateStream {}
 impl Default for CreateStream {
     fn default() -> Self {
         CreateStream {
-            stream_id: 1,
+            stream_id: Some(1),
             name: "stream".to_string(),
         }
     }
@@ -27,11 +30,11 @@ impl Default for CreateStream {
 
 impl Validatable for CreateStream {
     fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+        if self.stream_id == Some(0) {
             return Err(Error::InvalidStreamId);
         }
 
-        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
+        if !identifier::is_valid_name(&self.name) {
             return Err(Error::InvalidStreamName);
         }
 
@@ -43,12 +46,11 @@ impl FromStr for CreateStream {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            return Err(Error::InvalidCommand);
-        }
-
-        let stream_id = parts[0].parse::<u32>()?;
-        let name = parts[1].to_string();
+        let (stream_id, name) = match parts.len() {
+            1 => (None, parts[0].to_string()),
+            2 => (as_optional(parts[0].parse::<u32>()?), parts[1].to_string()),
+            _ => return Err(Error::InvalidCommand),
+        };
         let command = CreateStream { stream_id, name };
         command.validate()?;
         Ok(command)
@@ -58,7 +60,7 @@ impl FromStr for CreateStream {
 impl BytesSerializable for CreateStream {
     fn as_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(4 + self.name.len());
-        bytes.extend(&self.stream_id.to_le_bytes());
+        bytes.extend(&self.stream_id.unwrap_or(0).to_le_bytes());
         bytes.extend(self.name.as_bytes());
         bytes
     }
@@ -68,7 +70,7 @@ impl BytesSerializable for CreateStream {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into()?);
+        let stream_id = as_optional(u32::from_le_bytes(bytes[..4].try_into()?));
         let name = from_utf8(&bytes[4..])?.to_string();
         let command = CreateStream { stream_id, name };
         command.validate()?;
@@ -78,7 +80,7 @@ impl BytesSerializable for CreateStream {
 
 impl Display for CreateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.name)
+        write!(f, "{}|{}", self.stream_id.unwrap_or(0), self.name)
     }
 }
 
@@ -89,12 +91,12 @@ mod tests {
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = CreateStream {
-            stream_id: 1,
+            stream_id: Some(1),
             name: "test".to_string(),
         };
 
         let bytes = command.as_bytes();
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let stream_id = as_optional(u32::from_le_bytes(bytes[..4].try_into().unwrap()));
         let name = from_utf8(&bytes[4..]).unwrap();
 
         assert!(!bytes.is_empty());
@@ -111,7 +113,7 @@ mod tests {
         assert!(command.is_ok());
 
         let command = command.unwrap();
-        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.stream_id, Some(stream_id));
         assert_eq!(command.name, name);
     }
 
@@ -124,7 +126,30 @@ mod tests {
         assert!(command.is_ok());
 
         let command = command.unwrap();
-        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.stream_id, Some(stream_id));
         assert_eq!(command.name, name);
     }
+
+    #[test]
+    fn should_be_read_from_string_without_id() {
+        let name = "test".to_string();
+        for input in [name.clone(), format!("0|{}", name)] {
+            let command = CreateStream::from_str(&input);
+            assert!(command.is_ok());
+
+            let command = command.unwrap();
+            assert_eq!(command.stream_id, None);
+            assert_eq!(command.name, name);
+        }
+    }
+
+    #[test]
+    fn should_not_be_valid_given_all_digit_name() {
+        let command = CreateStream {
+            name: "123".to_string(),
+            ..Default::default()
+        };
+
+        assert!(matches!(command.validate(), Err(Error::InvalidStreamName)));
+    }
 }
@@ -1,27 +1,22 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
+use crate::identifier::Identifier;
 use crate::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
 pub struct DeleteStream {
-    pub stream_id: u32,
+    pub stream_id: Identifier,
 }
 
 impl CommandPayload for DeleteStream {}
 
-impl Default for DeleteStream {
-    fn default() -> Self {
-        DeleteStream { stream_id: 1 }
-    }
-}
-
 impl Validatable for DeleteStream {
     fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+        if !self.stream_id.is_valid() {
             return Err(Error::InvalidStreamId);
         }
 
@@ -37,7 +32,7 @@ impl FromStr for DeleteStream {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = parts[0].parse::<u32>()?;
+        let stream_id = Identifier::from_str(parts[0])?;
         let command = DeleteStream { stream_id };
         command.validate()?;
         Ok(command)
@@ -46,17 +41,15 @@ impl FromStr for DeleteStream {
 
 impl BytesSerializable for DeleteStream {
     fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(4);
-        bytes.extend(self.stream_id.to_le_bytes());
-        bytes
+        self.stream_id.as_bytes()
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<DeleteStream, Error> {
-        if bytes.len() != 4 {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        if bytes.len() != stream_id.get_size_bytes() {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = u32::from_le_bytes(bytes.try_into()?);
         let command = DeleteStream { stream_id };
         command.validate()?;
         Ok(command)
@@ -75,10 +68,12 @@ mod tests {
 
     #[test]
     fn should_be_serialized_as_bytes() {
-        let command = DeleteStream { stream_id: 1 };
+        let command = DeleteStream {
+            stream_id: Identifier::numeric(1),
+        };
 
         let bytes = command.as_bytes();
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -86,8 +81,8 @@ mod tests {
 
     #[test]
     fn should_be_deserialized_from_bytes() {
-        let stream_id = 1u32;
-        let bytes = stream_id.to_le_bytes();
+        let stream_id = Identifier::named("test");
+        let bytes = stream_id.as_bytes();
         let command = DeleteStream::from_bytes(&bytes);
         assert!(command.is_ok());
 
@@ -97,7 +92,7 @@ mod tests {
 
     #[test]
     fn should_be_read_from_string() {
-        let stream_id = 1u32;
+        let stream_id = Identifier::numeric(1);
         let input = format!("{}", stream_id);
         let command = DeleteStream::from_str(&input);
         assert!(command.is_ok());
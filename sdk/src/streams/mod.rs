@@ -2,3 +2,4 @@ pub mod create_stream;
 pub mod delete_stream;
 pub mod get_stream;
 pub mod get_streams;
+pub mod update_stream;
@@ -0,0 +1,143 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct UpdateStream {
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    pub name: String,
+}
+
+impl CommandPayload for UpdateStream {}
+
+impl Default for UpdateStream {
+    fn default() -> Self {
+        UpdateStream {
+            stream_id: Identifier::default(),
+            name: "stream".to_string(),
+        }
+    }
+}
+
+impl Validatable for UpdateStream {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.stream_id.is_valid() {
+            return Err(Error::InvalidStreamId);
+        }
+
+        if !identifier::is_valid_name(&self.name) {
+            return Err(Error::InvalidStreamName);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for UpdateStream {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_str(parts[0])?;
+        let name = parts[1].to_string();
+        let command = UpdateStream { stream_id, name };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for UpdateStream {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.stream_id.get_size_bytes() + self.name.len());
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.name.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateStream, Error> {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let name = from_utf8(&bytes[stream_id.get_size_bytes()..])?.to_string();
+        let command = UpdateStream { stream_id, name };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.stream_id, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateStream {
+            stream_id: Identifier::numeric(1),
+            name: "test".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let name = from_utf8(&bytes[stream_id.get_size_bytes()..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(name, command.name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::named("stream");
+        let name = "test".to_string();
+        let bytes = [stream_id.as_bytes(), name.as_bytes().to_vec()].concat();
+        let command = UpdateStream::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.name, name);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1);
+        let name = "test".to_string();
+        let input = format!("{}|{}", stream_id, name);
+        let command = UpdateStream::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.name, name);
+    }
+
+    #[test]
+    fn should_not_be_read_from_string_given_empty_name() {
+        let command = UpdateStream::from_str("1|");
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_all_digit_name() {
+        let command = UpdateStream {
+            name: "123".to_string(),
+            ..Default::default()
+        };
+
+        assert!(matches!(command.validate(), Err(Error::InvalidStreamName)));
+    }
+}
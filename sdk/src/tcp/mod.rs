@@ -2,6 +2,7 @@ pub mod client;
 pub mod config;
 pub mod consumer_groups;
 pub mod messages;
+pub mod partitions;
 pub mod streams;
 pub mod system;
 pub mod topics;
@@ -0,0 +1,32 @@
+use crate::binary;
+use crate::client::PartitionClient;
+use crate::error::Error;
+use crate::models::created_partitions::CreatedPartitions;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::purge_partition::PurgePartition;
+use crate::partitions::rebuild_indexes::RebuildIndexes;
+use crate::tcp::client::TcpClient;
+use async_trait::async_trait;
+
+#[async_trait]
+impl PartitionClient for TcpClient {
+    async fn rebuild_indexes(&self, command: &RebuildIndexes) -> Result<(), Error> {
+        binary::partitions::rebuild_indexes(self, command).await
+    }
+
+    async fn purge_partition(&self, command: &PurgePartition) -> Result<(), Error> {
+        binary::partitions::purge_partition(self, command).await
+    }
+
+    async fn create_partitions(
+        &self,
+        command: &CreatePartitions,
+    ) -> Result<CreatedPartitions, Error> {
+        binary::partitions::create_partitions(self, command).await
+    }
+
+    async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), Error> {
+        binary::partitions::delete_partitions(self, command).await
+    }
+}
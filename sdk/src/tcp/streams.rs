@@ -6,6 +6,7 @@ use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
+use crate::streams::update_stream::UpdateStream;
 use crate::tcp::client::TcpClient;
 use async_trait::async_trait;
 
@@ -26,4 +27,8 @@ impl StreamClient for TcpClient {
     async fn delete_stream(&self, command: &DeleteStream) -> Result<(), Error> {
         binary::streams::delete_stream(self, command).await
     }
+
+    async fn update_stream(&self, command: &UpdateStream) -> Result<(), Error> {
+        binary::streams::update_stream(self, command).await
+    }
 }
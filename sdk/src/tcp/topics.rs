@@ -7,6 +7,8 @@ use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -26,4 +28,12 @@ impl TopicClient for TcpClient {
     async fn delete_topic(&self, command: &DeleteTopic) -> Result<(), Error> {
         binary::topics::delete_topic(self, command).await
     }
+
+    async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), Error> {
+        binary::topics::purge_topic(self, command).await
+    }
+
+    async fn update_topic(&self, command: &UpdateTopic) -> Result<(), Error> {
+        binary::topics::update_topic(self, command).await
+    }
 }
@@ -1,31 +1,90 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
+use crate::compression::CompressionAlgorithm;
 use crate::error::Error;
+use crate::identifier;
+use crate::identifier::Identifier;
 use crate::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::{from_utf8, FromStr};
 
-const MAX_NAME_LENGTH: usize = 1000;
-const MAX_PARTITIONS_COUNT: u32 = 100000;
+pub const MAX_NAME_LENGTH: usize = identifier::MAX_NAME_LENGTH;
+pub const MAX_PARTITIONS_COUNT: u32 = 100000;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTopic {
     #[serde(skip)]
-    pub stream_id: u32,
-    pub topic_id: u32,
+    pub stream_id: Identifier,
+    #[serde(default)]
+    pub topic_id: Option<u32>,
     pub partitions_count: u32,
+    pub message_expiry: Option<u32>,
+    pub max_topic_size_bytes: Option<u64>,
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    #[serde(default)]
+    pub compression: CompressionAlgorithm,
+    #[serde(default)]
+    pub deduplication: Option<DeduplicationWindow>,
+    #[serde(default)]
+    pub checksum_policy: ChecksumPolicy,
+    #[serde(default)]
+    pub overrides: TopicConfigOverrides,
     pub name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupPolicy {
+    #[default]
+    Delete,
+    Compact,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ChecksumPolicy {
+    #[default]
+    None,
+    Fail,
+    Skip,
+    Log,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+pub struct DeduplicationWindow {
+    #[serde(default)]
+    pub max_ids: Option<u32>,
+    #[serde(default)]
+    pub expiry: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+pub struct TopicConfigOverrides {
+    #[serde(default)]
+    pub segment_size_bytes: Option<u64>,
+    #[serde(default)]
+    pub messages_required_to_save: Option<u32>,
+    #[serde(default)]
+    pub enforce_sync: Option<bool>,
+}
+
 impl CommandPayload for CreateTopic {}
 
 impl Default for CreateTopic {
     fn default() -> Self {
         CreateTopic {
-            stream_id: 1,
-            topic_id: 1,
+            stream_id: Identifier::default(),
+            topic_id: Some(1),
             partitions_count: 1,
+            message_expiry: None,
+            max_topic_size_bytes: None,
+            cleanup_policy: CleanupPolicy::default(),
+            compression: CompressionAlgorithm::default(),
+            deduplication: None,
+            checksum_policy: ChecksumPolicy::default(),
+            overrides: TopicConfigOverrides::default(),
             name: "topic".to_string(),
         }
     }
@@ -33,15 +92,15 @@ impl Default for CreateTopic {
 
 impl Validatable for CreateTopic {
     fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+        if !self.stream_id.is_valid() {
             return Err(Error::InvalidStreamId);
         }
 
-        if self.topic_id == 0 {
+        if self.topic_id == Some(0) {
             return Err(Error::InvalidTopicId);
         }
 
-        if self.name.is_empty() || self.name.len() > MAX_NAME_LENGTH {
+        if !identifier::is_valid_name(&self.name) {
             return Err(Error::InvalidTopicName);
         }
 
@@ -49,6 +108,28 @@ impl Validatable for CreateTopic {
             return Err(Error::InvalidTopicPartitions);
         }
 
+        if let Some(message_expiry) = self.message_expiry {
+            if message_expiry == 0 {
+                return Err(Error::InvalidTopicMessageExpiry);
+            }
+        }
+
+        if let Some(max_topic_size_bytes) = self.max_topic_size_bytes {
+            if max_topic_size_bytes == 0 {
+                return Err(Error::InvalidTopicMaxSize);
+            }
+        }
+
+        if let Some(deduplication) = self.deduplication {
+            // The compaction keeps the newest message per ID, which the deduplication would reject.
+            if self.cleanup_policy == CleanupPolicy::Compact {
+                return Err(Error::InvalidTopicDeduplication);
+            }
+
+            deduplication.validate()?;
+        }
+
+        self.overrides.validate()?;
         Ok(())
     }
 }
@@ -57,18 +138,63 @@ impl FromStr for CreateTopic {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 4 {
+        if ![4, 6, 7, 8, 10, 11, 14].contains(&parts.len()) {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = parts[0].parse::<u32>()?;
-        let topic_id = parts[1].parse::<u32>()?;
+        let stream_id = Identifier::from_str(parts[0])?;
+        let topic_id = as_optional(parts[1].parse::<u32>()?);
         let partitions_count = parts[2].parse::<u32>()?;
         let name = parts[3].to_string();
+        let (message_expiry, max_topic_size_bytes) = match parts.len() {
+            4 => (None, None),
+            _ => (
+                as_optional(parts[4].parse::<u32>()?),
+                as_optional(parts[5].parse::<u64>()?),
+            ),
+        };
+        let cleanup_policy = match parts.len() {
+            4 | 6 => CleanupPolicy::default(),
+            _ => CleanupPolicy::from_str(parts[6])?,
+        };
+        let compression = match parts.len() {
+            8 | 10 | 11 | 14 => CompressionAlgorithm::from_str(parts[7])?,
+            _ => CompressionAlgorithm::default(),
+        };
+        let deduplication = match parts.len() {
+            10 | 11 | 14 => {
+                DeduplicationWindow::from_values(parts[8].parse::<u32>()?, parts[9].parse::<u32>()?)
+            }
+            _ => None,
+        };
+        let checksum_policy = match parts.len() {
+            11 | 14 => ChecksumPolicy::from_str(parts[10])?,
+            _ => ChecksumPolicy::default(),
+        };
+        let overrides = match parts.len() {
+            14 => TopicConfigOverrides {
+                segment_size_bytes: as_optional(parts[11].parse::<u64>()?),
+                messages_required_to_save: as_optional(parts[12].parse::<u32>()?),
+                enforce_sync: match parts[13] {
+                    "d" | "default" => None,
+                    "t" | "true" => Some(true),
+                    "f" | "false" => Some(false),
+                    _ => return Err(Error::InvalidCommand),
+                },
+            },
+            _ => TopicConfigOverrides::default(),
+        };
         let command = CreateTopic {
             stream_id,
             topic_id,
             partitions_count,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            checksum_policy,
+            overrides,
             name,
         };
         command.validate()?;
@@ -78,27 +204,55 @@ impl FromStr for CreateTopic {
 
 impl BytesSerializable for CreateTopic {
     fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(12 + self.name.len());
-        bytes.extend(self.stream_id.to_le_bytes());
-        bytes.extend(self.topic_id.to_le_bytes());
+        let mut bytes = Vec::with_capacity(44 + self.stream_id.get_size_bytes() + self.name.len());
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.unwrap_or(0).to_le_bytes());
         bytes.extend(self.partitions_count.to_le_bytes());
+        bytes.extend(self.message_expiry.unwrap_or(0).to_le_bytes());
+        bytes.extend(self.max_topic_size_bytes.unwrap_or(0).to_le_bytes());
+        bytes.extend(self.cleanup_policy.as_code().to_le_bytes());
+        bytes.extend(self.compression.as_code().to_le_bytes());
+        bytes.extend(DeduplicationWindow::as_bytes(self.deduplication));
+        bytes.extend(self.checksum_policy.as_code().to_le_bytes());
+        bytes.extend(self.overrides.as_bytes());
         bytes.extend(self.name.as_bytes());
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<CreateTopic, Error> {
-        if bytes.len() < 13 {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let position = stream_id.get_size_bytes();
+        if bytes.len() < position + 45 {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into()?);
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into()?);
-        let partitions_count = u32::from_le_bytes(bytes[8..12].try_into()?);
-        let name = from_utf8(&bytes[12..])?.to_string();
+        let topic_id = as_optional(u32::from_le_bytes(
+            bytes[position..position + 4].try_into()?,
+        ));
+        let partitions_count = u32::from_le_bytes(bytes[position + 4..position + 8].try_into()?);
+        let message_expiry = as_optional(u32::from_le_bytes(
+            bytes[position + 8..position + 12].try_into()?,
+        ));
+        let max_topic_size_bytes = as_optional(u64::from_le_bytes(
+            bytes[position + 12..position + 20].try_into()?,
+        ));
+        let cleanup_policy = CleanupPolicy::from_code(bytes[position + 20])?;
+        let compression = CompressionAlgorithm::from_code(bytes[position + 21])?;
+        let deduplication = DeduplicationWindow::from_bytes(&bytes[position + 22..position + 30])?;
+        let checksum_policy = ChecksumPolicy::from_code(bytes[position + 30])?;
+        let overrides = TopicConfigOverrides::from_bytes(&bytes[position + 31..position + 44])?;
+        let name = from_utf8(&bytes[position + 44..])?.to_string();
         let command = CreateTopic {
             stream_id,
             topic_id,
             partitions_count,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            checksum_policy,
+            overrides,
             name,
         };
         command.validate()?;
@@ -108,14 +262,214 @@ impl BytesSerializable for CreateTopic {
 
 impl Display for CreateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let deduplication = self.deduplication.unwrap_or_default();
         write!(
             f,
-            "{}|{}|{}|{}",
-            self.stream_id, self.topic_id, self.partitions_count, self.name
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id.unwrap_or(0),
+            self.partitions_count,
+            self.name,
+            self.message_expiry.unwrap_or(0),
+            self.max_topic_size_bytes.unwrap_or(0),
+            self.cleanup_policy,
+            self.compression,
+            deduplication.max_ids.unwrap_or(0),
+            deduplication.expiry.unwrap_or(0),
+            self.checksum_policy,
+            self.overrides.segment_size_bytes.unwrap_or(0),
+            self.overrides.messages_required_to_save.unwrap_or(0),
+            match self.overrides.enforce_sync {
+                None => "default",
+                Some(true) => "true",
+                Some(false) => "false",
+            }
         )
     }
 }
 
+impl CleanupPolicy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CleanupPolicy::Delete => 0,
+            CleanupPolicy::Compact => 1,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0 => Ok(CleanupPolicy::Delete),
+            1 => Ok(CleanupPolicy::Compact),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for CleanupPolicy {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "d" | "delete" => Ok(CleanupPolicy::Delete),
+            "c" | "compact" => Ok(CleanupPolicy::Compact),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for CleanupPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupPolicy::Delete => write!(f, "delete"),
+            CleanupPolicy::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+impl ChecksumPolicy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ChecksumPolicy::None => 0,
+            ChecksumPolicy::Fail => 1,
+            ChecksumPolicy::Skip => 2,
+            ChecksumPolicy::Log => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0 => Ok(ChecksumPolicy::None),
+            1 => Ok(ChecksumPolicy::Fail),
+            2 => Ok(ChecksumPolicy::Skip),
+            3 => Ok(ChecksumPolicy::Log),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for ChecksumPolicy {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "n" | "none" => Ok(ChecksumPolicy::None),
+            "f" | "fail" => Ok(ChecksumPolicy::Fail),
+            "s" | "skip" => Ok(ChecksumPolicy::Skip),
+            "l" | "log" => Ok(ChecksumPolicy::Log),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for ChecksumPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChecksumPolicy::None => write!(f, "none"),
+            ChecksumPolicy::Fail => write!(f, "fail"),
+            ChecksumPolicy::Skip => write!(f, "skip"),
+            ChecksumPolicy::Log => write!(f, "log"),
+        }
+    }
+}
+
+impl DeduplicationWindow {
+    // The value of 0 means unlimited, and there's no window if both of them are unlimited.
+    pub fn from_values(max_ids: u32, expiry: u32) -> Option<Self> {
+        if max_ids == 0 && expiry == 0 {
+            return None;
+        }
+
+        Some(DeduplicationWindow {
+            max_ids: as_optional(max_ids),
+            expiry: as_optional(expiry),
+        })
+    }
+
+    pub fn as_bytes(deduplication: Option<Self>) -> Vec<u8> {
+        let deduplication = deduplication.unwrap_or_default();
+        let mut bytes = Vec::with_capacity(8);
+        bytes.extend(deduplication.max_ids.unwrap_or(0).to_le_bytes());
+        bytes.extend(deduplication.expiry.unwrap_or(0).to_le_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Option<Self>, Error> {
+        if bytes.len() != 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let max_ids = u32::from_le_bytes(bytes[..4].try_into()?);
+        let expiry = u32::from_le_bytes(bytes[4..8].try_into()?);
+        Ok(Self::from_values(max_ids, expiry))
+    }
+}
+
+impl Validatable for DeduplicationWindow {
+    fn validate(&self) -> Result<(), Error> {
+        if self.max_ids.is_none() && self.expiry.is_none() {
+            return Err(Error::InvalidTopicDeduplication);
+        }
+
+        if self.max_ids == Some(0) || self.expiry == Some(0) {
+            return Err(Error::InvalidTopicDeduplication);
+        }
+
+        Ok(())
+    }
+}
+
+impl TopicConfigOverrides {
+    // Segment size + Messages required to save + Sync, where the value of 0 means that the server setting is used.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(13);
+        bytes.extend(self.segment_size_bytes.unwrap_or(0).to_le_bytes());
+        bytes.extend(self.messages_required_to_save.unwrap_or(0).to_le_bytes());
+        bytes.push(match self.enforce_sync {
+            None => 0,
+            Some(false) => 1,
+            Some(true) => 2,
+        });
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != 13 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let segment_size_bytes = u64::from_le_bytes(bytes[..8].try_into()?);
+        let messages_required_to_save = u32::from_le_bytes(bytes[8..12].try_into()?);
+        let enforce_sync = match bytes[12] {
+            0 => None,
+            1 => Some(false),
+            2 => Some(true),
+            _ => return Err(Error::InvalidCommand),
+        };
+        Ok(TopicConfigOverrides {
+            segment_size_bytes: as_optional(segment_size_bytes),
+            messages_required_to_save: as_optional(messages_required_to_save),
+            enforce_sync,
+        })
+    }
+}
+
+impl Validatable for TopicConfigOverrides {
+    fn validate(&self) -> Result<(), Error> {
+        if self.segment_size_bytes == Some(0) || self.messages_required_to_save == Some(0) {
+            return Err(Error::InvalidTopicConfigOverrides);
+        }
+
+        Ok(())
+    }
+}
+
+// The value of 0 is used to represent the disabled (unlimited) retention setting.
+pub(crate) fn as_optional<T: PartialEq + Default>(value: T) -> Option<T> {
+    if value == T::default() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,35 +477,84 @@ mod tests {
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = CreateTopic {
-            stream_id: 1,
-            topic_id: 2,
+            stream_id: Identifier::named("stream"),
+            topic_id: Some(2),
             partitions_count: 3,
+            message_expiry: Some(10),
+            max_topic_size_bytes: Some(1000),
+            cleanup_policy: CleanupPolicy::Compact,
+            compression: CompressionAlgorithm::Zstd,
+            deduplication: Some(DeduplicationWindow {
+                max_ids: Some(100),
+                expiry: None,
+            }),
+            checksum_policy: ChecksumPolicy::Skip,
+            overrides: TopicConfigOverrides {
+                segment_size_bytes: Some(1000000),
+                messages_required_to_save: None,
+                enforce_sync: Some(true),
+            },
             name: "test".to_string(),
         };
 
         let bytes = command.as_bytes();
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
-        let partitions_count = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
-        let name = from_utf8(&bytes[12..]).unwrap();
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let bytes = &bytes[stream_id.get_size_bytes()..];
+        let topic_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let partitions_count = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let message_expiry = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let max_topic_size_bytes = u64::from_le_bytes(bytes[12..20].try_into().unwrap());
+        let cleanup_policy = CleanupPolicy::from_code(bytes[20]).unwrap();
+        let compression = CompressionAlgorithm::from_code(bytes[21]).unwrap();
+        let deduplication = DeduplicationWindow::from_bytes(&bytes[22..30]).unwrap();
+        let checksum_policy = ChecksumPolicy::from_code(bytes[30]).unwrap();
+        let overrides = TopicConfigOverrides::from_bytes(&bytes[31..44]).unwrap();
+        let name = from_utf8(&bytes[44..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
-        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(Some(topic_id), command.topic_id);
         assert_eq!(partitions_count, command.partitions_count);
+        assert_eq!(Some(message_expiry), command.message_expiry);
+        assert_eq!(Some(max_topic_size_bytes), command.max_topic_size_bytes);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
+        assert_eq!(compression, command.compression);
+        assert_eq!(deduplication, command.deduplication);
+        assert_eq!(checksum_policy, command.checksum_policy);
+        assert_eq!(overrides, command.overrides);
         assert_eq!(name, command.name);
     }
 
     #[test]
     fn should_be_deserialized_from_bytes() {
-        let stream_id = 1u32;
+        let stream_id = Identifier::numeric(1);
         let topic_id = 2u32;
         let partitions_count = 3u32;
+        let message_expiry = 10u32;
+        let max_topic_size_bytes = 0u64;
+        let cleanup_policy = CleanupPolicy::Delete;
+        let compression = CompressionAlgorithm::Lz4;
+        let deduplication_max_ids = 0u32;
+        let deduplication_expiry = 60u32;
+        let checksum_policy = ChecksumPolicy::Fail;
+        let segment_size_bytes = 0u64;
+        let messages_required_to_save = 100u32;
+        let enforce_sync = 1u8;
         let name = "test".to_string();
         let bytes = [
-            &stream_id.to_le_bytes(),
+            stream_id.as_bytes().as_slice(),
             &topic_id.to_le_bytes(),
             &partitions_count.to_le_bytes(),
+            &message_expiry.to_le_bytes(),
+            &max_topic_size_bytes.to_le_bytes(),
+            &cleanup_policy.as_code().to_le_bytes(),
+            &compression.as_code().to_le_bytes(),
+            &deduplication_max_ids.to_le_bytes(),
+            &deduplication_expiry.to_le_bytes(),
+            &checksum_policy.as_code().to_le_bytes(),
+            &segment_size_bytes.to_le_bytes(),
+            &messages_required_to_save.to_le_bytes(),
+            &enforce_sync.to_le_bytes(),
             name.as_bytes(),
         ]
         .concat();
@@ -160,14 +563,34 @@ mod tests {
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
-        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.topic_id, Some(topic_id));
         assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.max_topic_size_bytes, None);
+        assert_eq!(command.cleanup_policy, cleanup_policy);
+        assert_eq!(command.compression, compression);
+        assert_eq!(
+            command.deduplication,
+            Some(DeduplicationWindow {
+                max_ids: None,
+                expiry: Some(deduplication_expiry),
+            })
+        );
+        assert_eq!(command.checksum_policy, checksum_policy);
+        assert_eq!(
+            command.overrides,
+            TopicConfigOverrides {
+                segment_size_bytes: None,
+                messages_required_to_save: Some(messages_required_to_save),
+                enforce_sync: Some(false),
+            }
+        );
         assert_eq!(command.name, name);
     }
 
     #[test]
     fn should_be_read_from_string() {
-        let stream_id = 1u32;
+        let stream_id = Identifier::numeric(1);
         let topic_id = 2u32;
         let partitions_count = 3u32;
         let name = "test".to_string();
@@ -177,8 +600,175 @@ mod tests {
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
-        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.topic_id, Some(topic_id));
         assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.message_expiry, None);
+        assert_eq!(command.max_topic_size_bytes, None);
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(command.compression, CompressionAlgorithm::None);
+        assert_eq!(command.deduplication, None);
+        assert_eq!(command.checksum_policy, ChecksumPolicy::None);
+        assert_eq!(command.overrides, TopicConfigOverrides::default());
         assert_eq!(command.name, name);
     }
+
+    #[test]
+    fn should_be_read_from_string_with_retention_settings() {
+        let stream_id = Identifier::named("stream");
+        let topic_id = 2u32;
+        let partitions_count = 3u32;
+        let name = "test".to_string();
+        let message_expiry = 10u32;
+        let max_topic_size_bytes = 1000u64;
+        let input = format!(
+            "{}|{}|{}|{}|{}|{}",
+            stream_id, topic_id, partitions_count, name, message_expiry, max_topic_size_bytes
+        );
+        let command = CreateTopic::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, Some(topic_id));
+        assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.max_topic_size_bytes, Some(max_topic_size_bytes));
+        assert_eq!(command.name, name);
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_cleanup_policy() {
+        let input = "1|2|3|test|0|0|compact";
+        let command = CreateTopic::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.message_expiry, None);
+        assert_eq!(command.max_topic_size_bytes, None);
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Compact);
+        assert_eq!(command.name, "test");
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_compression() {
+        let input = "1|2|3|test|0|0|delete|zstd";
+        let command = CreateTopic::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(command.compression, CompressionAlgorithm::Zstd);
+        assert_eq!(command.name, "test");
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_deduplication_window() {
+        let input = "1|2|3|test|0|0|delete|none|1000|3600";
+        let command = CreateTopic::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(
+            command.deduplication,
+            Some(DeduplicationWindow {
+                max_ids: Some(1000),
+                expiry: Some(3600),
+            })
+        );
+        assert_eq!(command.name, "test");
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_checksum_policy() {
+        let input = "1|2|3|test|0|0|delete|none|0|0|skip";
+        let command = CreateTopic::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.deduplication, None);
+        assert_eq!(command.checksum_policy, ChecksumPolicy::Skip);
+        assert_eq!(command.name, "test");
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_config_overrides() {
+        let input = "1|2|3|test|0|0|delete|none|0|0|none|1000000|0|true";
+        let command = CreateTopic::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(
+            command.overrides,
+            TopicConfigOverrides {
+                segment_size_bytes: Some(1000000),
+                messages_required_to_save: None,
+                enforce_sync: Some(true),
+            }
+        );
+        assert_eq!(command.to_string(), input);
+    }
+
+    #[test]
+    fn should_be_read_from_string_without_topic_id() {
+        let command = CreateTopic::from_str("stream|0|3|test");
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, Identifier::named("stream"));
+        assert_eq!(command.topic_id, None);
+        assert_eq!(command.name, "test");
+    }
+
+    #[test]
+    fn should_not_be_valid_given_all_digit_name() {
+        let command = CreateTopic {
+            name: "123".to_string(),
+            ..Default::default()
+        };
+
+        assert!(matches!(command.validate(), Err(Error::InvalidTopicName)));
+    }
+
+    #[test]
+    fn should_not_be_valid_given_compact_cleanup_policy_with_deduplication_window() {
+        let command = CreateTopic {
+            cleanup_policy: CleanupPolicy::Compact,
+            deduplication: Some(DeduplicationWindow {
+                max_ids: Some(100),
+                expiry: None,
+            }),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(Error::InvalidTopicDeduplication)
+        ));
+    }
+
+    #[test]
+    fn should_not_be_valid_given_unlimited_deduplication_window() {
+        let command = CreateTopic {
+            deduplication: Some(DeduplicationWindow::default()),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_zero_segment_size_override() {
+        let command = CreateTopic {
+            overrides: TopicConfigOverrides {
+                segment_size_bytes: Some(0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(Error::InvalidTopicConfigOverrides)
+        ));
+    }
 }
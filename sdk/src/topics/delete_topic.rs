@@ -1,35 +1,27 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
+use crate::identifier::Identifier;
 use crate::validatable::Validatable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
 pub struct DeleteTopic {
-    pub stream_id: u32,
-    pub topic_id: u32,
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
 }
 
 impl CommandPayload for DeleteTopic {}
 
-impl Default for DeleteTopic {
-    fn default() -> Self {
-        DeleteTopic {
-            stream_id: 1,
-            topic_id: 1,
-        }
-    }
-}
-
 impl Validatable for DeleteTopic {
     fn validate(&self) -> Result<(), Error> {
-        if self.stream_id == 0 {
+        if !self.stream_id.is_valid() {
             return Err(Error::InvalidStreamId);
         }
 
-        if self.topic_id == 0 {
+        if !self.topic_id.is_valid() {
             return Err(Error::InvalidTopicId);
         }
 
@@ -45,8 +37,8 @@ impl FromStr for DeleteTopic {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = parts[0].parse::<u32>()?;
-        let topic_id = parts[1].parse::<u32>()?;
+        let stream_id = Identifier::from_str(parts[0])?;
+        let topic_id = Identifier::from_str(parts[1])?;
         let command = DeleteTopic {
             stream_id,
             topic_id,
@@ -58,19 +50,21 @@ impl FromStr for DeleteTopic {
 
 impl BytesSerializable for DeleteTopic {
     fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(8);
-        bytes.extend(self.stream_id.to_le_bytes());
-        bytes.extend(self.topic_id.to_le_bytes());
+        let mut bytes =
+            Vec::with_capacity(self.stream_id.get_size_bytes() + self.topic_id.get_size_bytes());
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<DeleteTopic, Error> {
-        if bytes.len() != 8 {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        if bytes.len() != position + topic_id.get_size_bytes() {
             return Err(Error::InvalidCommand);
         }
 
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into()?);
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into()?);
         let command = DeleteTopic {
             stream_id,
             topic_id,
@@ -93,13 +87,13 @@ mod tests {
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = DeleteTopic {
-            stream_id: 1,
-            topic_id: 2,
+            stream_id: Identifier::numeric(1),
+            topic_id: Identifier::named("test"),
         };
 
         let bytes = command.as_bytes();
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
-        let topic_id = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let topic_id = Identifier::from_bytes(&bytes[stream_id.get_size_bytes()..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -108,9 +102,9 @@ mod tests {
 
     #[test]
     fn should_be_deserialized_from_bytes() {
-        let stream_id = 1u32;
-        let topic_id = 2u32;
-        let bytes = [stream_id.to_le_bytes(), topic_id.to_le_bytes()].concat();
+        let stream_id = Identifier::named("test");
+        let topic_id = Identifier::numeric(2);
+        let bytes = [stream_id.as_bytes(), topic_id.as_bytes()].concat();
         let command = DeleteTopic::from_bytes(&bytes);
         assert!(command.is_ok());
 
@@ -121,8 +115,8 @@ mod tests {
 
     #[test]
     fn should_be_read_from_string() {
-        let stream_id = 1u32;
-        let topic_id = 2u32;
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::named("test");
         let input = format!("{}|{}", stream_id, topic_id);
         let command = DeleteTopic::from_str(&input);
         assert!(command.is_ok());
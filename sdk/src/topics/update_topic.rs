@@ -0,0 +1,379 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::compression::CompressionAlgorithm;
+use crate::error::Error;
+use crate::identifier;
+use crate::identifier::Identifier;
+use crate::topics::create_topic::{
+    as_optional, ChecksumPolicy, CleanupPolicy, DeduplicationWindow,
+};
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct UpdateTopic {
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    pub message_expiry: Option<u32>,
+    pub max_topic_size_bytes: Option<u64>,
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    #[serde(default)]
+    pub compression: CompressionAlgorithm,
+    #[serde(default)]
+    pub deduplication: Option<DeduplicationWindow>,
+    #[serde(default)]
+    pub checksum_policy: ChecksumPolicy,
+    pub name: String,
+}
+
+impl CommandPayload for UpdateTopic {}
+
+impl Default for UpdateTopic {
+    fn default() -> Self {
+        UpdateTopic {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            message_expiry: None,
+            max_topic_size_bytes: None,
+            cleanup_policy: CleanupPolicy::default(),
+            compression: CompressionAlgorithm::default(),
+            deduplication: None,
+            checksum_policy: ChecksumPolicy::default(),
+            name: "topic".to_string(),
+        }
+    }
+}
+
+impl Validatable for UpdateTopic {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.stream_id.is_valid() {
+            return Err(Error::InvalidStreamId);
+        }
+
+        if !self.topic_id.is_valid() {
+            return Err(Error::InvalidTopicId);
+        }
+
+        if !identifier::is_valid_name(&self.name) {
+            return Err(Error::InvalidTopicName);
+        }
+
+        if let Some(message_expiry) = self.message_expiry {
+            if message_expiry == 0 {
+                return Err(Error::InvalidTopicMessageExpiry);
+            }
+        }
+
+        if let Some(max_topic_size_bytes) = self.max_topic_size_bytes {
+            if max_topic_size_bytes == 0 {
+                return Err(Error::InvalidTopicMaxSize);
+            }
+        }
+
+        if let Some(deduplication) = self.deduplication {
+            // The compaction keeps the newest message per ID, which the deduplication would reject.
+            if self.cleanup_policy == CleanupPolicy::Compact {
+                return Err(Error::InvalidTopicDeduplication);
+            }
+
+            deduplication.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for UpdateTopic {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if ![3, 5, 6, 7, 9, 10].contains(&parts.len()) {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_str(parts[0])?;
+        let topic_id = Identifier::from_str(parts[1])?;
+        let name = parts[2].to_string();
+        let (message_expiry, max_topic_size_bytes) = match parts.len() {
+            3 => (None, None),
+            _ => (
+                as_optional(parts[3].parse::<u32>()?),
+                as_optional(parts[4].parse::<u64>()?),
+            ),
+        };
+        let cleanup_policy = match parts.len() {
+            3 | 5 => CleanupPolicy::default(),
+            _ => CleanupPolicy::from_str(parts[5])?,
+        };
+        let compression = match parts.len() {
+            7 | 9 | 10 => CompressionAlgorithm::from_str(parts[6])?,
+            _ => CompressionAlgorithm::default(),
+        };
+        let deduplication = match parts.len() {
+            9 | 10 => {
+                DeduplicationWindow::from_values(parts[7].parse::<u32>()?, parts[8].parse::<u32>()?)
+            }
+            _ => None,
+        };
+        let checksum_policy = match parts.len() {
+            10 => ChecksumPolicy::from_str(parts[9])?,
+            _ => ChecksumPolicy::default(),
+        };
+        let command = UpdateTopic {
+            stream_id,
+            topic_id,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            checksum_policy,
+            name,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for UpdateTopic {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            23 + self.stream_id.get_size_bytes() + self.topic_id.get_size_bytes() + self.name.len(),
+        );
+        bytes.extend(self.stream_id.as_bytes());
+        bytes.extend(self.topic_id.as_bytes());
+        bytes.extend(self.message_expiry.unwrap_or(0).to_le_bytes());
+        bytes.extend(self.max_topic_size_bytes.unwrap_or(0).to_le_bytes());
+        bytes.extend(self.cleanup_policy.as_code().to_le_bytes());
+        bytes.extend(self.compression.as_code().to_le_bytes());
+        bytes.extend(DeduplicationWindow::as_bytes(self.deduplication));
+        bytes.extend(self.checksum_policy.as_code().to_le_bytes());
+        bytes.extend(self.name.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateTopic, Error> {
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let mut position = stream_id.get_size_bytes();
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes();
+        if bytes.len() < position + 24 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let message_expiry = as_optional(u32::from_le_bytes(
+            bytes[position..position + 4].try_into()?,
+        ));
+        let max_topic_size_bytes = as_optional(u64::from_le_bytes(
+            bytes[position + 4..position + 12].try_into()?,
+        ));
+        let cleanup_policy = CleanupPolicy::from_code(bytes[position + 12])?;
+        let compression = CompressionAlgorithm::from_code(bytes[position + 13])?;
+        let deduplication = DeduplicationWindow::from_bytes(&bytes[position + 14..position + 22])?;
+        let checksum_policy = ChecksumPolicy::from_code(bytes[position + 22])?;
+        let name = from_utf8(&bytes[position + 23..])?.to_string();
+        let command = UpdateTopic {
+            stream_id,
+            topic_id,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            checksum_policy,
+            name,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateTopic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let deduplication = self.deduplication.unwrap_or_default();
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.name,
+            self.message_expiry.unwrap_or(0),
+            self.max_topic_size_bytes.unwrap_or(0),
+            self.cleanup_policy,
+            self.compression,
+            deduplication.max_ids.unwrap_or(0),
+            deduplication.expiry.unwrap_or(0),
+            self.checksum_policy
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1),
+            topic_id: Identifier::named("topic"),
+            message_expiry: Some(10),
+            max_topic_size_bytes: Some(1000),
+            cleanup_policy: CleanupPolicy::Compact,
+            compression: CompressionAlgorithm::Zstd,
+            deduplication: Some(DeduplicationWindow {
+                max_ids: Some(100),
+                expiry: None,
+            }),
+            checksum_policy: ChecksumPolicy::Skip,
+            name: "test".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        let bytes = &bytes[stream_id.get_size_bytes()..];
+        let topic_id = Identifier::from_bytes(bytes).unwrap();
+        let bytes = &bytes[topic_id.get_size_bytes()..];
+        let message_expiry = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let max_topic_size_bytes = u64::from_le_bytes(bytes[4..12].try_into().unwrap());
+        let cleanup_policy = CleanupPolicy::from_code(bytes[12]).unwrap();
+        let compression = CompressionAlgorithm::from_code(bytes[13]).unwrap();
+        let deduplication = DeduplicationWindow::from_bytes(&bytes[14..22]).unwrap();
+        let checksum_policy = ChecksumPolicy::from_code(bytes[22]).unwrap();
+        let name = from_utf8(&bytes[23..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(Some(message_expiry), command.message_expiry);
+        assert_eq!(Some(max_topic_size_bytes), command.max_topic_size_bytes);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
+        assert_eq!(compression, command.compression);
+        assert_eq!(deduplication, command.deduplication);
+        assert_eq!(checksum_policy, command.checksum_policy);
+        assert_eq!(name, command.name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::named("topic");
+        let message_expiry = 10u32;
+        let max_topic_size_bytes = 0u64;
+        let cleanup_policy = CleanupPolicy::Delete;
+        let compression = CompressionAlgorithm::Lz4;
+        let deduplication_max_ids = 0u32;
+        let deduplication_expiry = 60u32;
+        let checksum_policy = ChecksumPolicy::Fail;
+        let name = "test".to_string();
+        let bytes = [
+            stream_id.as_bytes().as_slice(),
+            &topic_id.as_bytes(),
+            &message_expiry.to_le_bytes(),
+            &max_topic_size_bytes.to_le_bytes(),
+            &cleanup_policy.as_code().to_le_bytes(),
+            &compression.as_code().to_le_bytes(),
+            &deduplication_max_ids.to_le_bytes(),
+            &deduplication_expiry.to_le_bytes(),
+            &checksum_policy.as_code().to_le_bytes(),
+            name.as_bytes(),
+        ]
+        .concat();
+        let command = UpdateTopic::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.max_topic_size_bytes, None);
+        assert_eq!(command.cleanup_policy, cleanup_policy);
+        assert_eq!(command.compression, compression);
+        assert_eq!(
+            command.deduplication,
+            Some(DeduplicationWindow {
+                max_ids: None,
+                expiry: Some(deduplication_expiry),
+            })
+        );
+        assert_eq!(command.checksum_policy, checksum_policy);
+        assert_eq!(command.name, name);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1);
+        let topic_id = Identifier::named("topic");
+        let name = "test".to_string();
+        let input = format!("{}|{}|{}", stream_id, topic_id, name);
+        let command = UpdateTopic::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.message_expiry, None);
+        assert_eq!(command.max_topic_size_bytes, None);
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(command.compression, CompressionAlgorithm::None);
+        assert_eq!(command.deduplication, None);
+        assert_eq!(command.checksum_policy, ChecksumPolicy::None);
+        assert_eq!(command.name, name);
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_all_settings() {
+        let input = "1|2|test|10|1000|delete|lz4|100|60|log";
+        let command = UpdateTopic::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, Identifier::numeric(1));
+        assert_eq!(command.topic_id, Identifier::numeric(2));
+        assert_eq!(command.message_expiry, Some(10));
+        assert_eq!(command.max_topic_size_bytes, Some(1000));
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(command.compression, CompressionAlgorithm::Lz4);
+        assert_eq!(
+            command.deduplication,
+            Some(DeduplicationWindow {
+                max_ids: Some(100),
+                expiry: Some(60),
+            })
+        );
+        assert_eq!(command.checksum_policy, ChecksumPolicy::Log);
+        assert_eq!(command.name, "test");
+    }
+
+    #[test]
+    fn should_not_be_valid_given_compact_cleanup_policy_with_deduplication_window() {
+        let command = UpdateTopic {
+            cleanup_policy: CleanupPolicy::Compact,
+            deduplication: Some(DeduplicationWindow {
+                max_ids: Some(100),
+                expiry: None,
+            }),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(Error::InvalidTopicDeduplication)
+        ));
+    }
+
+    #[test]
+    fn should_not_be_valid_given_all_digit_name() {
+        let command = UpdateTopic {
+            name: "123".to_string(),
+            ..Default::default()
+        };
+
+        assert!(matches!(command.validate(), Err(Error::InvalidTopicName)));
+    }
+}
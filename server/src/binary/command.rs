@@ -5,6 +5,7 @@ use crate::binary::handlers::consumer_groups::{
 };
 use crate::binary::handlers::messages::*;
 use crate::binary::handlers::offsets::*;
+use crate::binary::handlers::partitions::*;
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
@@ -24,10 +25,14 @@ use tracing::trace;
          | 1 byte  |
 
 2. POLL: | COMMAND |   STREAM  |   TOPIC   |    KIND   |   VALUE   |   COUNT   |
-         | 1 byte  |  4 bytes  |  4 bytes  |   1 byte  |  8 bytes  |  4 bytes  |
+         | 1 byte  |  n bytes  |  n bytes  |   1 byte  |  8 bytes  |  4 bytes  |
 
 3. SEND: | COMMAND |   STREAM  |    TOPIC   |    KIND   |   VALUE   |   COUNT   |  PAYLOAD  |
-         | 1 byte  |  4 bytes  |   4 bytes  |   1 byte  |  8 bytes  |  4 bytes  |  n bytes  |
+         | 1 byte  |  n bytes  |   n bytes  |   1 byte  |  8 bytes  |  4 bytes  |  n bytes  |
+
+  IDENTIFIER (STREAM, TOPIC): |    KIND   |   LENGTH  |   VALUE   |
+                              |   1 byte  |   1 byte  |  n bytes  |
+  KIND is 1 for the numeric ID (4 bytes) or 2 for the name (up to 255 bytes).
 */
 
 /*
@@ -85,6 +90,9 @@ async fn try_handle(
         Command::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, client_context, system).await
         }
+        Command::DeleteMessagesBefore(command) => {
+            delete_messages_before_handler::handle(command, sender, system).await
+        }
         Command::GetOffset(command) => {
             get_offset_handler::handle(command, sender, client_context, system).await
         }
@@ -99,6 +107,9 @@ async fn try_handle(
         Command::DeleteStream(command) => {
             delete_stream_handler::handle(command, sender, system).await
         }
+        Command::UpdateStream(command) => {
+            update_stream_handler::handle(command, sender, system).await
+        }
         Command::GetTopic(command) => get_topic_handler::handle(command, sender, system).await,
         Command::GetTopics(command) => get_topics_handler::handle(command, sender, system).await,
         Command::CreateTopic(command) => {
@@ -107,6 +118,10 @@ async fn try_handle(
         Command::DeleteTopic(command) => {
             delete_topic_handler::handle(command, sender, system).await
         }
+        Command::PurgeTopic(command) => purge_topic_handler::handle(command, sender, system).await,
+        Command::UpdateTopic(command) => {
+            update_topic_handler::handle(command, sender, system).await
+        }
         Command::GetGroup(command) => {
             get_consumer_group_handler::handle(command, sender, system).await
         }
@@ -125,5 +140,17 @@ async fn try_handle(
         Command::LeaveGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, client_context, system).await
         }
+        Command::RebuildIndexes(command) => {
+            rebuild_indexes_handler::handle(command, sender, system).await
+        }
+        Command::PurgePartition(command) => {
+            purge_partition_handler::handle(command, sender, system).await
+        }
+        Command::CreatePartitions(command) => {
+            create_partitions_handler::handle(command, sender, system).await
+        }
+        Command::DeletePartitions(command) => {
+            delete_partitions_handler::handle(command, sender, system).await
+        }
     }
 }
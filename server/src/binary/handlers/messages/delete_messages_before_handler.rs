@@ -0,0 +1,27 @@
+use crate::binary::sender::Sender;
+use anyhow::Result;
+use sdk::error::Error;
+use sdk::messages::delete_messages_before::DeleteMessagesBefore;
+use std::sync::Arc;
+use streaming::system::System;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &DeleteMessagesBefore,
+    sender: &mut dyn Sender,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{}", command);
+    let system = system.read().await;
+    system
+        .delete_messages_before(
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crate::binary::client_context::ClientContext;
 use crate::binary::mapper;
 use crate::binary::sender::Sender;
 use anyhow::Result;
+use sdk::compression::CompressionAlgorithm;
 use sdk::consumer_type::ConsumerType;
 use sdk::error::Error;
 use sdk::messages::poll_messages::PollMessages;
@@ -12,8 +13,8 @@ use tokio::sync::RwLock;
 use tracing::trace;
 
 /*
-    |  POLL   | CONSUMER  |   STREAM  |   TOPIC   |    PT_ID  |    KIND   |   VALUE   |   COUNT   |   COMMIT  |
-    | 1 byte  |  4 bytes  |  4 bytes  |  4 bytes  |   4 bytes |   1 byte  |  8 bytes  |  4 bytes  |   1 byte  |
+    |  POLL   | CONSUMER  |   STREAM  |   TOPIC   |    PT_ID  |    KIND   |   VALUE   |   COUNT   |   COMMIT  | COMPRESSION |
+    | 1 byte  |  4 bytes  |  n bytes  |  n bytes  |   4 bytes |   1 byte  |  8 bytes  |  4 bytes  |   1 byte  |    1 byte   |
 
     POLL
         - Constant 1 byte of value 2
@@ -22,10 +23,10 @@ use tracing::trace;
         - Arbitrary Consumer ID that might be used for offset tracking.
 
     STREAM:
-        - Unique Stream ID to poll the messages from.
+        - Unique Stream ID or name to poll the messages from, as | KIND | LENGTH | VALUE | identifier.
 
     TOPIC:
-        - Unique Topic ID to poll the messages from.
+        - Unique Topic ID or name (within the stream) to poll the messages from, as | KIND | LENGTH | VALUE | identifier.
 
     KIND:
         - 0 -> offset
@@ -45,6 +46,11 @@ use tracing::trace;
     COMMIT:
         - Auto commit flag, if true, the consumer offset will be stored automatically.
 
+    COMPRESSION:
+        - The codec of the returned batches (0 -> none, 1 -> lz4, 2 -> zstd, 3 -> gzip).
+        - Optional, the command of the older clients ends after COMMIT (e.g. 31 bytes instead of 32 when the stream
+          and the topic were sent as the 4 bytes IDs), in which case no codec is requested.
+
     Poll the message(s) by consumer: 0, stream: 1, topic: 1, partition: 1, using kind: offset, value is 0, messages count is 1.
     |    0    |    1    |     1     |     1     |     0     |     0     |     1     |
 
@@ -74,8 +80,8 @@ pub async fn handle(
 
     let system = system.read().await;
     let topic = system
-        .get_stream(command.stream_id)?
-        .get_topic(command.topic_id)?;
+        .get_stream(&command.stream_id)?
+        .get_topic(&command.topic_id)?;
     let consumer = match command.consumer_type {
         ConsumerType::Consumer => PollingConsumer::Consumer(command.consumer_id),
         ConsumerType::ConsumerGroup => {
@@ -91,6 +97,44 @@ pub async fn handle(
         }
     };
 
+    // The batches are stored either uncompressed or with the codec of the topic, which the client decodes either way,
+    // thus they can be sent straight from the log only if no codec is requested.
+    let zero_copy_min_bytes = match command.compression {
+        CompressionAlgorithm::None => sender.get_zero_copy_min_bytes(),
+        _ => None,
+    };
+    if let Some(zero_copy_min_bytes) = zero_copy_min_bytes {
+        let log_slices = topic
+            .get_log_slices(
+                consumer,
+                partition_id,
+                command.kind,
+                command.value,
+                command.count,
+                zero_copy_min_bytes,
+            )
+            .await?;
+        if let Some(log_slices) = log_slices {
+            let offset = log_slices
+                .iter()
+                .rev()
+                .find_map(|log_slice| log_slice.get_last_offset());
+            if offset.is_none() {
+                sender.send_empty_ok_response().await?;
+                return Ok(());
+            }
+
+            if command.auto_commit {
+                let offset = offset.unwrap();
+                trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, command.consumer_id, command.stream_id, command.topic_id, command.partition_id);
+                topic.store_offset(consumer, partition_id, offset).await?;
+            }
+
+            sender.send_ok_log_slices_response(&log_slices).await?;
+            return Ok(());
+        }
+    }
+
     let messages = topic
         .get_messages(
             consumer,
@@ -107,7 +151,7 @@ pub async fn handle(
     }
 
     let offset = messages.last().unwrap().offset;
-    let messages = mapper::map_messages(&messages);
+    let messages = mapper::map_messages(&messages, command.compression)?;
     if command.auto_commit {
         trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, command.consumer_id, command.stream_id, command.topic_id, command.partition_id);
         topic.store_offset(consumer, partition_id, offset).await?;
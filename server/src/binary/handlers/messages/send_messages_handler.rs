@@ -2,10 +2,12 @@ use crate::binary::sender::Sender;
 use anyhow::Result;
 use sdk::error::Error;
 use sdk::messages::send_messages::SendMessages;
+use sdk::topics::create_topic::CleanupPolicy;
+use sdk::validatable::Validatable;
 use std::sync::Arc;
 use streaming::message::Message;
 use streaming::system::System;
-use streaming::utils::{checksum, timestamp};
+use streaming::utils::timestamp;
 use tokio::sync::RwLock;
 use tracing::trace;
 
@@ -16,24 +18,38 @@ pub async fn handle(
 ) -> Result<(), Error> {
     trace!("{}", command);
     let mut messages = Vec::with_capacity(command.messages_count as usize);
+    // The messages sent together share the timestamp, so that they can be stored as a single batch.
+    let timestamp = timestamp::get();
     for message in &command.messages {
-        let timestamp = timestamp::get();
-        let checksum = checksum::get(&message.payload);
         messages.push(Message::empty(
             timestamp,
             message.id,
             message.payload.clone(),
-            checksum,
         ));
     }
 
     let system = system.read().await;
     let topic = system
-        .get_stream(command.stream_id)?
-        .get_topic(command.topic_id)?;
-    topic
-        .append_messages(command.key_kind, command.key_value, messages)
-        .await?;
+        .get_stream(&command.stream_id)?
+        .get_topic(&command.topic_id)?;
+    // The empty payloads are accepted only as the tombstones for the compacted topics.
+    if topic.cleanup_policy != CleanupPolicy::Compact {
+        command.validate()?;
+    }
+
+    // The validated batch is stored as it was sent, unless its records have to be changed.
+    match &command.batch {
+        Some(batch) => {
+            topic
+                .append_batch(command.key_kind, command.key_value, messages, batch.clone())
+                .await?
+        }
+        None => {
+            topic
+                .append_messages(command.key_kind, command.key_value, messages)
+                .await?
+        }
+    }
 
     sender.send_empty_ok_response().await?;
     Ok(())
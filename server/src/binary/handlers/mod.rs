@@ -1,6 +1,7 @@
 pub mod consumer_groups;
 pub mod messages;
 pub mod offsets;
+pub mod partitions;
 pub mod streams;
 pub mod system;
 pub mod topics;
@@ -26,8 +26,8 @@ pub async fn handle(
 
     let system = system.read().await;
     let topic = system
-        .get_stream(command.stream_id)?
-        .get_topic(command.topic_id)?;
+        .get_stream(&command.stream_id)?
+        .get_topic(&command.topic_id)?;
 
     let partition_id = match consumer {
         PollingConsumer::Consumer(_) => command.partition_id,
@@ -0,0 +1,31 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use anyhow::Result;
+use sdk::error::Error;
+use sdk::partitions::create_partitions::CreatePartitions;
+use std::sync::Arc;
+use streaming::system::System;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &CreatePartitions,
+    sender: &mut dyn Sender,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{}", command);
+    let mut system = system.write().await;
+    let previous_partitions_count = system
+        .create_partitions(
+            &command.stream_id,
+            &command.topic_id,
+            command.partitions_count,
+        )
+        .await?;
+    let created_partitions = mapper::map_created_partitions(
+        previous_partitions_count,
+        previous_partitions_count + command.partitions_count,
+    );
+    sender.send_ok_response(&created_partitions).await?;
+    Ok(())
+}
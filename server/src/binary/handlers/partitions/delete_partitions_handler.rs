@@ -0,0 +1,26 @@
+use crate::binary::sender::Sender;
+use anyhow::Result;
+use sdk::error::Error;
+use sdk::partitions::delete_partitions::DeletePartitions;
+use std::sync::Arc;
+use streaming::system::System;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &DeletePartitions,
+    sender: &mut dyn Sender,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{}", command);
+    let mut system = system.write().await;
+    system
+        .delete_partitions(
+            &command.stream_id,
+            &command.topic_id,
+            command.partitions_count,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,4 @@
+pub mod create_partitions_handler;
+pub mod delete_partitions_handler;
+pub mod purge_partition_handler;
+pub mod rebuild_indexes_handler;
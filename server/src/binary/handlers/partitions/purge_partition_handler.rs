@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use anyhow::Result;
+use sdk::error::Error;
+use sdk::partitions::purge_partition::PurgePartition;
+use std::sync::Arc;
+use streaming::system::System;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &PurgePartition,
+    sender: &mut dyn Sender,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{}", command);
+    let system = system.read().await;
+    system
+        .purge_partition(&command.stream_id, &command.topic_id, command.partition_id)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -2,3 +2,4 @@ pub mod create_stream_handler;
 pub mod delete_stream_handler;
 pub mod get_stream_handler;
 pub mod get_streams_handler;
+pub mod update_stream_handler;
@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use anyhow::Result;
+use sdk::error::Error;
+use sdk::streams::update_stream::UpdateStream;
+use std::sync::Arc;
+use streaming::system::System;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &UpdateStream,
+    sender: &mut dyn Sender,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{}", command);
+    let mut system = system.write().await;
+    system
+        .update_stream(&command.stream_id, &command.name)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
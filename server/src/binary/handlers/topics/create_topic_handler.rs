@@ -15,8 +15,19 @@ pub async fn handle(
     trace!("{}", command);
     let mut system = system.write().await;
     system
-        .get_stream_mut(command.stream_id)?
-        .create_topic(command.topic_id, &command.name, command.partitions_count)
+        .get_stream_mut(&command.stream_id)?
+        .create_topic(
+            command.topic_id,
+            &command.name,
+            command.partitions_count,
+            command.message_expiry,
+            command.max_topic_size_bytes,
+            command.cleanup_policy,
+            command.compression,
+            command.deduplication,
+            command.checksum_policy,
+            command.overrides,
+        )
         .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
@@ -2,3 +2,5 @@ pub mod create_topic_handler;
 pub mod delete_topic_handler;
 pub mod get_topic_handler;
 pub mod get_topics_handler;
+pub mod purge_topic_handler;
+pub mod update_topic_handler;
@@ -1,7 +1,11 @@
+use sdk::compression::CompressionAlgorithm;
+use sdk::error::Error;
 use std::sync::Arc;
 use streaming::clients::client_manager::{Client, Transport};
+use streaming::message;
 use streaming::message::Message;
 use streaming::partitions::partition::Partition;
+use streaming::segments::log_slice::LogSlice;
 use streaming::streams::stream::Stream;
 use streaming::topics::consumer_group::ConsumerGroup;
 use streaming::topics::topic::Topic;
@@ -14,6 +18,13 @@ pub fn map_offset(consumer_id: u32, offset: u64) -> Vec<u8> {
     bytes
 }
 
+pub fn map_created_partitions(previous_partitions_count: u32, partitions_count: u32) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8);
+    bytes.extend(previous_partitions_count.to_le_bytes());
+    bytes.extend(partitions_count.to_le_bytes());
+    bytes
+}
+
 pub async fn map_client(client: &Client) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_client(client, &mut bytes);
@@ -34,20 +45,39 @@ pub async fn map_clients(clients: &[Arc<RwLock<Client>>]) -> Vec<u8> {
     bytes
 }
 
-pub fn map_messages(messages: &[Arc<Message>]) -> Vec<u8> {
-    let messages_count = messages.len() as u32;
-    let messages_size = messages
+pub fn map_messages(
+    messages: &[Arc<Message>],
+    compression: CompressionAlgorithm,
+) -> Result<Vec<u8>, Error> {
+    // The messages are sent as the batches, in the same format as they're stored in the log.
+    let batches = message::split_batches(messages, u64::MAX);
+    let batches_size = batches
         .iter()
-        .map(|message| message.get_size_bytes(false))
+        .map(|batch| message::get_batch_size_bytes(batch))
         .sum::<u32>();
 
-    let mut bytes = Vec::with_capacity(4 + messages_size as usize);
-    bytes.extend(messages_count.to_le_bytes());
-    for message in messages {
-        message.extend(&mut bytes, false);
+    let mut bytes = Vec::with_capacity(batches_size as usize);
+    for batch in batches {
+        message::create_batch(batch)
+            .compress(compression)?
+            .extend(&mut bytes);
     }
 
-    bytes
+    Ok(bytes)
+}
+
+pub async fn map_log_slices(log_slices: &[LogSlice]) -> Result<Vec<u8>, Error> {
+    let batches_size = log_slices
+        .iter()
+        .map(|log_slice| log_slice.get_size_bytes())
+        .sum::<u64>();
+
+    let mut bytes = Vec::with_capacity(batches_size as usize);
+    for log_slice in log_slices {
+        log_slice.extend(&mut bytes).await?;
+    }
+
+    Ok(bytes)
 }
 
 pub fn map_stream(stream: &Stream) -> Vec<u8> {
@@ -78,6 +108,7 @@ pub fn map_topics(topics: &[&Topic]) -> Vec<u8> {
 pub async fn map_topic(topic: &Topic) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_topic(topic, &mut bytes);
+    bytes.extend(topic.overrides.as_bytes());
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
         extend_partition(&partition, &mut bytes);
@@ -132,10 +163,11 @@ fn extend_partition(partition: &Partition, bytes: &mut Vec<u8>) {
         partition
             .get_segments()
             .iter()
-            .map(|segment| segment.current_size_bytes as u64)
+            .map(|segment| segment.current_size_bytes)
             .sum::<u64>()
             .to_le_bytes(),
     );
+    bytes.extend(partition.get_corrupted_messages_count().to_le_bytes());
 }
 
 fn extend_consumer_group(consumer_group: &ConsumerGroup, bytes: &mut Vec<u8>) {
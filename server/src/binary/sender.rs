@@ -1,9 +1,20 @@
+use crate::binary::mapper;
 use async_trait::async_trait;
 use sdk::error::Error;
+use streaming::segments::log_slice::LogSlice;
 
 #[async_trait]
 pub trait Sender: Sync + Send {
     async fn send_empty_ok_response(&mut self) -> Result<(), Error>;
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), Error>;
     async fn send_error_response(&mut self, error: Error) -> Result<(), Error>;
+
+    fn get_zero_copy_min_bytes(&self) -> Option<u64> {
+        None
+    }
+
+    async fn send_ok_log_slices_response(&mut self, log_slices: &[LogSlice]) -> Result<(), Error> {
+        let payload = mapper::map_log_slices(log_slices).await?;
+        self.send_ok_response(&payload).await
+    }
 }
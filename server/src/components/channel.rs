@@ -1,14 +1,14 @@
 use crate::server_command::ServerCommand;
 use flume::Receiver;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use streaming::persister::Persister;
-use streaming::segments::storage::FileSegmentStorage;
 use streaming::system::System;
 use tokio::sync::RwLock;
-use tracing::{error, info};
+use tracing::{error, info, trace};
 
 pub fn start(system: Arc<RwLock<System>>, receiver: Receiver<ServerCommand>) {
     tokio::spawn(async move {
+        let is_compacting = Arc::new(AtomicBool::new(false));
         loop {
             let server_command = receiver.recv_async().await;
             if server_command.is_err() {
@@ -20,17 +20,54 @@ pub fn start(system: Arc<RwLock<System>>, receiver: Receiver<ServerCommand>) {
             match server_command {
                 ServerCommand::SaveMessages(enforce_sync) => {
                     let system = system.read().await;
-                    let persister: Arc<dyn Persister> = match enforce_sync {
-                        true => Arc::new(streaming::persister::FileWithSyncPersister),
-                        false => Arc::new(streaming::persister::FilePersister),
-                    };
-                    let storage = Arc::new(FileSegmentStorage::new(persister));
-                    if system.persist_messages(storage).await.is_err() {
+                    if system.persist_messages(enforce_sync).await.is_err() {
                         error!("Couldn't save buffered messages on disk.");
                         return;
                     }
                     info!("Buffered messages saved on disk.");
                 }
+                ServerCommand::CleanMessages => {
+                    let system = system.read().await;
+                    if let Err(error) = system.clean_messages().await {
+                        error!("Couldn't delete expired segments: {}", error);
+                        continue;
+                    }
+                    trace!("Expired segments have been deleted.");
+                }
+                ServerCommand::DeleteDrainedPartitions => {
+                    let mut system = system.write().await;
+                    if let Err(error) = system.delete_drained_partitions().await {
+                        error!("Couldn't delete drained partitions: {}", error);
+                        continue;
+                    }
+                    trace!("Drained partitions have been deleted.");
+                }
+                ServerCommand::CompactSegments(tombstone_retention) => {
+                    // The compaction runs on its own task, so that it doesn't delay saving the messages.
+                    if is_compacting.swap(true, Ordering::SeqCst) {
+                        trace!("Segments are still being compacted.");
+                        continue;
+                    }
+
+                    let system = system.clone();
+                    let is_compacting = is_compacting.clone();
+                    tokio::spawn(async move {
+                        let system = system.read().await;
+                        match system.compact_segments(tombstone_retention).await {
+                            Ok(_) => trace!("Segments have been compacted."),
+                            Err(error) => error!("Couldn't compact segments: {}", error),
+                        }
+                        is_compacting.store(false, Ordering::SeqCst);
+                    });
+                }
+                ServerCommand::ArchiveSegments(archive_after) => {
+                    let system = system.read().await;
+                    if let Err(error) = system.archive_segments(archive_after).await {
+                        error!("Couldn't archive segments: {}", error);
+                        continue;
+                    }
+                    trace!("Segments have been archived.");
+                }
             }
         }
     });
@@ -0,0 +1,31 @@
+use crate::server_command::ServerCommand;
+use crate::server_config::MessageArchiverConfig;
+use flume::Sender;
+use std::time::Duration;
+use tokio::{task, time};
+use tracing::info;
+
+pub fn start(config: MessageArchiverConfig, sender: Sender<ServerCommand>) {
+    if !config.enabled {
+        info!("Message archiver is disabled.");
+        return;
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    let archive_after = config.archive_after;
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Message archiver is enabled, segments older than: {} seconds will be automatically moved to the archive every: {:?}",
+            archive_after, duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            sender
+                .send_async(ServerCommand::ArchiveSegments(archive_after))
+                .await
+                .unwrap();
+        }
+    });
+}
@@ -0,0 +1,34 @@
+use crate::server_command::ServerCommand;
+use crate::server_config::MessageCleanerConfig;
+use flume::Sender;
+use std::time::Duration;
+use tokio::{task, time};
+use tracing::info;
+
+pub fn start(config: MessageCleanerConfig, sender: Sender<ServerCommand>) {
+    if !config.enabled {
+        info!("Message cleaner is disabled.");
+        return;
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Message cleaner is enabled, expired segments and drained partitions will be automatically deleted every: {:?}",
+            duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            sender
+                .send_async(ServerCommand::CleanMessages)
+                .await
+                .unwrap();
+            sender
+                .send_async(ServerCommand::DeleteDrainedPartitions)
+                .await
+                .unwrap();
+        }
+    });
+}
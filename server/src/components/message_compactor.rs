@@ -0,0 +1,31 @@
+use crate::server_command::ServerCommand;
+use crate::server_config::MessageCompactorConfig;
+use flume::Sender;
+use std::time::Duration;
+use tokio::{task, time};
+use tracing::info;
+
+pub fn start(config: MessageCompactorConfig, sender: Sender<ServerCommand>) {
+    if !config.enabled {
+        info!("Message compactor is disabled.");
+        return;
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    let tombstone_retention = config.tombstone_retention;
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Message compactor is enabled, segments of the compacted topics will be automatically rewritten every: {:?}",
+            duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            sender
+                .send_async(ServerCommand::CompactSegments(tombstone_retention))
+                .await
+                .unwrap();
+        }
+    });
+}
@@ -1,2 +1,5 @@
 pub mod channel;
+pub mod message_archiver;
+pub mod message_cleaner;
+pub mod message_compactor;
 pub mod message_saver;
@@ -5,8 +5,10 @@ use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Json, Router};
 use sdk::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use sdk::identifier::Identifier;
 use sdk::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use sdk::validatable::Validatable;
+use std::str::FromStr;
 use std::sync::Arc;
 use streaming::system::System;
 use tokio::sync::RwLock;
@@ -23,12 +25,14 @@ pub fn router(system: Arc<RwLock<System>>) -> Router {
 
 async fn get_consumer_group(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id, consumer_group_id)): Path<(u32, u32, u32)>,
+    Path((stream_id, topic_id, consumer_group_id)): Path<(String, String, u32)>,
 ) -> Result<Json<ConsumerGroupDetails>, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
     let system = system.read().await;
     let consumer_group = system
-        .get_stream(stream_id)?
-        .get_topic(topic_id)?
+        .get_stream(&stream_id)?
+        .get_topic(&topic_id)?
         .get_consumer_group(consumer_group_id)?;
     let consumer_group = consumer_group.read().await;
     let consumer_group = mapper::map_consumer_group(&consumer_group).await;
@@ -37,36 +41,42 @@ async fn get_consumer_group(
 
 async fn get_consumer_groups(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
 ) -> Result<Json<Vec<ConsumerGroup>>, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
     let system = system.read().await;
-    let topic = system.get_stream(stream_id)?.get_topic(topic_id)?;
+    let topic = system.get_stream(&stream_id)?.get_topic(&topic_id)?;
     let consumer_groups = mapper::map_consumer_groups(&topic.get_consumer_groups()).await;
     Ok(Json(consumer_groups))
 }
 
 async fn create_consumer_group(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
     Json(mut command): Json<CreateConsumerGroup>,
 ) -> Result<StatusCode, CustomError> {
-    command.stream_id = stream_id;
-    command.topic_id = topic_id;
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    command.stream_id = stream_id.clone();
+    command.topic_id = topic_id.clone();
     command.validate()?;
     let mut system = system.write().await;
     system
-        .create_consumer_group(stream_id, topic_id, command.consumer_group_id)
+        .create_consumer_group(&stream_id, &topic_id, command.consumer_group_id)
         .await?;
     Ok(StatusCode::CREATED)
 }
 
 async fn delete_consumer_group(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id, consumer_group_id)): Path<(u32, u32, u32)>,
+    Path((stream_id, topic_id, consumer_group_id)): Path<(String, String, u32)>,
 ) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
     let mut system = system.write().await;
     system
-        .delete_consumer_group(stream_id, topic_id, consumer_group_id)
+        .delete_consumer_group(&stream_id, &topic_id, consumer_group_id)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
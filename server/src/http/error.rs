@@ -24,12 +24,16 @@ impl IntoResponse for CustomError {
             CustomError::Error(error) => {
                 let status_code = match error {
                     Error::StreamNotFound(_) => StatusCode::NOT_FOUND,
+                    Error::StreamNameNotFound(_) => StatusCode::NOT_FOUND,
                     Error::TopicNotFound(_, _) => StatusCode::NOT_FOUND,
+                    Error::TopicNameNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::PartitionNotFound(_) => StatusCode::NOT_FOUND,
                     Error::SegmentNotFound => StatusCode::NOT_FOUND,
                     Error::LogFileNotFound => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
+                    Error::DuplicateMessageId(_, _) => StatusCode::CONFLICT,
+                    Error::TopicPartitionsDraining(_, _) => StatusCode::CONFLICT,
                     Error::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::WriteError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::CannotParseInt(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -1,4 +1,4 @@
-use crate::http::{consumer_groups, messages, streams, system, topics};
+use crate::http::{consumer_groups, messages, partitions, streams, system, topics};
 use axum::Router;
 use std::sync::Arc;
 use streaming::system::System;
@@ -20,7 +20,8 @@ pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
                         "/:topic_id/consumer_groups",
                         consumer_groups::router(system.clone()),
                     )
-                    .nest("/:topic_id/messages", messages::router(system.clone())),
+                    .nest("/:topic_id/messages", messages::router(system.clone()))
+                    .nest("/:topic_id/partitions", partitions::router(system.clone())),
             ),
         ),
     );
@@ -59,6 +59,7 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         id: topic.id,
         name: topic.name.clone(),
         partitions_count: topic.get_partitions().len() as u32,
+        overrides: topic.overrides,
         partitions: Vec::new(),
     };
     for partition in topic.get_partitions() {
@@ -72,8 +73,9 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
                 size_bytes: partition
                     .get_segments()
                     .iter()
-                    .map(|segment| segment.current_size_bytes as u64)
+                    .map(|segment| segment.current_size_bytes)
                     .sum(),
+                corrupted_messages_count: partition.get_corrupted_messages_count(),
             });
     }
     topic_details.partitions.sort_by(|a, b| a.id.cmp(&b.id));
@@ -3,39 +3,52 @@ use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Json, Router};
+use bytes::Bytes;
+use sdk::compression::CompressionAlgorithm;
+use sdk::identifier::Identifier;
+use sdk::messages::delete_messages_before::DeleteMessagesBefore;
 use sdk::messages::poll_messages::PollMessages;
-use sdk::messages::send_messages::SendMessages;
+use sdk::messages::send_messages::{SendMessages, MAX_PAYLOAD_SIZE};
 use sdk::models::offset::Offset;
 use sdk::offsets::get_offset::GetOffset;
 use sdk::offsets::store_offset::StoreOffset;
+use sdk::topics::create_topic::CleanupPolicy;
 use sdk::validatable::Validatable;
+use std::str::FromStr;
 use std::sync::Arc;
 use streaming::message::Message;
 use streaming::polling_consumer::PollingConsumer;
 use streaming::system::System;
-use streaming::utils::{checksum, timestamp};
+use streaming::utils::timestamp;
 use tokio::sync::RwLock;
 use tracing::trace;
 
 pub fn router(system: Arc<RwLock<System>>) -> Router {
     Router::new()
-        .route("/", get(poll_messages).post(send_messages))
+        .route(
+            "/",
+            get(poll_messages)
+                .post(send_messages)
+                .delete(delete_messages_before),
+        )
         .route("/offsets", get(get_offset).put(store_offset))
         .with_state(system)
 }
 
 async fn poll_messages(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<PollMessages>,
 ) -> Result<Json<Vec<Arc<Message>>>, CustomError> {
-    query.stream_id = stream_id;
-    query.topic_id = topic_id;
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    query.stream_id = stream_id.clone();
+    query.topic_id = topic_id.clone();
     query.validate()?;
 
     let consumer = PollingConsumer::Consumer(query.consumer_id);
     let system = system.read().await;
-    let topic = system.get_stream(stream_id)?.get_topic(topic_id)?;
+    let topic = system.get_stream(&stream_id)?.get_topic(&topic_id)?;
     let messages = topic
         .get_messages(
             consumer,
@@ -50,8 +63,27 @@ async fn poll_messages(
         return Ok(Json(messages));
     }
 
+    let offset = messages.last().unwrap().offset;
+    // There's no batch in the JSON response, thus each payload is compressed on its own.
+    let messages = match query.compression {
+        CompressionAlgorithm::None => messages,
+        compression => {
+            let mut compressed_messages = Vec::with_capacity(messages.len());
+            for message in messages {
+                let payload = Bytes::from(compression.compress(&message.payload)?);
+                compressed_messages.push(Arc::new(Message::create(
+                    message.offset,
+                    message.timestamp,
+                    message.id,
+                    payload,
+                )));
+            }
+            compressed_messages
+        }
+    };
+
+    // The offset is stored only once the messages are ready to be sent.
     if query.auto_commit {
-        let offset = messages.last().unwrap().offset;
         trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, query.stream_id, query.topic_id, query.partition_id);
         topic
             .store_offset(consumer, query.partition_id, offset)
@@ -63,46 +95,79 @@ async fn poll_messages(
 
 async fn send_messages(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
     Json(mut command): Json<SendMessages>,
 ) -> Result<StatusCode, CustomError> {
-    command.stream_id = stream_id;
-    command.topic_id = topic_id;
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    command.stream_id = stream_id.clone();
+    command.topic_id = topic_id.clone();
     command.messages_count = command.messages.len() as u32;
-    command.validate()?;
+    // The payloads are validated once decompressed, as they're stored that way.
+    let compression = command.compression;
+    if compression != CompressionAlgorithm::None {
+        for message in command.messages.iter_mut() {
+            message.payload = Bytes::from(
+                compression.decompress_with_limit(&message.payload, MAX_PAYLOAD_SIZE as usize)?,
+            );
+            message.length = message.payload.len() as u32;
+        }
+        command.compression = CompressionAlgorithm::None;
+    }
+    command.validate_allowing_tombstones()?;
+
+    let system = system.read().await;
+    let topic = system.get_stream(&stream_id)?.get_topic(&topic_id)?;
+    // The empty payloads are accepted only as the tombstones for the compacted topics.
+    if topic.cleanup_policy != CleanupPolicy::Compact {
+        command.validate()?;
+    }
 
     let mut messages = Vec::with_capacity(command.messages_count as usize);
+    // The messages sent together share the timestamp, so that they can be stored as a single batch.
+    let timestamp = timestamp::get();
     for message in command.messages {
-        let timestamp = timestamp::get();
-        let checksum = checksum::get(&message.payload);
-        messages.push(Message::empty(
-            timestamp,
-            message.id,
-            message.payload,
-            checksum,
-        ));
+        messages.push(Message::empty(timestamp, message.id, message.payload));
     }
 
-    let system = system.read().await;
-    let topic = system.get_stream(stream_id)?.get_topic(topic_id)?;
     topic
         .append_messages(command.key_kind, command.key_value, messages)
         .await?;
     Ok(StatusCode::CREATED)
 }
 
+async fn delete_messages_before(
+    State(system): State<Arc<RwLock<System>>>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<DeleteMessagesBefore>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    query.stream_id = stream_id.clone();
+    query.topic_id = topic_id.clone();
+    query.validate()?;
+
+    let system = system.read().await;
+    system
+        .delete_messages_before(&stream_id, &topic_id, query.partition_id, query.offset)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn store_offset(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
     mut command: Json<StoreOffset>,
 ) -> Result<StatusCode, CustomError> {
-    command.stream_id = stream_id;
-    command.topic_id = topic_id;
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    command.stream_id = stream_id.clone();
+    command.topic_id = topic_id.clone();
     command.validate()?;
 
     let consumer = PollingConsumer::Consumer(command.consumer_id);
     let system = system.read().await;
-    let topic = system.get_stream(stream_id)?.get_topic(topic_id)?;
+    let topic = system.get_stream(&stream_id)?.get_topic(&topic_id)?;
     topic
         .store_offset(consumer, command.partition_id, command.offset)
         .await?;
@@ -111,18 +176,20 @@ async fn store_offset(
 
 async fn get_offset(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<GetOffset>,
 ) -> Result<Json<Offset>, CustomError> {
-    query.stream_id = stream_id;
-    query.topic_id = topic_id;
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    query.stream_id = stream_id.clone();
+    query.topic_id = topic_id.clone();
     query.validate()?;
 
     let consumer = PollingConsumer::Consumer(query.consumer_id);
     let system = system.read().await;
     let offset = system
-        .get_stream(stream_id)?
-        .get_topic(topic_id)?
+        .get_stream(&stream_id)?
+        .get_topic(&topic_id)?
         .get_offset(consumer, query.partition_id)
         .await?;
 
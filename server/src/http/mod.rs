@@ -3,6 +3,7 @@ pub mod error;
 pub mod http_server;
 mod mapper;
 pub mod messages;
+pub mod partitions;
 pub mod streams;
 pub mod system;
 pub mod topics;
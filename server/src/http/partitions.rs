@@ -0,0 +1,88 @@
+use crate::http::error::CustomError;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Json, Router};
+use sdk::identifier::Identifier;
+use sdk::models::created_partitions::CreatedPartitions;
+use sdk::partitions::create_partitions::CreatePartitions;
+use sdk::partitions::delete_partitions::DeletePartitions;
+use sdk::validatable::Validatable;
+use std::str::FromStr;
+use std::sync::Arc;
+use streaming::system::System;
+use tokio::sync::RwLock;
+
+pub fn router(system: Arc<RwLock<System>>) -> Router {
+    Router::new()
+        .route("/", post(create_partitions).delete(delete_partitions))
+        .route("/:partition_id/rebuild_indexes", post(rebuild_indexes))
+        .route("/:partition_id/purge", post(purge_partition))
+        .with_state(system)
+}
+
+async fn create_partitions(
+    State(system): State<Arc<RwLock<System>>>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<CreatePartitions>,
+) -> Result<(StatusCode, Json<CreatedPartitions>), CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    command.stream_id = stream_id.clone();
+    command.topic_id = topic_id.clone();
+    command.validate()?;
+    let mut system = system.write().await;
+    let previous_partitions_count = system
+        .create_partitions(&stream_id, &topic_id, command.partitions_count)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedPartitions {
+            previous_partitions_count,
+            partitions_count: previous_partitions_count + command.partitions_count,
+        }),
+    ))
+}
+
+async fn delete_partitions(
+    State(system): State<Arc<RwLock<System>>>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<DeletePartitions>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    query.stream_id = stream_id.clone();
+    query.topic_id = topic_id.clone();
+    query.validate()?;
+    let mut system = system.write().await;
+    system
+        .delete_partitions(&stream_id, &topic_id, query.partitions_count)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn rebuild_indexes(
+    State(system): State<Arc<RwLock<System>>>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    let system = system.read().await;
+    system
+        .rebuild_indexes(&stream_id, &topic_id, partition_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn purge_partition(
+    State(system): State<Arc<RwLock<System>>>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    let system = system.read().await;
+    system
+        .purge_partition(&stream_id, &topic_id, partition_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -4,9 +4,12 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Json, Router};
+use sdk::identifier::Identifier;
 use sdk::models::stream::{Stream, StreamDetails};
 use sdk::streams::create_stream::CreateStream;
+use sdk::streams::update_stream::UpdateStream;
 use sdk::validatable::Validatable;
+use std::str::FromStr;
 use std::sync::Arc;
 use streaming::system::System;
 use tokio::sync::RwLock;
@@ -14,16 +17,20 @@ use tokio::sync::RwLock;
 pub fn router(system: Arc<RwLock<System>>) -> Router {
     Router::new()
         .route("/", get(get_streams).post(create_stream))
-        .route("/:stream_id", get(get_stream).delete(delete_stream))
+        .route(
+            "/:stream_id",
+            get(get_stream).put(update_stream).delete(delete_stream),
+        )
         .with_state(system)
 }
 
 async fn get_stream(
     State(system): State<Arc<RwLock<System>>>,
-    Path(stream_id): Path<u32>,
+    Path(stream_id): Path<String>,
 ) -> Result<Json<StreamDetails>, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
     let system = system.read().await;
-    let stream = system.get_stream(stream_id)?;
+    let stream = system.get_stream(&stream_id)?;
     let stream = mapper::map_stream(stream);
     Ok(Json(stream))
 }
@@ -48,10 +55,24 @@ async fn create_stream(
     Ok(StatusCode::CREATED)
 }
 
+async fn update_stream(
+    State(system): State<Arc<RwLock<System>>>,
+    Path(stream_id): Path<String>,
+    Json(mut command): Json<UpdateStream>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    command.stream_id = stream_id.clone();
+    command.validate()?;
+    let mut system = system.write().await;
+    system.update_stream(&stream_id, &command.name).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn delete_stream(
     State(system): State<Arc<RwLock<System>>>,
-    Path(stream_id): Path<u32>,
+    Path(stream_id): Path<String>,
 ) -> Result<StatusCode, CustomError> {
-    system.write().await.delete_stream(stream_id).await?;
+    let stream_id = Identifier::from_str(&stream_id)?;
+    system.write().await.delete_stream(&stream_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
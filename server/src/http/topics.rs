@@ -2,11 +2,14 @@ use crate::http::error::CustomError;
 use crate::http::mapper;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Json, Router};
+use sdk::identifier::Identifier;
 use sdk::models::topic::{Topic, TopicDetails};
 use sdk::topics::create_topic::CreateTopic;
+use sdk::topics::update_topic::UpdateTopic;
 use sdk::validatable::Validatable;
+use std::str::FromStr;
 use std::sync::Arc;
 use streaming::system::System;
 use tokio::sync::RwLock;
@@ -14,54 +17,113 @@ use tokio::sync::RwLock;
 pub fn router(system: Arc<RwLock<System>>) -> Router {
     Router::new()
         .route("/", get(get_topics).post(create_topic))
-        .route("/:topic_id", get(get_topic).delete(delete_topic))
+        .route(
+            "/:topic_id",
+            get(get_topic).put(update_topic).delete(delete_topic),
+        )
+        .route("/:topic_id/purge", post(purge_topic))
         .with_state(system)
 }
 
 async fn get_topic(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
 ) -> Result<Json<TopicDetails>, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
     let system = system.read().await;
-    let topic = system.get_stream(stream_id)?.get_topic(topic_id)?;
+    let topic = system.get_stream(&stream_id)?.get_topic(&topic_id)?;
     let topic = mapper::map_topic(topic).await;
     Ok(Json(topic))
 }
 
 async fn get_topics(
     State(system): State<Arc<RwLock<System>>>,
-    Path(stream_id): Path<u32>,
+    Path(stream_id): Path<String>,
 ) -> Result<Json<Vec<Topic>>, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
     let system = system.read().await;
-    let topics = system.get_stream(stream_id)?.get_topics();
+    let topics = system.get_stream(&stream_id)?.get_topics();
     let topics = mapper::map_topics(&topics);
     Ok(Json(topics))
 }
 
 async fn create_topic(
     State(system): State<Arc<RwLock<System>>>,
-    Path(stream_id): Path<u32>,
+    Path(stream_id): Path<String>,
     Json(mut command): Json<CreateTopic>,
 ) -> Result<StatusCode, CustomError> {
-    command.stream_id = stream_id;
+    let stream_id = Identifier::from_str(&stream_id)?;
+    command.stream_id = stream_id.clone();
     command.validate()?;
     let mut system = system.write().await;
     system
-        .get_stream_mut(stream_id)?
-        .create_topic(command.topic_id, &command.name, command.partitions_count)
+        .get_stream_mut(&stream_id)?
+        .create_topic(
+            command.topic_id,
+            &command.name,
+            command.partitions_count,
+            command.message_expiry,
+            command.max_topic_size_bytes,
+            command.cleanup_policy,
+            command.compression,
+            command.deduplication,
+            command.checksum_policy,
+            command.overrides,
+        )
         .await?;
     Ok(StatusCode::CREATED)
 }
 
+async fn update_topic(
+    State(system): State<Arc<RwLock<System>>>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateTopic>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    command.stream_id = stream_id.clone();
+    command.topic_id = topic_id.clone();
+    command.validate()?;
+    let mut system = system.write().await;
+    system
+        .get_stream_mut(&stream_id)?
+        .update_topic(
+            &topic_id,
+            &command.name,
+            command.message_expiry,
+            command.max_topic_size_bytes,
+            command.cleanup_policy,
+            command.compression,
+            command.deduplication,
+            command.checksum_policy,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn delete_topic(
     State(system): State<Arc<RwLock<System>>>,
-    Path((stream_id, topic_id)): Path<(u32, u32)>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
 ) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
     system
         .write()
         .await
-        .get_stream_mut(stream_id)?
-        .delete_topic(topic_id)
+        .get_stream_mut(&stream_id)?
+        .delete_topic(&topic_id)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn purge_topic(
+    State(system): State<Arc<RwLock<System>>>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str(&stream_id)?;
+    let topic_id = Identifier::from_str(&topic_id)?;
+    let system = system.read().await;
+    system.purge_topic(&stream_id, &topic_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -9,7 +9,9 @@ mod server_error;
 mod tcp;
 
 use crate::args::Args;
-use crate::components::{channel, message_saver};
+use crate::components::{
+    channel, message_archiver, message_cleaner, message_compactor, message_saver,
+};
 use crate::http::http_server;
 use crate::quic::quic_server;
 use crate::server_command::ServerCommand;
@@ -19,8 +21,6 @@ use crate::tcp::tcp_server;
 use anyhow::Result;
 use clap::Parser;
 use std::sync::Arc;
-use streaming::persister::FileWithSyncPersister;
-use streaming::segments::storage::FileSegmentStorage;
 use streaming::system::System;
 use tokio::signal;
 use tokio::sync::RwLock;
@@ -37,6 +37,9 @@ async fn main() -> Result<(), ServerError> {
     let system = Arc::new(RwLock::new(system));
     let (sender, receiver) = flume::unbounded::<ServerCommand>();
     message_saver::start(config.message_saver, sender.clone());
+    message_cleaner::start(config.message_cleaner, sender.clone());
+    message_compactor::start(config.message_compactor, sender.clone());
+    message_archiver::start(config.message_archiver, sender.clone());
     channel::start(system.clone(), receiver);
 
     if config.http.enabled {
@@ -58,9 +61,7 @@ async fn main() -> Result<(), ServerError> {
         Ok(()) => {
             info!("Shutting down Iggy server...");
             let mut system = system.write().await;
-            let persister = Arc::new(FileWithSyncPersister);
-            let storage = Arc::new(FileSegmentStorage::new(persister));
-            system.shutdown(storage).await?;
+            system.shutdown().await?;
             info!("Iggy server has shutdown successfully.");
         }
         Err(err) => {
@@ -1,4 +1,8 @@
 #[derive(Debug)]
 pub enum ServerCommand {
     SaveMessages(bool),
+    CleanMessages,
+    DeleteDrainedPartitions,
+    CompactSegments(u32),
+    ArchiveSegments(u32),
 }
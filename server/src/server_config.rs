@@ -12,6 +12,9 @@ use tracing::{error, info};
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ServerConfig {
     pub message_saver: MessageSaverConfig,
+    pub message_cleaner: MessageCleanerConfig,
+    pub message_compactor: MessageCompactorConfig,
+    pub message_archiver: MessageArchiverConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
@@ -35,6 +38,8 @@ pub struct QuicConfig {
 pub struct TcpConfig {
     pub enabled: bool,
     pub address: String,
+    pub zero_copy_polling: bool,
+    pub zero_copy_min_bytes: u64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -50,10 +55,33 @@ pub struct MessageSaverConfig {
     pub interval: u64,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageCleanerConfig {
+    pub enabled: bool,
+    pub interval: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageCompactorConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub tombstone_retention: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageArchiverConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub archive_after: u32,
+}
+
 impl Default for ServerConfig {
     fn default() -> ServerConfig {
         ServerConfig {
             message_saver: MessageSaverConfig::default(),
+            message_cleaner: MessageCleanerConfig::default(),
+            message_compactor: MessageCompactorConfig::default(),
+            message_archiver: MessageArchiverConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
@@ -83,6 +111,8 @@ impl Default for TcpConfig {
         TcpConfig {
             enabled: true,
             address: "127.0.0.1:8090".to_string(),
+            zero_copy_polling: true,
+            zero_copy_min_bytes: 65536,
         }
     }
 }
@@ -106,6 +136,35 @@ impl Default for MessageSaverConfig {
     }
 }
 
+impl Default for MessageCleanerConfig {
+    fn default() -> MessageCleanerConfig {
+        MessageCleanerConfig {
+            enabled: true,
+            interval: 60,
+        }
+    }
+}
+
+impl Default for MessageCompactorConfig {
+    fn default() -> MessageCompactorConfig {
+        MessageCompactorConfig {
+            enabled: true,
+            interval: 300,
+            tombstone_retention: 86400,
+        }
+    }
+}
+
+impl Default for MessageArchiverConfig {
+    fn default() -> MessageArchiverConfig {
+        MessageArchiverConfig {
+            enabled: false,
+            interval: 3600,
+            archive_after: 604800,
+        }
+    }
+}
+
 impl ServerConfig {
     pub fn load(path: &str) -> Result<ServerConfig, ServerError> {
         let config: Result<ServerConfig, Error> = Figment::new()
@@ -127,6 +186,11 @@ impl ServerConfig {
 
     fn validate_config(config: &ServerConfig) -> Result<(), ServerError> {
         let partition_config = &config.system.stream.topic.partition;
+        if partition_config.segment.size_bytes == 0 {
+            error!("Segment configuration -> size cannot be zero.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
         if partition_config.segment.size_bytes > segment::MAX_SIZE_BYTES {
             error!(
                 "Segment configuration -> size cannot be greater than: {} bytes.",
@@ -135,21 +199,33 @@ impl ServerConfig {
             return Err(ServerError::InvalidConfiguration);
         }
 
-        if partition_config.messages_buffer > 0
-            && !Self::is_power_of_two(partition_config.messages_buffer)
+        if partition_config.segment.index_interval_bytes as u64
+            > partition_config.segment.size_bytes
         {
-            error!("Segment configuration -> messages buffer must be a power of two.");
+            error!(
+                "Segment configuration -> index interval cannot be greater than the segment size."
+            );
             return Err(ServerError::InvalidConfiguration);
         }
 
-        Ok(())
-    }
+        if partition_config.segment.mmap_reads && partition_config.segment.mmap_max_segments == 0 {
+            error!("Segment configuration -> max mapped segments cannot be zero.");
+            return Err(ServerError::InvalidConfiguration);
+        }
 
-    fn is_power_of_two(n: u32) -> bool {
-        if n == 0 {
-            return false;
+        if config.message_archiver.enabled && !config.system.archive.enabled {
+            error!("Message archiver configuration -> archive must be enabled in the system configuration.");
+            return Err(ServerError::InvalidConfiguration);
         }
 
-        (n & (n - 1)) == 0
+        if config.system.cache.enabled && config.system.cache.get_size_bytes().is_none() {
+            error!(
+                "Cache configuration -> size: '{}' is invalid.",
+                config.system.cache.size
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
     }
 }
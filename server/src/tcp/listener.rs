@@ -15,7 +15,7 @@ use tracing::{error, info, trace};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(address: &str, system: Arc<RwLock<System>>) {
+pub fn start(address: &str, zero_copy_min_bytes: Option<u64>, system: Arc<RwLock<System>>) {
     let address = address.to_string();
     tokio::spawn(async move {
         let listener = TcpListener::bind(address).await;
@@ -30,7 +30,9 @@ pub fn start(address: &str, system: Arc<RwLock<System>>) {
                     info!("Accepted new TCP connection: {}", address);
                     let system = system.clone();
                     tokio::spawn(async move {
-                        if let Err(error) = handle_connection(stream, system.clone()).await {
+                        if let Err(error) =
+                            handle_connection(stream, zero_copy_min_bytes, system.clone()).await
+                        {
                             handle_error(error);
                             system.read().await.delete_client(&address).await;
                         }
@@ -44,6 +46,7 @@ pub fn start(address: &str, system: Arc<RwLock<System>>) {
 
 async fn handle_connection(
     stream: TcpStream,
+    zero_copy_min_bytes: Option<u64>,
     system: Arc<RwLock<System>>,
 ) -> Result<(), ServerError> {
     let address = stream.peer_addr()?;
@@ -53,7 +56,10 @@ async fn handle_connection(
         .add_client(&address, Transport::Tcp)
         .await;
     let client_context = ClientContext { client_id };
-    let mut sender = TcpSender { stream };
+    let mut sender = TcpSender {
+        stream,
+        zero_copy_min_bytes,
+    };
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
 
     loop {
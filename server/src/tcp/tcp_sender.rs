@@ -5,11 +5,23 @@ use tokio::io::AsyncWriteExt;
 use tokio::net::TcpStream;
 use tracing::trace;
 
+#[cfg(target_os = "linux")]
+use std::fs::File;
+#[cfg(target_os = "linux")]
+use std::io::ErrorKind;
+#[cfg(target_os = "linux")]
+use std::os::unix::io::AsRawFd;
+#[cfg(target_os = "linux")]
+use streaming::segments::log_slice::LogSlice;
+#[cfg(target_os = "linux")]
+use tokio::io::Interest;
+
 const STATUS_OK: &[u8] = &[0];
 
 #[derive(Debug)]
 pub struct TcpSender {
     pub(crate) stream: TcpStream,
+    pub(crate) zero_copy_min_bytes: Option<u64>,
 }
 
 unsafe impl Send for TcpSender {}
@@ -29,6 +41,52 @@ impl Sender for TcpSender {
         self.send_response(&error.as_code().to_le_bytes(), &[])
             .await
     }
+
+    fn get_zero_copy_min_bytes(&self) -> Option<u64> {
+        self.zero_copy_min_bytes
+    }
+
+    // The batches are sent straight from the log by the kernel, unless they've been trimmed to the requested range.
+    #[cfg(target_os = "linux")]
+    async fn send_ok_log_slices_response(&mut self, log_slices: &[LogSlice]) -> Result<(), Error> {
+        let messages_count = log_slices
+            .iter()
+            .map(|log_slice| log_slice.get_messages_count())
+            .sum::<u32>();
+        let batches_size = log_slices
+            .iter()
+            .map(|log_slice| log_slice.get_size_bytes())
+            .sum::<u64>();
+        trace!(
+            "Sending {} messages from the segment logs, total size: {} bytes...",
+            messages_count,
+            batches_size
+        );
+
+        let mut bytes = Vec::with_capacity(5);
+        bytes.extend(STATUS_OK);
+        bytes.extend((batches_size as u32).to_le_bytes());
+        self.stream.write_all(&bytes).await?;
+        for log_slice in log_slices {
+            for batch in &log_slice.batches {
+                match &batch.trimmed {
+                    Some(trimmed) => self.stream.write_all(trimmed).await?,
+                    None => {
+                        send_file(
+                            &self.stream,
+                            &log_slice.file,
+                            batch.position,
+                            batch.header.length as usize,
+                        )
+                        .await?
+                    }
+                }
+            }
+        }
+
+        trace!("Sent {} messages from the segment logs.", messages_count);
+        Ok(())
+    }
 }
 
 impl TcpSender {
@@ -42,3 +100,40 @@ impl TcpSender {
         Ok(())
     }
 }
+
+#[cfg(target_os = "linux")]
+async fn send_file(
+    stream: &TcpStream,
+    file: &File,
+    mut position: u64,
+    mut length: usize,
+) -> Result<(), Error> {
+    while length > 0 {
+        stream.writable().await?;
+        let result = stream.try_io(Interest::WRITABLE, || {
+            let mut offset = position as libc::off_t;
+            // SAFETY: both descriptors remain open for the duration of the call, and the offset points to a local variable.
+            let sent_bytes = unsafe {
+                libc::sendfile(stream.as_raw_fd(), file.as_raw_fd(), &mut offset, length)
+            };
+            if sent_bytes < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+
+            Ok(sent_bytes as usize)
+        });
+
+        match result {
+            // The log is shorter than expected, e.g. it's been truncated in the meantime.
+            Ok(0) => return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into()),
+            Ok(sent_bytes) => {
+                position += sent_bytes as u64;
+                length -= sent_bytes;
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => continue,
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    Ok(())
+}
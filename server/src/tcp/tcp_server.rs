@@ -7,6 +7,12 @@ use tracing::info;
 
 pub fn start(config: TcpConfig, system: Arc<RwLock<System>>) {
     info!("Initializing Iggy TCP server...");
-    listener::start(&config.address, system);
+    // The messages are sent straight from the segment logs only if they're large enough to make up for finding them.
+    let zero_copy_min_bytes = if config.zero_copy_polling {
+        Some(config.zero_copy_min_bytes)
+    } else {
+        None
+    };
+    listener::start(&config.address, zero_copy_min_bytes, system);
     info!("Iggy TCP server has started on: {:?}", config.address);
 }
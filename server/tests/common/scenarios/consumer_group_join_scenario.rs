@@ -1,14 +1,16 @@
 use crate::common::{ClientFactory, TestServer};
 use sdk::client::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
 use sdk::clients::client::{IggyClient, IggyClientConfig};
+use sdk::compression::CompressionAlgorithm;
 use sdk::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use sdk::consumer_groups::get_consumer_group::GetConsumerGroup;
 use sdk::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use sdk::identifier::Identifier;
 use sdk::models::client_info::ClientInfoDetails;
 use sdk::models::consumer_group::ConsumerGroupDetails;
 use sdk::streams::create_stream::CreateStream;
 use sdk::system::get_me::GetMe;
-use sdk::topics::create_topic::CreateTopic;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, CreateTopic, TopicConfigOverrides};
 use tokio::time::sleep;
 
 const STREAM_ID: u32 = 1;
@@ -30,24 +32,31 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     // 1. Create the stream
     let create_stream = CreateStream {
-        stream_id: STREAM_ID,
+        stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
     // 2. Create the topic
     let create_topic = CreateTopic {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Some(TOPIC_ID),
         partitions_count: PARTITIONS_COUNT,
+        message_expiry: None,
+        max_topic_size_bytes: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compression: CompressionAlgorithm::None,
+        deduplication: None,
+        checksum_policy: ChecksumPolicy::default(),
+        overrides: TopicConfigOverrides::default(),
         name: TOPIC_NAME.to_string(),
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
     // 3. Create the consumer group
     let create_group = CreateConsumerGroup {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         consumer_group_id: CONSUMER_GROUP_ID,
     };
     system_client
@@ -108,8 +117,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
 async fn join_consumer_group(client: &IggyClient) {
     let join_group = JoinConsumerGroup {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         consumer_group_id: CONSUMER_GROUP_ID,
     };
     client.join_consumer_group(&join_group).await.unwrap();
@@ -136,8 +145,8 @@ async fn get_consumer_group_and_validate_members(
     members_count: u32,
 ) -> ConsumerGroupDetails {
     let get_group = GetConsumerGroup {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         consumer_group_id: CONSUMER_GROUP_ID,
     };
     let consumer_group = client.get_consumer_group(&get_group).await.unwrap();
@@ -1,17 +1,19 @@
 use crate::common::{ClientFactory, TestServer};
 use sdk::client::{ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient};
 use sdk::clients::client::{IggyClient, IggyClientConfig};
+use sdk::compression::CompressionAlgorithm;
 use sdk::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use sdk::consumer_groups::get_consumer_group::GetConsumerGroup;
 use sdk::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use sdk::consumer_type::ConsumerType;
+use sdk::identifier::Identifier;
 use sdk::messages::poll_messages::Kind::Next;
 use sdk::messages::poll_messages::{Format, PollMessages};
 use sdk::messages::send_messages::{KeyKind, Message, SendMessages};
 use sdk::models::consumer_group::ConsumerGroupDetails;
 use sdk::streams::create_stream::CreateStream;
 use sdk::system::get_me::GetMe;
-use sdk::topics::create_topic::CreateTopic;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, CreateTopic, TopicConfigOverrides};
 use std::str::{from_utf8, FromStr};
 use tokio::time::sleep;
 
@@ -35,24 +37,31 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     // 1. Create the stream
     let create_stream = CreateStream {
-        stream_id: STREAM_ID,
+        stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
     // 2. Create the topic
     let create_topic = CreateTopic {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Some(TOPIC_ID),
         partitions_count: PARTITIONS_COUNT,
+        message_expiry: None,
+        max_topic_size_bytes: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compression: CompressionAlgorithm::None,
+        deduplication: None,
+        checksum_policy: ChecksumPolicy::default(),
+        overrides: TopicConfigOverrides::default(),
         name: TOPIC_NAME.to_string(),
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
     // 3. Create the consumer group
     let create_group = CreateConsumerGroup {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         consumer_group_id: CONSUMER_GROUP_ID,
     };
     system_client
@@ -61,8 +70,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
 
     let join_group = JoinConsumerGroup {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         consumer_group_id: CONSUMER_GROUP_ID,
     };
 
@@ -81,12 +90,14 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         let message = Message::from_str(&get_message_payload(partition_id, entity_id)).unwrap();
         let messages = vec![message];
         let send_messages = SendMessages {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             key_kind: KeyKind::EntityId,
             key_value: entity_id,
             messages_count: 1,
+            compression: CompressionAlgorithm::None,
             messages,
+            batch: None,
         };
         system_client.send_messages(&send_messages).await.unwrap();
     }
@@ -94,8 +105,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     // 6. Get the consumer group details
     let consumer_group_info = system_client
         .get_consumer_group(&GetConsumerGroup {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             consumer_group_id: CONSUMER_GROUP_ID,
         })
         .await
@@ -129,14 +140,15 @@ async fn validate_message_polling(client: &IggyClient, consumer_group: &Consumer
     let poll_messages = PollMessages {
         consumer_type: ConsumerType::ConsumerGroup,
         consumer_id: CONSUMER_GROUP_ID,
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         partition_id: 0,
         kind: Next,
         value: 0,
         count: 1,
         auto_commit: true,
         format: Format::None,
+        compression: CompressionAlgorithm::None,
     };
 
     for i in 1..=MESSAGES_COUNT_PER_PARTITION {
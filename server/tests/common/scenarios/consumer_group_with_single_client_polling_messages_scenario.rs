@@ -1,16 +1,18 @@
 use crate::common::{ClientFactory, TestServer};
 use sdk::client::{ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient};
 use sdk::clients::client::{IggyClient, IggyClientConfig};
+use sdk::compression::CompressionAlgorithm;
 use sdk::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use sdk::consumer_groups::get_consumer_group::GetConsumerGroup;
 use sdk::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use sdk::consumer_type::ConsumerType;
+use sdk::identifier::Identifier;
 use sdk::messages::poll_messages::Kind::Next;
 use sdk::messages::poll_messages::{Format, PollMessages};
 use sdk::messages::send_messages::{KeyKind, Message, SendMessages};
 use sdk::streams::create_stream::CreateStream;
 use sdk::system::get_me::GetMe;
-use sdk::topics::create_topic::CreateTopic;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, CreateTopic, TopicConfigOverrides};
 use std::str::{from_utf8, FromStr};
 use tokio::time::sleep;
 
@@ -32,31 +34,38 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     // 1. Create the stream
     let create_stream = CreateStream {
-        stream_id: STREAM_ID,
+        stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
     };
     client.create_stream(&create_stream).await.unwrap();
 
     // 2. Create the topic
     let create_topic = CreateTopic {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Some(TOPIC_ID),
         partitions_count: PARTITIONS_COUNT,
+        message_expiry: None,
+        max_topic_size_bytes: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compression: CompressionAlgorithm::None,
+        deduplication: None,
+        checksum_policy: ChecksumPolicy::default(),
+        overrides: TopicConfigOverrides::default(),
         name: TOPIC_NAME.to_string(),
     };
     client.create_topic(&create_topic).await.unwrap();
 
     // 3. Create the consumer group
     let create_group = CreateConsumerGroup {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         consumer_group_id: CONSUMER_GROUP_ID,
     };
     client.create_consumer_group(&create_group).await.unwrap();
 
     let join_group = JoinConsumerGroup {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         consumer_group_id: CONSUMER_GROUP_ID,
     };
 
@@ -66,8 +75,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     // 5. Validate that group contains the single client with all partitions assigned
     let consumer_group_info = client
         .get_consumer_group(&GetConsumerGroup {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             consumer_group_id: CONSUMER_GROUP_ID,
         })
         .await
@@ -92,12 +101,14 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         let message = Message::from_str(&get_message_payload(partition_id, entity_id)).unwrap();
         let messages = vec![message];
         let send_messages = SendMessages {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             key_kind: KeyKind::EntityId,
             key_value: entity_id,
             messages_count: 1,
+            compression: CompressionAlgorithm::None,
             messages,
+            batch: None,
         };
         client.send_messages(&send_messages).await.unwrap();
     }
@@ -106,14 +117,15 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let poll_messages = PollMessages {
         consumer_type: ConsumerType::ConsumerGroup,
         consumer_id: CONSUMER_GROUP_ID,
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         partition_id: 0,
         kind: Next,
         value: 0,
         count: 1,
         auto_commit: true,
         format: Format::None,
+        compression: CompressionAlgorithm::None,
     };
 
     let mut partition_id = 1;
@@ -1,7 +1,10 @@
 use crate::common::{ClientFactory, TestServer};
 use bytes::Bytes;
-use sdk::client::{ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient};
+use sdk::client::{
+    ConsumerGroupClient, MessageClient, PartitionClient, StreamClient, SystemClient, TopicClient,
+};
 use sdk::clients::client::{IggyClient, IggyClientConfig};
+use sdk::compression::CompressionAlgorithm;
 use sdk::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use sdk::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use sdk::consumer_groups::get_consumer_group::GetConsumerGroup;
@@ -10,22 +13,33 @@ use sdk::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use sdk::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use sdk::consumer_type::ConsumerType;
 use sdk::error::Error;
+use sdk::identifier::Identifier;
+use sdk::messages::delete_messages_before::DeleteMessagesBefore;
 use sdk::messages::poll_messages::Kind::{Next, Offset};
 use sdk::messages::poll_messages::{Format, PollMessages};
 use sdk::messages::send_messages::{KeyKind, Message, SendMessages};
 use sdk::offsets::get_offset::GetOffset;
 use sdk::offsets::store_offset::StoreOffset;
+use sdk::partitions::create_partitions::CreatePartitions;
+use sdk::partitions::delete_partitions::DeletePartitions;
+use sdk::partitions::purge_partition::PurgePartition;
+use sdk::partitions::rebuild_indexes::RebuildIndexes;
 use sdk::streams::create_stream::CreateStream;
 use sdk::streams::delete_stream::DeleteStream;
 use sdk::streams::get_stream::GetStream;
 use sdk::streams::get_streams::GetStreams;
+use sdk::streams::update_stream::UpdateStream;
 use sdk::system::get_clients::GetClients;
 use sdk::system::get_me::GetMe;
 use sdk::system::ping::Ping;
-use sdk::topics::create_topic::CreateTopic;
+use sdk::topics::create_topic::{
+    ChecksumPolicy, CleanupPolicy, CreateTopic, DeduplicationWindow, TopicConfigOverrides,
+};
 use sdk::topics::delete_topic::DeleteTopic;
 use sdk::topics::get_topic::GetTopic;
 use sdk::topics::get_topics::GetTopics;
+use sdk::topics::purge_topic::PurgeTopic;
+use sdk::topics::update_topic::UpdateTopic;
 use tokio::time::sleep;
 
 const STREAM_ID: u32 = 1;
@@ -56,7 +70,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     // 3. Create the stream
     let create_stream = CreateStream {
-        stream_id: STREAM_ID,
+        stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
     };
     client.create_stream(&create_stream).await.unwrap();
@@ -72,7 +86,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     // 5. Get stream details
     let stream = client
         .get_stream(&GetStream {
-            stream_id: STREAM_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
         })
         .await
         .unwrap();
@@ -83,9 +97,23 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     // 6. Create the topic
     let create_topic = CreateTopic {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Some(TOPIC_ID),
         partitions_count: PARTITIONS_COUNT,
+        message_expiry: None,
+        max_topic_size_bytes: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compression: CompressionAlgorithm::None,
+        deduplication: Some(DeduplicationWindow {
+            max_ids: Some(10000),
+            expiry: None,
+        }),
+        checksum_policy: ChecksumPolicy::default(),
+        overrides: TopicConfigOverrides {
+            segment_size_bytes: Some(10_000_000),
+            messages_required_to_save: Some(100),
+            enforce_sync: None,
+        },
         name: TOPIC_NAME.to_string(),
     };
     client.create_topic(&create_topic).await.unwrap();
@@ -93,7 +121,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     // 7. Get topics and validate that created topic exists
     let topics = client
         .get_topics(&GetTopics {
-            stream_id: STREAM_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
         })
         .await
         .unwrap();
@@ -103,11 +131,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.name, TOPIC_NAME);
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
 
-    // 8. Get topic details
+    // 8. Get topic details by the stream and topic names
     let topic = client
         .get_topic(&GetTopic {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::named(STREAM_NAME),
+            topic_id: Identifier::named(TOPIC_NAME),
         })
         .await
         .unwrap();
@@ -115,6 +143,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.name, TOPIC_NAME);
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
     assert_eq!(topic.partitions.len(), PARTITIONS_COUNT as usize);
+    assert_eq!(topic.overrides, create_topic.overrides);
     let mut id = 1;
     for topic_partition in topic.partitions {
         assert_eq!(topic_partition.id, id);
@@ -127,7 +156,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     // 9. Get stream details and validate that created topic exists
     let stream = client
         .get_stream(&GetStream {
-            stream_id: STREAM_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
         })
         .await
         .unwrap();
@@ -140,7 +169,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(stream_topic.name, topic.name);
     assert_eq!(stream_topic.partitions_count, topic.partitions_count);
 
-    // 10. Send messages to the specific topic and partition
+    // 10. Send the compressed messages to the specific topic and partition
     let messages_count = 1000u32;
     let mut messages = Vec::new();
     for offset in 0..messages_count {
@@ -154,27 +183,33 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     }
 
     let send_messages = SendMessages {
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         key_kind: KeyKind::PartitionId,
         key_value: PARTITION_ID,
         messages_count,
+        compression: CompressionAlgorithm::Zstd,
         messages,
+        batch: None,
     };
     client.send_messages(&send_messages).await.unwrap();
 
+    // The same messages sent again are rejected, as their IDs are still in the deduplication window of the topic
+    assert!(client.send_messages(&send_messages).await.is_err());
+
     // 11. Poll messages from the specific partition in topic
     let poll_messages = PollMessages {
         consumer_type: CONSUMER_TYPE,
         consumer_id: CONSUMER_ID,
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         partition_id: PARTITION_ID,
         kind: Offset,
         value: 0,
         count: messages_count,
         auto_commit: false,
         format: Format::None,
+        compression: CompressionAlgorithm::None,
     };
 
     let messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -185,7 +220,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         assert_message(message, offset);
     }
 
-    // 12. Messages should be also polled in the smaller batches
+    // 12. Messages should be also polled in the smaller, compressed batches
     let batches_count = 10;
     let batch_size = messages_count / batches_count;
     for i in 0..batches_count {
@@ -193,14 +228,15 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         let poll_messages = PollMessages {
             consumer_type: CONSUMER_TYPE,
             consumer_id: CONSUMER_ID,
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             partition_id: PARTITION_ID,
             kind: Offset,
             value: start_offset,
             count: batch_size,
             auto_commit: false,
             format: Format::None,
+            compression: CompressionAlgorithm::Lz4,
         };
 
         let messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -215,8 +251,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     // 13. Get topic details and validate the partition details
     let topic = client
         .get_topic(&GetTopic {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
         })
         .await
         .unwrap();
@@ -229,19 +265,21 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic_partition.segments_count, 1);
     assert!(topic_partition.size_bytes > 0);
     assert_eq!(topic_partition.current_offset, (messages_count - 1) as u64);
+    assert_eq!(topic_partition.corrupted_messages_count, 0);
 
     // 14. Ensure that messages do not exist in the second partition in the same topic
     let poll_messages = PollMessages {
         consumer_type: CONSUMER_TYPE,
         consumer_id: CONSUMER_ID,
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         partition_id: PARTITION_ID + 1,
         kind: Offset,
         value: 0,
         count: messages_count,
         auto_commit: false,
         format: Format::None,
+        compression: CompressionAlgorithm::None,
     };
     let messages = client.poll_messages(&poll_messages).await.unwrap();
     assert!(messages.is_empty());
@@ -251,8 +289,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .get_offset(&GetOffset {
             consumer_type: CONSUMER_TYPE,
             consumer_id: CONSUMER_ID,
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             partition_id: PARTITION_ID,
         })
         .await
@@ -266,8 +304,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .store_offset(&StoreOffset {
             consumer_type: CONSUMER_TYPE,
             consumer_id: CONSUMER_ID,
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             partition_id: PARTITION_ID,
             offset: stored_offset,
         })
@@ -279,8 +317,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .get_offset(&GetOffset {
             consumer_type: CONSUMER_TYPE,
             consumer_id: CONSUMER_ID,
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             partition_id: PARTITION_ID,
         })
         .await
@@ -293,14 +331,15 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let poll_messages = PollMessages {
         consumer_type: CONSUMER_TYPE,
         consumer_id: CONSUMER_ID,
-        stream_id: STREAM_ID,
-        topic_id: TOPIC_ID,
+        stream_id: Identifier::numeric(STREAM_ID),
+        topic_id: Identifier::numeric(TOPIC_ID),
         partition_id: PARTITION_ID,
         kind: Next,
         value: 0,
         count: messages_count,
         auto_commit: true,
         format: Format::None,
+        compression: CompressionAlgorithm::None,
     };
 
     let messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -316,8 +355,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .get_offset(&GetOffset {
             consumer_type: CONSUMER_TYPE,
             consumer_id: CONSUMER_ID,
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             partition_id: PARTITION_ID,
         })
         .await
@@ -325,32 +364,141 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.consumer_id, CONSUMER_ID);
     assert_eq!(offset.offset, expected_last_offset);
 
-    // 20. Get the consumer groups and validate that there are no groups
+    // 20. Rebuild the partition indexes and ensure that the messages can be still polled
+    client
+        .rebuild_indexes(&RebuildIndexes {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partition_id: PARTITION_ID,
+        })
+        .await
+        .unwrap();
+    let messages = client
+        .poll_messages(&PollMessages {
+            consumer_type: CONSUMER_TYPE,
+            consumer_id: CONSUMER_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partition_id: PARTITION_ID,
+            kind: Offset,
+            value: 0,
+            count: messages_count,
+            auto_commit: false,
+            format: Format::None,
+            compression: CompressionAlgorithm::None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(messages.len() as u32, messages_count);
+
+    // 21. Delete the messages before the offset and ensure that the remaining ones are polled from that offset
+    let log_start_offset = 100;
+    client
+        .delete_messages_before(&DeleteMessagesBefore {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partition_id: PARTITION_ID,
+            offset: log_start_offset,
+        })
+        .await
+        .unwrap();
+    let messages = client
+        .poll_messages(&PollMessages {
+            consumer_type: CONSUMER_TYPE,
+            consumer_id: CONSUMER_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partition_id: PARTITION_ID,
+            kind: Offset,
+            value: 0,
+            count: messages_count,
+            auto_commit: false,
+            format: Format::None,
+            compression: CompressionAlgorithm::None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(messages.len() as u32, messages_count);
+    assert_eq!(messages[0].offset, log_start_offset);
+
+    // 22. Purge the partition and ensure that its messages and the consumer offset don't exist anymore
+    client
+        .purge_partition(&PurgePartition {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partition_id: PARTITION_ID,
+        })
+        .await
+        .unwrap();
+    let poll_messages = PollMessages {
+        partition_id: PARTITION_ID,
+        ..poll_messages
+    };
+    let messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert!(messages.is_empty());
+    let offset = client
+        .get_offset(&GetOffset {
+            consumer_type: CONSUMER_TYPE,
+            consumer_id: CONSUMER_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partition_id: PARTITION_ID,
+        })
+        .await
+        .unwrap();
+    assert_eq!(offset.offset, 0);
+
+    // 23. Send the messages again, purge the topic and ensure that it's kept with the empty partitions
+    client.send_messages(&send_messages).await.unwrap();
+    client
+        .purge_topic(&PurgeTopic {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+        })
+        .await
+        .unwrap();
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+    for partition in topic.partitions {
+        assert_eq!(partition.segments_count, 1);
+        assert_eq!(partition.size_bytes, 0);
+        assert_eq!(partition.current_offset, 0);
+    }
+    let messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert!(messages.is_empty());
+
+    // 24. Get the consumer groups and validate that there are no groups
     let consumer_groups = client
         .get_consumer_groups(&GetConsumerGroups {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
         })
         .await
         .unwrap();
 
     assert!(consumer_groups.is_empty());
 
-    // 21. Create the consumer group
+    // 25. Create the consumer group
     client
         .create_consumer_group(&CreateConsumerGroup {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             consumer_group_id: CONSUMER_GROUP_ID,
         })
         .await
         .unwrap();
 
-    // 22. Get the consumer groups and validate that there is one group
+    // 26. Get the consumer groups and validate that there is one group
     let consumer_groups = client
         .get_consumer_groups(&GetConsumerGroups {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
         })
         .await
         .unwrap();
@@ -361,11 +509,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
     assert_eq!(consumer_group.members_count, 0);
 
-    // 23. Get the consumer group details
+    // 27. Get the consumer group details
     let consumer_group = client
         .get_consumer_group(&GetConsumerGroup {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             consumer_group_id: CONSUMER_GROUP_ID,
         })
         .await
@@ -376,11 +524,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.members_count, 0);
     assert!(consumer_group.members.is_empty());
 
-    // 24. Join the consumer group and then leave it if the feature is available
+    // 28. Join the consumer group and then leave it if the feature is available
     let result = client
         .join_consumer_group(&JoinConsumerGroup {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             consumer_group_id: CONSUMER_GROUP_ID,
         })
         .await;
@@ -389,8 +537,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         Ok(_) => {
             let consumer_group = client
                 .get_consumer_group(&GetConsumerGroup {
-                    stream_id: STREAM_ID,
-                    topic_id: TOPIC_ID,
+                    stream_id: Identifier::numeric(STREAM_ID),
+                    topic_id: Identifier::numeric(TOPIC_ID),
                     consumer_group_id: CONSUMER_GROUP_ID,
                 })
                 .await
@@ -413,8 +561,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
             client
                 .leave_consumer_group(&LeaveConsumerGroup {
-                    stream_id: STREAM_ID,
-                    topic_id: TOPIC_ID,
+                    stream_id: Identifier::numeric(STREAM_ID),
+                    topic_id: Identifier::numeric(TOPIC_ID),
                     consumer_group_id: CONSUMER_GROUP_ID,
                 })
                 .await
@@ -422,8 +570,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
             let consumer_group = client
                 .get_consumer_group(&GetConsumerGroup {
-                    stream_id: STREAM_ID,
-                    topic_id: TOPIC_ID,
+                    stream_id: Identifier::numeric(STREAM_ID),
+                    topic_id: Identifier::numeric(TOPIC_ID),
                     consumer_group_id: CONSUMER_GROUP_ID,
                 })
                 .await
@@ -438,43 +586,151 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         Err(e) => assert_eq!(e.as_code(), Error::FeatureUnavailable.as_code()),
     }
 
-    // 25. Delete the consumer group
+    // 29. Create the partitions and ensure that they are assigned to the consumer group
+    let created_partitions = client
+        .create_partitions(&CreatePartitions {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partitions_count: 2,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        created_partitions.previous_partitions_count,
+        PARTITIONS_COUNT
+    );
+    assert_eq!(created_partitions.partitions_count, PARTITIONS_COUNT + 2);
+
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT + 2);
+    assert_eq!(topic.partitions.len(), (PARTITIONS_COUNT + 2) as usize);
+
+    let consumer_group = client
+        .get_consumer_group(&GetConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            consumer_group_id: CONSUMER_GROUP_ID,
+        })
+        .await
+        .unwrap();
+    assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT + 2);
+
+    // 30. Delete the empty partitions, which are drained immediately, and ensure that they're unassigned
+    client
+        .delete_partitions(&DeletePartitions {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            partitions_count: 2,
+        })
+        .await
+        .unwrap();
+
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+    assert_eq!(topic.partitions.len(), PARTITIONS_COUNT as usize);
+
+    let consumer_group = client
+        .get_consumer_group(&GetConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            consumer_group_id: CONSUMER_GROUP_ID,
+        })
+        .await
+        .unwrap();
+    assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
+
+    // 31. Delete the consumer group
     client
         .delete_consumer_group(&DeleteConsumerGroup {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
             consumer_group_id: CONSUMER_GROUP_ID,
         })
         .await
         .unwrap();
 
-    // 26. Delete the existing topic and ensure it doesn't exist anymore
+    // 32. Update the stream and ensure that it's renamed
+    let updated_stream_name = format!("{}-updated", STREAM_NAME);
+    client
+        .update_stream(&UpdateStream {
+            stream_id: Identifier::numeric(STREAM_ID),
+            name: updated_stream_name.clone(),
+        })
+        .await
+        .unwrap();
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::numeric(STREAM_ID),
+        })
+        .await
+        .unwrap();
+    assert_eq!(stream.name, updated_stream_name);
+
+    // 33. Update the topic and ensure that it's renamed, while its partitions are kept
+    let updated_topic_name = format!("{}-updated", TOPIC_NAME);
+    client
+        .update_topic(&UpdateTopic {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+            message_expiry: Some(3600),
+            max_topic_size_bytes: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            compression: CompressionAlgorithm::None,
+            deduplication: None,
+            checksum_policy: ChecksumPolicy::Log,
+            name: updated_topic_name.clone(),
+        })
+        .await
+        .unwrap();
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.name, updated_topic_name);
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+
+    // 34. Delete the existing topic and ensure it doesn't exist anymore
     client
         .delete_topic(&DeleteTopic {
-            stream_id: STREAM_ID,
-            topic_id: TOPIC_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
+            topic_id: Identifier::numeric(TOPIC_ID),
         })
         .await
         .unwrap();
     let topics = client
         .get_topics(&GetTopics {
-            stream_id: STREAM_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
         })
         .await
         .unwrap();
     assert!(topics.is_empty());
 
-    // 27. Delete the existing stream and ensure it doesn't exist anymore
+    // 35. Delete the existing stream and ensure it doesn't exist anymore
     client
         .delete_stream(&DeleteStream {
-            stream_id: STREAM_ID,
+            stream_id: Identifier::numeric(STREAM_ID),
         })
         .await
         .unwrap();
     let streams = client.get_streams(&GetStreams {}).await.unwrap();
     assert!(streams.is_empty());
 
-    // 28. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
+    // 36. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
     let clients = client.get_clients(&GetClients {}).await.unwrap();
 
     assert!(clients.len() <= 1);
@@ -0,0 +1,353 @@
+use crate::message::Message;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::trace;
+
+type PartitionKey = (u32, u32, u32);
+
+#[derive(Debug, Default)]
+pub struct MessagesCache {
+    max_size_bytes: u64,
+    partitions: Mutex<CachedPartitions>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct CachedPartitions {
+    partitions: HashMap<PartitionKey, CachedMessages>,
+    size_bytes: u64,
+    // The logical clock, which is incremented on every access, as the order is all that matters for the eviction.
+    clock: u64,
+}
+
+#[derive(Debug, Default)]
+struct CachedMessages {
+    messages: VecDeque<Arc<Message>>,
+    size_bytes: u64,
+    last_used_at: u64,
+}
+
+impl MessagesCache {
+    pub fn new(max_size_bytes: u64) -> Self {
+        Self {
+            max_size_bytes,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_size_bytes > 0
+    }
+
+    pub fn get_max_size_bytes(&self) -> u64 {
+        self.max_size_bytes
+    }
+
+    pub fn get_size_bytes(&self) -> u64 {
+        self.partitions.lock().unwrap().size_bytes
+    }
+
+    pub fn get_messages_count(&self) -> u64 {
+        self.partitions
+            .lock()
+            .unwrap()
+            .partitions
+            .values()
+            .map(|cached| cached.messages.len() as u64)
+            .sum()
+    }
+
+    pub fn get_hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn get_misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    pub fn append(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        messages: &[Arc<Message>],
+    ) {
+        if !self.is_enabled() || messages.is_empty() {
+            return;
+        }
+
+        let mut cached_partitions = self.partitions.lock().unwrap();
+        cached_partitions.clock += 1;
+        let clock = cached_partitions.clock;
+        let CachedPartitions {
+            partitions,
+            size_bytes,
+            ..
+        } = &mut *cached_partitions;
+        let cached = partitions
+            .entry((stream_id, topic_id, partition_id))
+            .or_default();
+        cached.last_used_at = clock;
+        for message in messages {
+            if let Some(last_message) = cached.messages.back() {
+                if message.offset != last_message.offset + 1 {
+                    *size_bytes -= cached.size_bytes;
+                    cached.size_bytes = 0;
+                    cached.messages.clear();
+                }
+            }
+
+            let message_size_bytes = message.get_size_bytes() as u64;
+            cached.size_bytes += message_size_bytes;
+            *size_bytes += message_size_bytes;
+            cached.messages.push_back(message.clone());
+        }
+
+        self.evict(&mut cached_partitions);
+    }
+
+    pub fn get(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Option<Vec<Arc<Message>>> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let mut partitions = self.partitions.lock().unwrap();
+        partitions.clock += 1;
+        let clock = partitions.clock;
+        let cached = partitions
+            .partitions
+            .get_mut(&(stream_id, topic_id, partition_id))
+            .filter(|cached| cached.contains(start_offset, end_offset));
+        let cached = match cached {
+            Some(cached) => cached,
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+        };
+
+        cached.last_used_at = clock;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        let start_index = (start_offset - cached.messages[0].offset) as usize;
+        let end_index = (end_offset - cached.messages[0].offset) as usize;
+        trace!(
+            "Loading messages from cache, start offset: {}, end offset: {}...",
+            start_offset,
+            end_offset
+        );
+        Some(
+            cached
+                .messages
+                .range(start_index..=end_index)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    pub fn contains(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+
+        self.partitions
+            .lock()
+            .unwrap()
+            .partitions
+            .get(&(stream_id, topic_id, partition_id))
+            .is_some_and(|cached| cached.contains(start_offset, end_offset))
+    }
+
+    pub fn remove_partition(&self, stream_id: u32, topic_id: u32, partition_id: u32) {
+        self.remove(|key| *key == (stream_id, topic_id, partition_id));
+    }
+
+    pub fn remove_topic(&self, stream_id: u32, topic_id: u32) {
+        self.remove(|key| key.0 == stream_id && key.1 == topic_id);
+    }
+
+    pub fn remove_stream(&self, stream_id: u32) {
+        self.remove(|key| key.0 == stream_id);
+    }
+
+    fn remove(&self, predicate: impl Fn(&PartitionKey) -> bool) {
+        let mut partitions = self.partitions.lock().unwrap();
+        let CachedPartitions {
+            partitions,
+            size_bytes,
+            ..
+        } = &mut *partitions;
+        partitions.retain(|key, cached| {
+            if !predicate(key) {
+                return true;
+            }
+
+            *size_bytes -= cached.size_bytes;
+            false
+        });
+    }
+
+    fn evict(&self, partitions: &mut CachedPartitions) {
+        let CachedPartitions {
+            partitions,
+            size_bytes,
+            ..
+        } = partitions;
+        while *size_bytes > self.max_size_bytes {
+            let least_recently_used = partitions
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used_at)
+                .map(|(key, _)| *key);
+            let key = match least_recently_used {
+                Some(key) => key,
+                None => return,
+            };
+
+            let cached = partitions.get_mut(&key).unwrap();
+            while *size_bytes > self.max_size_bytes {
+                match cached.messages.pop_front() {
+                    Some(message) => {
+                        let message_size_bytes = message.get_size_bytes() as u64;
+                        cached.size_bytes -= message_size_bytes;
+                        *size_bytes -= message_size_bytes;
+                    }
+                    None => break,
+                }
+            }
+
+            if cached.messages.is_empty() {
+                partitions.remove(&key);
+            }
+            trace!(
+                "Evicted the cached messages of partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                key.2,
+                key.0,
+                key.1
+            );
+        }
+    }
+}
+
+impl CachedMessages {
+    fn contains(&self, start_offset: u64, end_offset: u64) -> bool {
+        match (self.messages.front(), self.messages.back()) {
+            (Some(first_message), Some(last_message)) => {
+                start_offset <= end_offset
+                    && start_offset >= first_message.offset
+                    && end_offset <= last_message.offset
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    #[test]
+    fn should_return_the_cached_messages_in_range_and_count_hits_and_misses() {
+        let cache = MessagesCache::new(1024 * 1024);
+        cache.append(1, 1, 1, &get_messages(0, 10, 10));
+
+        let messages = cache.get(1, 1, 1, 3, 5).unwrap();
+        assert_offsets(&messages, &[3, 4, 5]);
+        assert!(cache.get(1, 1, 1, 8, 10).is_none());
+        assert!(cache.get(1, 1, 2, 0, 0).is_none());
+        assert!(cache.contains(1, 1, 1, 0, 9));
+        assert_eq!(cache.get_hits(), 1);
+        assert_eq!(cache.get_misses(), 2);
+        assert_eq!(cache.get_messages_count(), 10);
+        assert_eq!(cache.get_size_bytes(), 10 * (28 + 10));
+    }
+
+    #[test]
+    fn should_drop_the_cached_messages_not_followed_by_the_appended_ones() {
+        let cache = MessagesCache::new(1024 * 1024);
+        cache.append(1, 1, 1, &get_messages(0, 5, 10));
+        cache.append(1, 1, 1, &get_messages(7, 3, 10));
+
+        assert!(!cache.contains(1, 1, 1, 0, 4));
+        assert_offsets(&cache.get(1, 1, 1, 7, 9).unwrap(), &[7, 8, 9]);
+        assert_eq!(cache.get_size_bytes(), 3 * (28 + 10));
+    }
+
+    #[test]
+    fn should_evict_the_oldest_messages_of_the_least_recently_used_partition_first() {
+        let message_size_bytes = 28 + 72;
+        let cache = MessagesCache::new(10 * message_size_bytes);
+        cache.append(1, 1, 1, &get_messages(0, 5, 72));
+        cache.append(1, 1, 2, &get_messages(0, 5, 72));
+        assert!(cache.get(1, 1, 1, 0, 4).is_some());
+
+        cache.append(1, 1, 3, &get_messages(0, 3, 72));
+
+        assert!(cache.contains(1, 1, 1, 0, 4));
+        assert!(!cache.contains(1, 1, 2, 0, 2));
+        assert!(cache.contains(1, 1, 2, 3, 4));
+        assert!(cache.contains(1, 1, 3, 0, 2));
+        assert_eq!(cache.get_size_bytes(), 10 * message_size_bytes);
+    }
+
+    #[test]
+    fn should_remove_the_cached_messages_of_the_deleted_topic() {
+        let cache = MessagesCache::new(1024 * 1024);
+        cache.append(1, 1, 1, &get_messages(0, 5, 10));
+        cache.append(1, 2, 1, &get_messages(0, 5, 10));
+
+        cache.remove_topic(1, 1);
+
+        assert!(!cache.contains(1, 1, 1, 0, 4));
+        assert!(cache.contains(1, 2, 1, 0, 4));
+        assert_eq!(cache.get_size_bytes(), 5 * (28 + 10));
+    }
+
+    #[test]
+    fn should_not_cache_messages_given_zero_size() {
+        let cache = MessagesCache::new(0);
+        cache.append(1, 1, 1, &get_messages(0, 5, 10));
+
+        assert!(!cache.is_enabled());
+        assert!(cache.get(1, 1, 1, 0, 4).is_none());
+        assert_eq!(cache.get_size_bytes(), 0);
+    }
+
+    fn get_messages(start_offset: u64, count: u64, payload_size: usize) -> Vec<Arc<Message>> {
+        (start_offset..start_offset + count)
+            .map(|offset| {
+                Arc::new(Message::create(
+                    offset,
+                    offset,
+                    offset as u128,
+                    Bytes::from(vec![0; payload_size]),
+                ))
+            })
+            .collect()
+    }
+
+    fn assert_offsets(messages: &[Arc<Message>], offsets: &[u64]) {
+        assert_eq!(
+            messages
+                .iter()
+                .map(|message| message.offset)
+                .collect::<Vec<_>>(),
+            offsets
+        );
+    }
+}
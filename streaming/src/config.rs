@@ -1,3 +1,4 @@
+use byte_unit::Byte;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -5,6 +6,35 @@ use std::sync::Arc;
 pub struct SystemConfig {
     pub path: String,
     pub stream: Arc<StreamConfig>,
+    pub archive: Arc<ArchiveConfig>,
+    pub storage: Arc<StorageConfig>,
+    pub cache: Arc<CacheConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ArchiveConfig {
+    pub enabled: bool,
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CacheConfig {
+    pub enabled: bool,
+    pub size: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StorageConfig {
+    pub kind: StorageKind,
+    pub memory_streams: Vec<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageKind {
+    #[default]
+    File,
+    Memory,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -24,17 +54,19 @@ pub struct PartitionConfig {
     pub path: String,
     pub segment: Arc<SegmentConfig>,
     pub messages_required_to_save: u32,
-    pub messages_buffer: u32,
-    pub deduplicate_messages: bool,
     pub enforce_sync: bool,
     pub validate_checksum: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
-    pub size_bytes: u32,
+    pub size_bytes: u64,
+    pub index_interval_bytes: u32,
     pub cache_indexes: bool,
     pub cache_time_indexes: bool,
+    pub mmap_reads: bool,
+    pub mmap_max_segments: u32,
+    pub mmap_idle_timeout: u32,
 }
 
 impl Default for SystemConfig {
@@ -42,10 +74,54 @@ impl Default for SystemConfig {
         SystemConfig {
             path: "local_data".to_string(),
             stream: Arc::new(StreamConfig::default()),
+            archive: Arc::new(ArchiveConfig::default()),
+            storage: Arc::new(StorageConfig::default()),
+            cache: Arc::new(CacheConfig::default()),
+        }
+    }
+}
+
+impl Default for ArchiveConfig {
+    fn default() -> ArchiveConfig {
+        ArchiveConfig {
+            enabled: false,
+            path: "local_archive".to_string(),
+        }
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> CacheConfig {
+        CacheConfig {
+            enabled: true,
+            size: "4 GB".to_string(),
         }
     }
 }
 
+impl CacheConfig {
+    pub fn get_size_bytes(&self) -> Option<u64> {
+        Byte::from_str(&self.size)
+            .ok()
+            .map(|size| size.get_bytes() as u64)
+    }
+}
+
+impl Default for StorageConfig {
+    fn default() -> StorageConfig {
+        StorageConfig {
+            kind: StorageKind::File,
+            memory_streams: Vec::new(),
+        }
+    }
+}
+
+impl StorageConfig {
+    pub fn is_memory_stream(&self, stream_id: u32) -> bool {
+        self.kind == StorageKind::Memory || self.memory_streams.contains(&stream_id)
+    }
+}
+
 impl Default for StreamConfig {
     fn default() -> StreamConfig {
         StreamConfig {
@@ -70,8 +146,6 @@ impl Default for PartitionConfig {
             path: "partitions".to_string(),
             segment: Arc::new(SegmentConfig::default()),
             messages_required_to_save: 1000,
-            messages_buffer: 1024,
-            deduplicate_messages: false,
             enforce_sync: false,
             validate_checksum: false,
         }
@@ -82,8 +156,12 @@ impl Default for SegmentConfig {
     fn default() -> SegmentConfig {
         SegmentConfig {
             size_bytes: 1024 * 1024 * 1024,
+            index_interval_bytes: 0,
             cache_indexes: true,
             cache_time_indexes: true,
+            mmap_reads: false,
+            mmap_max_segments: 100,
+            mmap_idle_timeout: 60,
         }
     }
 }
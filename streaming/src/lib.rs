@@ -1,9 +1,13 @@
+pub mod cache;
 pub mod clients;
 pub mod config;
+pub mod memory_storage;
 pub mod message;
+pub mod migration;
 pub mod partitions;
 pub mod persister;
 pub mod polling_consumer;
+pub mod remote_store;
 pub mod segments;
 pub mod storage;
 pub mod streams;
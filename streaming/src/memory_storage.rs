@@ -0,0 +1,841 @@
+use crate::cache::MessagesCache;
+use crate::message;
+use crate::message::Message;
+use crate::partitions::partition::{ConsumerOffset, Partition};
+use crate::remote_store::RemoteSegmentStore;
+use crate::segments::block_index::BlockIndex;
+use crate::segments::index::{Index, IndexRange};
+use crate::segments::log_slice::LogSlice;
+use crate::segments::segment::{Segment, UnsavedBatch, MAX_REWRITTEN_BATCH_SIZE_BYTES};
+use crate::segments::storage::find_index_range;
+use crate::segments::time_index::TimeIndex;
+use crate::storage::{
+    PartitionStorage, SegmentStorage, Storage, StreamStorage, SystemStorage, TopicStorage,
+};
+use crate::streams::stream::Stream;
+use crate::topics::consumer_group::ConsumerGroup;
+use crate::topics::topic::Topic;
+use async_trait::async_trait;
+use sdk::compression::CompressionAlgorithm;
+use sdk::error::Error;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, DeduplicationWindow};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, trace};
+
+type TopicKey = (u32, u32);
+type PartitionKey = (u32, u32, u32);
+type SegmentKey = (u32, u32, u32, u64);
+
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    streams: BTreeMap<u32, String>,
+    topics: BTreeMap<TopicKey, TopicInfo>,
+    consumer_groups: BTreeSet<(u32, u32, u32)>,
+    partitions: BTreeSet<PartitionKey>,
+    draining_partitions: BTreeSet<PartitionKey>,
+    segments: BTreeMap<SegmentKey, Vec<Arc<Message>>>,
+    // The consumer offsets and the log start offsets are identified by their paths, the same way as their files.
+    offsets: HashMap<String, u64>,
+}
+
+#[derive(Debug)]
+struct TopicInfo {
+    name: String,
+    message_expiry: Option<u32>,
+    max_topic_size_bytes: Option<u64>,
+    cleanup_policy: CleanupPolicy,
+    compression: CompressionAlgorithm,
+    deduplication: Option<DeduplicationWindow>,
+    checksum_policy: ChecksumPolicy,
+}
+
+impl From<&Topic> for TopicInfo {
+    fn from(topic: &Topic) -> Self {
+        TopicInfo {
+            name: topic.name.clone(),
+            message_expiry: topic.message_expiry,
+            max_topic_size_bytes: topic.max_topic_size_bytes,
+            cleanup_policy: topic.cleanup_policy,
+            compression: topic.compression,
+            deduplication: topic.deduplication,
+            checksum_policy: topic.checksum_policy,
+        }
+    }
+}
+
+impl MemoryStore {
+    fn delete_partitions(&mut self, start: PartitionKey, end: PartitionKey) {
+        self.partitions.retain(|key| !(start..=end).contains(key));
+        self.draining_partitions
+            .retain(|key| !(start..=end).contains(key));
+        self.segments
+            .retain(|(stream_id, topic_id, partition_id, _), _| {
+                !(start..=end).contains(&(*stream_id, *topic_id, *partition_id))
+            });
+    }
+
+    fn delete_offsets(&mut self, path: &str) {
+        let prefix = format!("{}/", path);
+        self.offsets
+            .retain(|offset_path, _| !offset_path.starts_with(&prefix));
+    }
+}
+
+impl SystemStorage {
+    pub fn memory() -> Self {
+        let store = Arc::new(RwLock::new(MemoryStore::default()));
+        Self {
+            stream: Arc::new(MemoryStreamStorage::new(store.clone())),
+            topic: Arc::new(MemoryTopicStorage::new(store.clone())),
+            partition: Arc::new(MemoryPartitionStorage::new(store.clone())),
+            segment: Arc::new(MemorySegmentStorage::new(store)),
+            remote: None,
+            cache: Arc::new(MessagesCache::default()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MemoryStreamStorage {
+    store: Arc<RwLock<MemoryStore>>,
+}
+
+#[derive(Debug)]
+pub struct MemoryTopicStorage {
+    store: Arc<RwLock<MemoryStore>>,
+}
+
+#[derive(Debug)]
+pub struct MemoryPartitionStorage {
+    store: Arc<RwLock<MemoryStore>>,
+}
+
+#[derive(Debug)]
+pub struct MemorySegmentStorage {
+    store: Arc<RwLock<MemoryStore>>,
+}
+
+impl MemoryStreamStorage {
+    pub fn new(store: Arc<RwLock<MemoryStore>>) -> Self {
+        Self { store }
+    }
+}
+
+impl MemoryTopicStorage {
+    pub fn new(store: Arc<RwLock<MemoryStore>>) -> Self {
+        Self { store }
+    }
+}
+
+impl MemoryPartitionStorage {
+    pub fn new(store: Arc<RwLock<MemoryStore>>) -> Self {
+        Self { store }
+    }
+}
+
+impl MemorySegmentStorage {
+    pub fn new(store: Arc<RwLock<MemoryStore>>) -> Self {
+        Self { store }
+    }
+
+    async fn get_messages(&self, segment: &Segment) -> Result<Vec<Arc<Message>>, Error> {
+        let store = self.store.read().await;
+        match store.segments.get(&get_segment_key(segment)) {
+            Some(messages) => Ok(messages.clone()),
+            None => Err(Error::SegmentNotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl StreamStorage for MemoryStreamStorage {
+    async fn save_info(&self, stream: &Stream) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        match store.streams.get_mut(&stream.id) {
+            Some(name) => {
+                *name = stream.name.clone();
+                Ok(())
+            }
+            None => Err(Error::StreamNotFound(stream.id)),
+        }
+    }
+}
+
+#[async_trait]
+impl Storage<Stream> for MemoryStreamStorage {
+    async fn load(&self, stream: &mut Stream) -> Result<(), Error> {
+        info!("Loading stream with ID: {} from memory...", stream.id);
+        let topic_ids;
+        {
+            let store = self.store.read().await;
+            let name = store.streams.get(&stream.id);
+            if name.is_none() {
+                return Err(Error::StreamNotFound(stream.id));
+            }
+
+            stream.name = name.unwrap().clone();
+            topic_ids = store
+                .topics
+                .range((stream.id, 0)..=(stream.id, u32::MAX))
+                .map(|((_, topic_id), _)| *topic_id)
+                .collect::<Vec<u32>>();
+        }
+
+        for topic_id in topic_ids {
+            let mut topic = Topic::empty(
+                stream.id,
+                topic_id,
+                &stream.topics_path,
+                stream.config.topic.clone(),
+                stream.storage.clone(),
+            );
+            topic.load().await?;
+            stream.topics.insert(topic.id, topic);
+        }
+
+        info!(
+            "Loaded stream: '{}' with ID: {} from memory.",
+            &stream.name, &stream.id
+        );
+        Ok(())
+    }
+
+    async fn save(&self, stream: &Stream) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        if store.streams.contains_key(&stream.id) {
+            return Err(Error::StreamAlreadyExists(stream.id));
+        }
+
+        store.streams.insert(stream.id, stream.name.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, stream: &Stream) -> Result<(), Error> {
+        info!("Deleting stream with ID: {} from memory...", stream.id);
+        let mut store = self.store.write().await;
+        if store.streams.remove(&stream.id).is_none() {
+            return Err(Error::StreamNotFound(stream.id));
+        }
+
+        store
+            .topics
+            .retain(|(stream_id, _), _| *stream_id != stream.id);
+        store
+            .consumer_groups
+            .retain(|(stream_id, _, _)| *stream_id != stream.id);
+        store.delete_partitions((stream.id, 0, 0), (stream.id, u32::MAX, u32::MAX));
+        store.delete_offsets(&stream.path);
+        info!("Deleted stream with ID: {} from memory.", stream.id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TopicStorage for MemoryTopicStorage {
+    async fn save_info(&self, topic: &Topic) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        if !store.topics.contains_key(&(topic.stream_id, topic.id)) {
+            return Err(Error::TopicNotFound(topic.id, topic.stream_id));
+        }
+
+        store
+            .topics
+            .insert((topic.stream_id, topic.id), TopicInfo::from(topic));
+        Ok(())
+    }
+
+    async fn save_consumer_group(
+        &self,
+        topic: &Topic,
+        consumer_group: &ConsumerGroup,
+    ) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store
+            .consumer_groups
+            .insert((topic.stream_id, topic.id, consumer_group.id));
+        Ok(())
+    }
+
+    async fn load_consumer_groups(&self, topic: &mut Topic) -> Result<(), Error> {
+        let store = self.store.read().await;
+        let consumer_group_ids = store
+            .consumer_groups
+            .range((topic.stream_id, topic.id, 0)..=(topic.stream_id, topic.id, u32::MAX))
+            .map(|(_, _, consumer_group_id)| *consumer_group_id);
+        for consumer_group_id in consumer_group_ids {
+            topic.consumer_groups.insert(
+                consumer_group_id,
+                RwLock::new(ConsumerGroup::new(
+                    topic.id,
+                    consumer_group_id,
+                    topic.partitions.len() as u32,
+                )),
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn delete_consumer_group(
+        &self,
+        topic: &Topic,
+        consumer_group: &ConsumerGroup,
+    ) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store
+            .consumer_groups
+            .remove(&(topic.stream_id, topic.id, consumer_group.id));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Topic> for MemoryTopicStorage {
+    async fn load(&self, topic: &mut Topic) -> Result<(), Error> {
+        info!(
+            "Loading topic with ID: {} for stream with ID: {} from memory...",
+            topic.id, topic.stream_id
+        );
+        let partition_ids;
+        {
+            let store = self.store.read().await;
+            let topic_info = store.topics.get(&(topic.stream_id, topic.id));
+            if topic_info.is_none() {
+                return Err(Error::TopicNotFound(topic.id, topic.stream_id));
+            }
+
+            let topic_info = topic_info.unwrap();
+            topic.name = topic_info.name.clone();
+            topic.message_expiry = topic_info.message_expiry;
+            topic.max_topic_size_bytes = topic_info.max_topic_size_bytes;
+            topic.cleanup_policy = topic_info.cleanup_policy;
+            topic.compression = topic_info.compression;
+            topic.deduplication = topic_info.deduplication;
+            topic.checksum_policy = topic_info.checksum_policy;
+            partition_ids = store
+                .partitions
+                .range((topic.stream_id, topic.id, 0)..=(topic.stream_id, topic.id, u32::MAX))
+                .map(|(_, _, partition_id)| *partition_id)
+                .collect::<Vec<u32>>();
+        }
+
+        for partition_id in partition_ids {
+            let mut partition = topic.create_partition(partition_id, false);
+            partition.load().await?;
+            topic
+                .partitions
+                .insert(partition.id, RwLock::new(partition));
+        }
+
+        self.load_consumer_groups(topic).await?;
+        topic.load_messages_to_cache().await?;
+
+        info!(
+            "Loaded topic: '{}' with ID: {} for stream with ID: {} from memory.",
+            &topic.name, &topic.id, topic.stream_id
+        );
+        Ok(())
+    }
+
+    async fn save(&self, topic: &Topic) -> Result<(), Error> {
+        {
+            let mut store = self.store.write().await;
+            if store.topics.contains_key(&(topic.stream_id, topic.id)) {
+                return Err(Error::TopicAlreadyExists(topic.id, topic.stream_id));
+            }
+
+            store
+                .topics
+                .insert((topic.stream_id, topic.id), TopicInfo::from(topic));
+        }
+
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            partition.persist().await?;
+        }
+
+        info!(
+            "Saved topic with ID: {} for stream with ID: {} in memory.",
+            topic.id, topic.stream_id
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, topic: &Topic) -> Result<(), Error> {
+        info!(
+            "Deleting topic with ID: {} for stream with ID: {} from memory...",
+            topic.id, topic.stream_id
+        );
+        let mut store = self.store.write().await;
+        if store.topics.remove(&(topic.stream_id, topic.id)).is_none() {
+            return Err(Error::TopicNotFound(topic.id, topic.stream_id));
+        }
+
+        store.consumer_groups.retain(|(stream_id, topic_id, _)| {
+            (*stream_id, *topic_id) != (topic.stream_id, topic.id)
+        });
+        store.delete_partitions(
+            (topic.stream_id, topic.id, 0),
+            (topic.stream_id, topic.id, u32::MAX),
+        );
+        store.delete_offsets(&topic.path);
+        info!(
+            "Deleted topic with ID: {} for stream with ID: {} from memory.",
+            topic.id, topic.stream_id
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PartitionStorage for MemoryPartitionStorage {
+    async fn save_offset(&self, offset: &ConsumerOffset) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store.offsets.insert(offset.path.clone(), offset.offset);
+        trace!(
+            "Stored offset: {} for {} in memory.",
+            offset.offset,
+            offset.consumer_id,
+        );
+        Ok(())
+    }
+
+    async fn delete_offset(&self, offset: &ConsumerOffset) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store.offsets.remove(&offset.path);
+        trace!("Deleted offset for {} from memory.", offset.consumer_id);
+        Ok(())
+    }
+
+    async fn save_log_start_offset(&self, partition: &Partition) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store.offsets.insert(
+            partition.log_start_offset_path.clone(),
+            partition.log_start_offset,
+        );
+        trace!(
+            "Stored log start offset: {} for partition with ID: {} in memory.",
+            partition.log_start_offset,
+            partition.id
+        );
+        Ok(())
+    }
+
+    async fn save_draining(&self, partition: &Partition) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store
+            .draining_partitions
+            .insert((partition.stream_id, partition.topic_id, partition.id));
+        trace!(
+            "Marked partition with ID: {} as draining in memory.",
+            partition.id
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Partition> for MemoryPartitionStorage {
+    async fn load(&self, partition: &mut Partition) -> Result<(), Error> {
+        info!(
+            "Loading partition with ID: {} for stream with ID: {} and topic with ID: {} from memory...",
+            partition.id, partition.stream_id, partition.topic_id
+        );
+        let start_offsets;
+        {
+            let store = self.store.read().await;
+            let key = (partition.stream_id, partition.topic_id, partition.id);
+            if !store.partitions.contains(&key) {
+                return Err(Error::PartitionNotFound(partition.id));
+            }
+
+            start_offsets = store
+                .segments
+                .range((key.0, key.1, key.2, 0)..=(key.0, key.1, key.2, u64::MAX))
+                .map(|((_, _, _, start_offset), _)| *start_offset)
+                .collect::<Vec<u64>>();
+            if let Some(log_start_offset) = store.offsets.get(&partition.log_start_offset_path) {
+                partition.log_start_offset = *log_start_offset;
+            }
+            partition.is_draining = store.draining_partitions.contains(&key);
+            for (path, offsets) in [
+                (
+                    &partition.consumer_offsets_path,
+                    &partition.consumer_offsets,
+                ),
+                (
+                    &partition.consumer_group_offsets_path,
+                    &partition.consumer_group_offsets,
+                ),
+            ] {
+                let mut offsets = offsets.write().await;
+                let prefix = format!("{}/", path);
+                for (offset_path, offset) in store.offsets.iter() {
+                    let consumer_id = offset_path
+                        .strip_prefix(&prefix)
+                        .map(|id| id.parse::<u32>());
+                    if let Some(Ok(consumer_id)) = consumer_id {
+                        offsets.offsets.insert(
+                            consumer_id,
+                            RwLock::new(ConsumerOffset {
+                                consumer_id,
+                                offset: *offset,
+                                path: offset_path.clone(),
+                            }),
+                        );
+                    }
+                }
+            }
+        }
+
+        let segments_count = start_offsets.len();
+        for (index, start_offset) in start_offsets.into_iter().enumerate() {
+            let mut segment = Segment::create(
+                partition.stream_id,
+                partition.topic_id,
+                partition.id,
+                start_offset,
+                &partition.path,
+                partition.config.segment.clone(),
+                partition.storage.clone(),
+            );
+            segment.load().await?;
+            if !partition.should_increment_offset {
+                partition.should_increment_offset = segment.current_size_bytes > 0;
+            }
+
+            // Only the last segment can be still open, the previous ones end right before the next one starts.
+            if index < segments_count - 1 {
+                segment.is_closed = true;
+            } else if segment.is_closed {
+                segment.end_offset = segment.current_offset;
+            } else {
+                segment.unsaved_batches = Some(Vec::new());
+            }
+
+            if let Some(previous_segment) = partition.segments.last_mut() {
+                previous_segment.end_offset = segment.start_offset - 1;
+            }
+
+            partition.segments.push(segment);
+        }
+
+        if let Some(last_segment) = partition.segments.last() {
+            partition.current_offset = last_segment.current_offset;
+        }
+
+        partition.load_message_ids().await?;
+
+        info!(
+            "Loaded partition with ID: {} for stream with ID: {} and topic with ID: {} from memory, current offset: {}.",
+            partition.id, partition.stream_id, partition.topic_id, partition.current_offset
+        );
+        Ok(())
+    }
+
+    async fn save(&self, partition: &Partition) -> Result<(), Error> {
+        {
+            let mut store = self.store.write().await;
+            if !store
+                .partitions
+                .insert((partition.stream_id, partition.topic_id, partition.id))
+            {
+                return Err(Error::CannotCreatePartition(
+                    partition.id,
+                    partition.stream_id,
+                    partition.topic_id,
+                ));
+            }
+        }
+
+        for segment in partition.get_segments() {
+            segment.persist().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, partition: &Partition) -> Result<(), Error> {
+        info!(
+            "Deleting partition with ID: {} for stream with ID: {} and topic with ID: {} from memory...",
+            partition.id, partition.stream_id, partition.topic_id,
+        );
+        let key = (partition.stream_id, partition.topic_id, partition.id);
+        let mut store = self.store.write().await;
+        store.delete_partitions(key, key);
+        store.delete_offsets(&partition.path);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Segment> for MemorySegmentStorage {
+    async fn load(&self, segment: &mut Segment) -> Result<(), Error> {
+        let messages = self.get_messages(segment).await?;
+        segment.current_size_bytes = get_size_bytes(&messages);
+        if let (Some(first_message), Some(last_message)) = (messages.first(), messages.last()) {
+            segment.current_offset = last_message.offset;
+            segment.min_timestamp = first_message.timestamp;
+            segment.max_timestamp = last_message.timestamp;
+        }
+
+        if segment.config.cache_indexes {
+            segment.indexes = Some(get_indexes(segment, &messages));
+        }
+
+        if segment.config.cache_time_indexes {
+            segment.time_indexes = Some(get_time_indexes(segment, &messages));
+        }
+
+        if segment.is_full() {
+            segment.is_closed = true;
+        }
+
+        trace!(
+            "Loaded segment with start offset: {} for partition with ID: {} from memory, {} messages.",
+            segment.start_offset,
+            segment.partition_id,
+            messages.len()
+        );
+        Ok(())
+    }
+
+    async fn save(&self, segment: &Segment) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store.segments.entry(get_segment_key(segment)).or_default();
+        Ok(())
+    }
+
+    async fn delete(&self, segment: &Segment) -> Result<(), Error> {
+        let mut store = self.store.write().await;
+        store.segments.remove(&get_segment_key(segment));
+        info!(
+            "Deleted segment with start offset: {} for partition with ID: {} from memory.",
+            segment.start_offset, segment.partition_id
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SegmentStorage for MemorySegmentStorage {
+    async fn load_messages(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+    ) -> Result<Vec<Arc<Message>>, Error> {
+        let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
+        let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
+        let store = self.store.read().await;
+        let messages = match store.segments.get(&get_segment_key(segment)) {
+            Some(messages) => messages,
+            None => return Err(Error::SegmentNotFound),
+        };
+
+        let start_index = messages.partition_point(|message| message.offset < start_offset);
+        let end_index = messages.partition_point(|message| message.offset <= end_offset);
+        let messages = messages[start_index..end_index.max(start_index)].to_vec();
+        trace!("Loaded {} messages from memory.", messages.len());
+        Ok(messages)
+    }
+
+    // The messages are already kept in memory, so there's no log to send them from.
+    async fn load_log_slice(
+        &self,
+        _segment: &Segment,
+        _index_range: &IndexRange,
+    ) -> Result<Option<LogSlice>, Error> {
+        Ok(None)
+    }
+
+    async fn save_batches(
+        &self,
+        segment: &Segment,
+        batches: &[UnsavedBatch],
+    ) -> Result<u64, Error> {
+        let mut store = self.store.write().await;
+        store
+            .segments
+            .entry(get_segment_key(segment))
+            .or_default()
+            .extend(
+                batches
+                    .iter()
+                    .flat_map(|batch| batch.messages.iter())
+                    .cloned(),
+            );
+        Ok(batches
+            .iter()
+            .map(|batch| batch.get_size_bytes() as u64)
+            .sum())
+    }
+
+    async fn overwrite_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u64, Error> {
+        let mut store = self.store.write().await;
+        store
+            .segments
+            .insert(get_segment_key(segment), messages.to_vec());
+        Ok(get_size_bytes(messages))
+    }
+
+    // The messages are already kept in memory, so compressing them wouldn't save anything.
+    async fn compress_messages(
+        &self,
+        _segment: &Segment,
+        _compression: CompressionAlgorithm,
+    ) -> Result<Option<BlockIndex>, Error> {
+        Ok(None)
+    }
+
+    async fn recover(&self, _segment: &Segment) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn rebuild_indexes(&self, _segment: &Segment) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn archive(
+        &self,
+        segment: &Segment,
+        _remote: &dyn RemoteSegmentStore,
+    ) -> Result<(), Error> {
+        Err(Error::CannotArchiveSegment(
+            segment.start_offset,
+            segment.partition_id,
+        ))
+    }
+
+    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<(u128, u64)>, Error> {
+        let messages = self.get_messages(segment).await?;
+        Ok(messages
+            .iter()
+            .map(|message| (message.id, message.timestamp))
+            .collect())
+    }
+
+    async fn load_message_offsets(&self, segment: &Segment) -> Result<Vec<(u128, u64)>, Error> {
+        let messages = self.get_messages(segment).await?;
+        Ok(messages
+            .iter()
+            .map(|message| (message.id, message.offset))
+            .collect())
+    }
+
+    // The messages are kept as they were appended, without being encoded into the batches.
+    async fn load_checksums(&self, _segment: &Segment) -> Result<(), Error> {
+        Ok(())
+    }
+
+    // The indexes aren't stored, as they're derived from the messages whenever they're needed.
+    async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error> {
+        let messages = self.get_messages(segment).await?;
+        Ok(get_indexes(segment, &messages))
+    }
+
+    async fn load_index_range(
+        &self,
+        segment: &Segment,
+        segment_start_offset: u64,
+        index_start_offset: u64,
+        index_end_offset: u64,
+    ) -> Result<Option<IndexRange>, Error> {
+        if index_start_offset > index_end_offset || index_end_offset < segment_start_offset {
+            return Ok(None);
+        }
+
+        let indexes = self.load_all_indexes(segment).await?;
+        Ok(find_index_range(
+            &indexes,
+            index_start_offset.saturating_sub(segment_start_offset) as u32,
+            (index_end_offset - segment_start_offset) as u32,
+            segment.current_size_bytes,
+        ))
+    }
+
+    async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, Error> {
+        let messages = self.get_messages(segment).await?;
+        Ok(get_time_indexes(segment, &messages))
+    }
+
+    async fn load_last_time_index(&self, segment: &Segment) -> Result<Option<TimeIndex>, Error> {
+        let messages = self.get_messages(segment).await?;
+        Ok(messages
+            .last()
+            .map(|message| get_time_index(segment, message)))
+    }
+
+    async fn load_time_index_by_timestamp(
+        &self,
+        segment: &Segment,
+        timestamp: u64,
+    ) -> Result<Option<TimeIndex>, Error> {
+        let messages = self.get_messages(segment).await?;
+        let position = messages.partition_point(|message| message.timestamp < timestamp);
+        Ok(messages
+            .get(position)
+            .map(|message| get_time_index(segment, message)))
+    }
+
+    async fn sync(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn close(&self, _segment: &Segment) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+fn get_segment_key(segment: &Segment) -> SegmentKey {
+    (
+        segment.stream_id,
+        segment.topic_id,
+        segment.partition_id,
+        segment.start_offset,
+    )
+}
+
+// The boundaries of the appended batches aren't kept, so the messages are split as if the segment was rewritten.
+fn get_size_bytes(messages: &[Arc<Message>]) -> u64 {
+    message::split_batches(messages, MAX_REWRITTEN_BATCH_SIZE_BYTES)
+        .iter()
+        .map(|batch| message::get_batch_size_bytes(batch) as u64)
+        .sum()
+}
+
+fn get_indexes(segment: &Segment, messages: &[Arc<Message>]) -> Vec<Index> {
+    let mut indexes = Vec::new();
+    let mut position = 0;
+    for batch in message::split_batches(messages, MAX_REWRITTEN_BATCH_SIZE_BYTES) {
+        let size_bytes = message::get_batch_size_bytes(batch);
+        if segment.should_index(position, size_bytes) {
+            indexes.push(Index {
+                relative_offset: (batch[0].offset - segment.start_offset) as u32,
+                position,
+            });
+        }
+        position += size_bytes as u64;
+    }
+
+    indexes
+}
+
+fn get_time_indexes(segment: &Segment, messages: &[Arc<Message>]) -> Vec<TimeIndex> {
+    messages
+        .iter()
+        .map(|message| get_time_index(segment, message))
+        .collect()
+}
+
+fn get_time_index(segment: &Segment, message: &Message) -> TimeIndex {
+    TimeIndex {
+        relative_offset: (message.offset - segment.start_offset) as u32,
+        timestamp: message.timestamp,
+    }
+}
@@ -1,7 +1,9 @@
 use bytes::Bytes;
+use sdk::batch::{MessageBatch, Record, BATCH_HEADER_SIZE, RECORD_HEADER_SIZE};
 use serde::{Deserialize, Serialize};
 use serde_with::base64::Base64;
 use serde_with::serde_as;
+use std::sync::Arc;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,47 +12,167 @@ pub struct Message {
     pub timestamp: u64,
     pub id: u128,
     #[serde(skip)]
-    pub checksum: u32,
-    #[serde(skip)]
     pub length: u32,
     #[serde_as(as = "Base64")]
     pub payload: Bytes,
 }
 
 impl Message {
-    pub fn empty(timestamp: u64, id: u128, payload: Bytes, checksum: u32) -> Self {
-        Message::create(0, timestamp, id, payload, checksum)
+    pub fn empty(timestamp: u64, id: u128, payload: Bytes) -> Self {
+        Message::create(0, timestamp, id, payload)
     }
 
-    pub fn create(offset: u64, timestamp: u64, id: u128, payload: Bytes, checksum: u32) -> Self {
+    pub fn create(offset: u64, timestamp: u64, id: u128, payload: Bytes) -> Self {
         Message {
             offset,
             timestamp,
             id,
-            checksum,
             length: payload.len() as u32,
             payload,
         }
     }
 
-    pub fn get_size_bytes(&self, with_checksum: bool) -> u32 {
-        // Offset + Timestamp + ID + Length + Payload
-        let size = 8 + 8 + 16 + 4 + self.length;
-        if with_checksum {
-            size + 4
-        } else {
-            size
+    pub fn get_size_bytes(&self) -> u32 {
+        RECORD_HEADER_SIZE + self.length
+    }
+}
+
+pub fn split_batches(messages: &[Arc<Message>], max_size_bytes: u64) -> Vec<&[Arc<Message>]> {
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut size_bytes = BATCH_HEADER_SIZE as u64;
+    for (index, message) in messages.iter().enumerate() {
+        let first_message = &messages[start];
+        if index > start
+            && (size_bytes + message.get_size_bytes() as u64 > max_size_bytes
+                || message.offset - first_message.offset > u32::MAX as u64
+                || message.timestamp < first_message.timestamp
+                || message.timestamp - first_message.timestamp > u32::MAX as u64)
+        {
+            batches.push(&messages[start..index]);
+            start = index;
+            size_bytes = BATCH_HEADER_SIZE as u64;
+        }
+        size_bytes += message.get_size_bytes() as u64;
+    }
+
+    if start < messages.len() {
+        batches.push(&messages[start..]);
+    }
+
+    batches
+}
+
+pub fn get_batch_size_bytes(messages: &[Arc<Message>]) -> u32 {
+    BATCH_HEADER_SIZE
+        + messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<u32>()
+}
+
+pub fn create_batch(messages: &[Arc<Message>]) -> MessageBatch {
+    let base_offset = messages.first().map_or(0, |message| message.offset);
+    let first_timestamp = messages.first().map_or(0, |message| message.timestamp);
+    let max_timestamp = messages
+        .iter()
+        .map(|message| message.timestamp)
+        .max()
+        .unwrap_or(0);
+    let records = messages
+        .iter()
+        .map(|message| Record {
+            offset_delta: (message.offset - base_offset) as u32,
+            timestamp_delta: (message.timestamp - first_timestamp) as u32,
+            id: message.id,
+            payload: message.payload.clone(),
+        })
+        .collect();
+    MessageBatch::new(base_offset, first_timestamp, max_timestamp, 0, records)
+}
+
+pub fn get_batch_messages(batch: MessageBatch) -> Vec<Message> {
+    let header = batch.header;
+    batch
+        .records
+        .into_iter()
+        .map(|record| {
+            Message::create(
+                header.base_offset + record.offset_delta as u64,
+                header.first_timestamp + record.timestamp_delta as u64,
+                record.id,
+                record.payload,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_split_the_batches_exceeding_the_size_or_the_timestamp_delta() {
+        let timestamps = [10, 11, 11, 5, u32::MAX as u64 + 6];
+        let messages = timestamps
+            .iter()
+            .enumerate()
+            .map(|(offset, timestamp)| {
+                Arc::new(Message::create(
+                    offset as u64,
+                    *timestamp,
+                    offset as u128,
+                    Bytes::from("message"),
+                ))
+            })
+            .collect::<Vec<_>>();
+        let max_size_bytes = (BATCH_HEADER_SIZE + 2 * messages[0].get_size_bytes()) as u64;
+
+        let batches = split_batches(&messages, max_size_bytes);
+
+        let batches_offsets = batches
+            .iter()
+            .map(|batch| {
+                batch
+                    .iter()
+                    .map(|message| message.offset)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(batches_offsets, vec![vec![0, 1], vec![2], vec![3], vec![4]]);
+        for batch in batches {
+            assert_eq!(
+                create_batch(batch).get_size_bytes(),
+                get_batch_size_bytes(batch)
+            );
         }
     }
 
-    pub fn extend(&self, bytes: &mut Vec<u8>, with_checksum: bool) {
-        bytes.extend(self.offset.to_le_bytes());
-        bytes.extend(self.timestamp.to_le_bytes());
-        bytes.extend(self.id.to_le_bytes());
-        if with_checksum {
-            bytes.extend(self.checksum.to_le_bytes());
+    #[test]
+    fn should_read_the_same_messages_from_the_created_batch() {
+        let messages = (0..3)
+            .map(|index| {
+                Arc::new(Message::create(
+                    100 + index * 2,
+                    1000 + index,
+                    index as u128,
+                    Bytes::from(format!("message {}", index)),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let batch = create_batch(&messages);
+        assert_eq!(batch.header.base_offset, 100);
+        assert_eq!(batch.header.get_last_offset(), 104);
+        assert_eq!(batch.header.max_timestamp, 1002);
+
+        let batch_messages = get_batch_messages(batch);
+        assert_eq!(batch_messages.len(), messages.len());
+        for (batch_message, message) in batch_messages.iter().zip(messages.iter()) {
+            assert_eq!(batch_message.offset, message.offset);
+            assert_eq!(batch_message.timestamp, message.timestamp);
+            assert_eq!(batch_message.id, message.id);
+            assert_eq!(batch_message.payload, message.payload);
         }
-        bytes.extend(self.length.to_le_bytes());
-        bytes.extend(&self.payload);
     }
 }
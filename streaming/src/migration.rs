@@ -0,0 +1,191 @@
+use crate::message;
+use crate::message::Message;
+use crate::segments::segment::{
+    INDEX_EXTENSION, LOG_EXTENSION, MAX_REWRITTEN_BATCH_SIZE_BYTES, TIME_INDEX_EXTENSION,
+};
+use crate::topics::topic::TOPIC_INFO;
+use bytes::Bytes;
+use sdk::error::Error;
+use std::collections::BTreeSet;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+use tracing::{error, info};
+
+pub const STORAGE_VERSION: u32 = 2;
+const VERSION_FILE: &str = "version";
+// The data directories created before the storage version was introduced. The topic info contains only the name,
+// each message is stored with its own header, the index contains the position (u32) of each message
+// and the time index contains the timestamp of each message.
+const INITIAL_STORAGE_VERSION: u32 = 1;
+// Initial message header: Offset + Timestamp + ID + Checksum + Length
+const INITIAL_MESSAGE_HEADER_SIZE: usize = 40;
+const TEMP_LOG_EXTENSION: &str = "log.tmp";
+// Topic info header: Message expiry + Max topic size + Cleanup policy + Compression + Deduplication window
+// + Checksum policy + Config overrides, followed by the name
+const TOPIC_INFO_HEADER_SIZE: usize = 14 + 8 + 1 + 13;
+
+pub async fn migrate(base_path: &str, streams_path: &str) -> Result<(), Error> {
+    let version_path = format!("{}/{}", base_path, VERSION_FILE);
+    let version = match fs::read(&version_path).await {
+        Ok(bytes) => match bytes.try_into() {
+            Ok(bytes) => u32::from_le_bytes(bytes),
+            Err(_) => return Err(Error::InvalidStorageVersion(0, STORAGE_VERSION)),
+        },
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            if has_streams(streams_path).await? {
+                INITIAL_STORAGE_VERSION
+            } else {
+                STORAGE_VERSION
+            }
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    if !(INITIAL_STORAGE_VERSION..=STORAGE_VERSION).contains(&version) {
+        return Err(Error::InvalidStorageVersion(version, STORAGE_VERSION));
+    }
+
+    if version < STORAGE_VERSION {
+        info!(
+            "Migrating storage from version: {} to version: {}...",
+            version, STORAGE_VERSION
+        );
+        if let Err(error) = migrate_initial_storage(Path::new(streams_path)).await {
+            error!("Cannot migrate storage: {}", error);
+            return Err(Error::CannotMigrateStorage(version, STORAGE_VERSION));
+        }
+        info!("Migrated storage to version: {}.", STORAGE_VERSION);
+    }
+
+    // The version file is saved only once the migration completes, so the interrupted one is resumed on the next start.
+    fs::write(&version_path, STORAGE_VERSION.to_le_bytes()).await?;
+    Ok(())
+}
+
+async fn has_streams(streams_path: &str) -> Result<bool, Error> {
+    let mut dir_entries = fs::read_dir(streams_path).await?;
+    Ok(dir_entries.next_entry().await?.is_some())
+}
+
+// The messages are stored in the batches with a single checksum instead of one per message, and the logs are converted,
+// while the indexes and the time indexes are deleted, as they're rebuilt from the log when the segment is loaded.
+// The topic info containing only the name gets the header, in which all the fields are disabled (zeros).
+async fn migrate_initial_storage(streams_path: &Path) -> Result<(), Error> {
+    // The time index is deleted once the converted log is saved, and the converted log replaces the old one afterwards,
+    // thus the remaining time indexes and converted logs point to the segments, which are yet to be migrated.
+    let mut segment_paths = BTreeSet::new();
+    let mut topic_info_paths = Vec::new();
+    let mut directories = vec![streams_path.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let mut dir_entries = fs::read_dir(&directory).await?;
+        while let Some(dir_entry) = dir_entries.next_entry().await? {
+            let path = dir_entry.path();
+            if dir_entry.metadata().await?.is_dir() {
+                directories.push(path);
+                continue;
+            }
+
+            if path.file_name().and_then(|file_name| file_name.to_str()) == Some(TOPIC_INFO) {
+                topic_info_paths.push(path);
+                continue;
+            }
+
+            let file_name = path.to_string_lossy();
+            if let Some(segment_path) = file_name
+                .strip_suffix(&format!(".{}", TIME_INDEX_EXTENSION))
+                .or_else(|| file_name.strip_suffix(&format!(".{}", TEMP_LOG_EXTENSION)))
+            {
+                segment_paths.insert(PathBuf::from(segment_path));
+            }
+        }
+    }
+
+    for segment_path in segment_paths {
+        let time_index_path = segment_path.with_extension(TIME_INDEX_EXTENSION);
+        if time_index_path.exists() {
+            convert_initial_log(&segment_path).await?;
+            fs::remove_file(&time_index_path).await?;
+        }
+        replace_initial_log(&segment_path).await?;
+    }
+
+    for topic_info_path in topic_info_paths {
+        convert_initial_topic_info(&topic_info_path).await?;
+    }
+
+    Ok(())
+}
+
+async fn convert_initial_log(segment_path: &Path) -> Result<(), Error> {
+    let bytes = fs::read(segment_path.with_extension(LOG_EXTENSION)).await?;
+    // The incomplete message at the end of the log is dropped, as it would be truncated by the recovery anyway.
+    let mut messages = Vec::new();
+    let mut position = 0;
+    while position + INITIAL_MESSAGE_HEADER_SIZE <= bytes.len() {
+        let offset = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let timestamp = u64::from_le_bytes(bytes[position + 8..position + 16].try_into()?);
+        let id = u128::from_le_bytes(bytes[position + 16..position + 32].try_into()?);
+        let length = u32::from_le_bytes(bytes[position + 36..position + 40].try_into()?) as usize;
+        let payload_position = position + INITIAL_MESSAGE_HEADER_SIZE;
+        if payload_position + length > bytes.len() {
+            break;
+        }
+
+        let payload = Bytes::copy_from_slice(&bytes[payload_position..payload_position + length]);
+        messages.push(Arc::new(Message::create(offset, timestamp, id, payload)));
+        position = payload_position + length;
+    }
+
+    let mut converted_bytes = Vec::with_capacity(bytes.len());
+    for batch in message::split_batches(&messages, MAX_REWRITTEN_BATCH_SIZE_BYTES) {
+        message::create_batch(batch).extend(&mut converted_bytes);
+    }
+
+    fs::write(
+        segment_path.with_extension(TEMP_LOG_EXTENSION),
+        converted_bytes,
+    )
+    .await?;
+    Ok(())
+}
+
+async fn replace_initial_log(segment_path: &Path) -> Result<(), Error> {
+    let temp_log_path = segment_path.with_extension(TEMP_LOG_EXTENSION);
+    if !temp_log_path.exists() {
+        return Ok(());
+    }
+
+    let index_path = segment_path.with_extension(INDEX_EXTENSION);
+    if index_path.exists() {
+        fs::remove_file(&index_path).await?;
+    }
+    fs::rename(&temp_log_path, segment_path.with_extension(LOG_EXTENSION)).await?;
+    Ok(())
+}
+
+// The converted topic info replaces the old one at once, and the one with the zeroed header already in place
+// is skipped, so it's safe to run the migration again.
+async fn convert_initial_topic_info(path: &Path) -> Result<(), Error> {
+    let bytes = fs::read(path).await?;
+    if bytes.is_empty() {
+        return Err(Error::InvalidFormat);
+    }
+
+    if bytes.len() > TOPIC_INFO_HEADER_SIZE
+        && bytes[..TOPIC_INFO_HEADER_SIZE]
+            .iter()
+            .all(|byte| *byte == 0)
+    {
+        return Ok(());
+    }
+
+    let mut converted_bytes = Vec::with_capacity(TOPIC_INFO_HEADER_SIZE + bytes.len());
+    converted_bytes.extend([0; TOPIC_INFO_HEADER_SIZE]);
+    converted_bytes.extend(bytes);
+    let temp_path = path.with_extension("info.tmp");
+    fs::write(&temp_path, converted_bytes).await?;
+    fs::rename(&temp_path, path).await?;
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use sdk::topics::create_topic::DeduplicationWindow;
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug)]
+pub struct MessageDeduplicator {
+    max_ids: Option<u32>,
+    expiry: Option<u64>,
+    message_ids: HashSet<u128>,
+    // The IDs with the timestamps of their messages, in the order they were appended, so that the oldest ones are evicted first.
+    appended_ids: VecDeque<(u128, u64)>,
+}
+
+impl MessageDeduplicator {
+    pub fn new(window: DeduplicationWindow) -> Self {
+        MessageDeduplicator {
+            max_ids: window.max_ids,
+            expiry: window.expiry.map(|expiry| expiry as u64 * 1_000_000),
+            message_ids: HashSet::new(),
+            appended_ids: VecDeque::new(),
+        }
+    }
+
+    pub fn contains(&self, message_id: u128) -> bool {
+        self.message_ids.contains(&message_id)
+    }
+
+    pub fn get_ids_count(&self) -> usize {
+        self.message_ids.len()
+    }
+
+    pub fn insert(&mut self, message_id: u128, timestamp: u64) {
+        if !self.message_ids.insert(message_id) {
+            return;
+        }
+
+        self.appended_ids.push_back((message_id, timestamp));
+        if let Some(max_ids) = self.max_ids {
+            while self.appended_ids.len() > max_ids as usize {
+                self.evict_oldest();
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.message_ids.clear();
+        self.appended_ids.clear();
+    }
+
+    pub fn evict_expired(&mut self, now: u64) {
+        let expiry = match self.expiry {
+            Some(expiry) => expiry,
+            None => return,
+        };
+
+        while let Some((_, timestamp)) = self.appended_ids.front() {
+            if timestamp + expiry > now {
+                break;
+            }
+
+            self.evict_oldest();
+        }
+    }
+
+    pub fn is_covered_by(&self, ids_count: usize, oldest_timestamp: u64, now: u64) -> bool {
+        if let Some(max_ids) = self.max_ids {
+            if ids_count >= max_ids as usize {
+                return true;
+            }
+        }
+
+        match self.expiry {
+            Some(expiry) => oldest_timestamp + expiry <= now,
+            None => false,
+        }
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some((message_id, _)) = self.appended_ids.pop_front() {
+            self.message_ids.remove(&message_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_evict_the_oldest_ids_given_max_ids() {
+        let mut deduplicator = MessageDeduplicator::new(DeduplicationWindow {
+            max_ids: Some(3),
+            expiry: None,
+        });
+        for message_id in 1..=5 {
+            deduplicator.insert(message_id, 1);
+        }
+
+        assert_eq!(deduplicator.get_ids_count(), 3);
+        assert!(!deduplicator.contains(1));
+        assert!(!deduplicator.contains(2));
+        assert!(deduplicator.contains(3));
+        assert!(deduplicator.contains(5));
+    }
+
+    #[test]
+    fn should_evict_the_expired_ids_given_expiry() {
+        let mut deduplicator = MessageDeduplicator::new(DeduplicationWindow {
+            max_ids: None,
+            expiry: Some(10),
+        });
+        deduplicator.insert(1, 1_000_000);
+        deduplicator.insert(2, 5_000_000);
+        deduplicator.insert(3, 9_000_000);
+
+        deduplicator.evict_expired(12_000_000);
+
+        assert!(!deduplicator.contains(1));
+        assert!(deduplicator.contains(2));
+        assert!(deduplicator.contains(3));
+    }
+
+    #[test]
+    fn should_be_covered_by_the_ids_filling_the_window() {
+        let deduplicator = MessageDeduplicator::new(DeduplicationWindow {
+            max_ids: Some(100),
+            expiry: Some(10),
+        });
+
+        assert!(deduplicator.is_covered_by(100, 20_000_000, 20_000_000));
+        assert!(deduplicator.is_covered_by(10, 10_000_000, 20_000_000));
+        assert!(!deduplicator.is_covered_by(10, 15_000_000, 20_000_000));
+    }
+}
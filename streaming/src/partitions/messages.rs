@@ -1,12 +1,18 @@
 use crate::message::Message;
+use crate::partitions::message_deduplicator::MessageDeduplicator;
 use crate::partitions::partition::Partition;
 use crate::polling_consumer::PollingConsumer;
+use crate::segments::log_slice::LogSlice;
 use crate::segments::segment::Segment;
-use crate::utils::random_id;
-use ringbuffer::{RingBuffer, RingBufferWrite};
+use crate::utils::{random_id, timestamp};
+use sdk::batch::MessageBatch;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
+use sdk::messages::poll_messages::Kind;
+use sdk::topics::create_topic::DeduplicationWindow;
+use std::collections::HashSet;
 use std::sync::Arc;
-use tracing::{error, trace, warn};
+use tracing::{info, trace, warn};
 
 const EMPTY_MESSAGES: Vec<Arc<Message>> = vec![];
 
@@ -21,56 +27,46 @@ impl Partition {
             timestamp,
             self.id
         );
-        if self.segments.is_empty() {
-            return Ok(EMPTY_MESSAGES);
+        match self.get_offset_by_timestamp(timestamp).await? {
+            Some(start_offset) => self.get_messages_by_offset(start_offset, count).await,
+            None => Ok(EMPTY_MESSAGES),
         }
+    }
 
-        let mut maybe_start_offset = None;
-        for segment in self.segments.iter() {
-            if segment.time_indexes.is_none() {
-                continue;
-            }
-
-            let time_indexes = segment.time_indexes.as_ref().unwrap();
-            if time_indexes.is_empty() {
-                continue;
-            }
-
-            let first_timestamp = time_indexes.first().unwrap().timestamp;
-            let last_timestamp = time_indexes.last().unwrap().timestamp;
-            if timestamp < first_timestamp || timestamp > last_timestamp {
-                continue;
-            }
-
-            let relative_start_offset = time_indexes
-                .iter()
-                .find(|time_index| time_index.timestamp >= timestamp)
-                .map(|time_index| time_index.relative_offset)
-                .unwrap_or(0);
-
-            let start_offset = segment.start_offset + relative_start_offset as u64;
-            maybe_start_offset = Some(start_offset);
-            trace!(
-                "Found start offset: {} for timestamp: {}.",
-                start_offset,
-                timestamp
-            );
-
-            break;
+    async fn get_offset_by_timestamp(&self, timestamp: u64) -> Result<Option<u64>, Error> {
+        if self.segments.is_empty() {
+            return Ok(None);
         }
 
+        // The segments are ordered by their timestamps, thus the first one containing the newer message is found by
+        // the binary search, skipping the empty active segment, which has no timestamps yet.
+        let segments = match self.segments.last() {
+            Some(segment) if segment.is_empty() => &self.segments[..self.segments.len() - 1],
+            _ => &self.segments[..],
+        };
+        let position = segments.partition_point(|segment| segment.max_timestamp < timestamp);
+        let maybe_start_offset = match segments.get(position) {
+            Some(segment) => segment.get_offset_by_timestamp(timestamp).await?,
+            None => None,
+        };
+
         if maybe_start_offset.is_none() {
             trace!("Start offset for timestamp: {} was not found.", timestamp);
-            return Ok(EMPTY_MESSAGES);
+            return Ok(None);
         }
 
-        self.get_messages_by_offset(maybe_start_offset.unwrap(), count)
-            .await
+        let start_offset = maybe_start_offset.unwrap();
+        trace!(
+            "Found start offset: {} for timestamp: {}.",
+            start_offset,
+            timestamp
+        );
+        Ok(Some(start_offset))
     }
 
     pub async fn get_messages_by_offset(
         &self,
-        start_offset: u64,
+        mut start_offset: u64,
         count: u32,
     ) -> Result<Vec<Arc<Message>>, Error> {
         trace!(
@@ -82,6 +78,12 @@ impl Partition {
             return Ok(EMPTY_MESSAGES);
         }
 
+        // The oldest messages might have been already deleted by the retention policy, or on demand.
+        let first_offset = self.get_first_offset();
+        if start_offset < first_offset {
+            start_offset = first_offset;
+        }
+
         if start_offset > self.current_offset {
             return Ok(EMPTY_MESSAGES);
         }
@@ -96,22 +98,17 @@ impl Partition {
         match segments.len() {
             0 => Ok(EMPTY_MESSAGES),
             1 => segments[0].get_messages(start_offset, count).await,
-            _ => Self::get_messages_from_segments(segments, start_offset, count).await,
+            _ => Self::get_messages_from_segments(segments, start_offset, end_offset).await,
         }
     }
 
     pub async fn get_first_messages(&self, count: u32) -> Result<Vec<Arc<Message>>, Error> {
-        self.get_messages_by_offset(0, count).await
+        self.get_messages_by_offset(self.get_first_offset(), count)
+            .await
     }
 
     pub async fn get_last_messages(&self, count: u32) -> Result<Vec<Arc<Message>>, Error> {
-        let mut count = count as u64;
-        if count > self.current_offset + 1 {
-            count = self.current_offset + 1
-        }
-
-        let start_offset = 1 + self.current_offset - count;
-        self.get_messages_by_offset(start_offset, count as u32)
+        self.get_messages_by_offset(self.get_last_offset(count), count)
             .await
     }
 
@@ -120,6 +117,114 @@ impl Partition {
         consumer: PollingConsumer,
         count: u32,
     ) -> Result<Vec<Arc<Message>>, Error> {
+        match self.get_next_offset(consumer).await {
+            Some(offset) => self.get_messages_by_offset(offset, count).await,
+            None => Ok(EMPTY_MESSAGES),
+        }
+    }
+
+    pub async fn get_log_slices(
+        &self,
+        consumer: PollingConsumer,
+        kind: Kind,
+        value: u64,
+        count: u32,
+        min_size_bytes: u64,
+    ) -> Result<Option<Vec<LogSlice>>, Error> {
+        if self.checksum_validator.is_enabled() {
+            return Ok(None);
+        }
+
+        let start_offset = match kind {
+            Kind::Offset => Some(value),
+            Kind::Timestamp => self.get_offset_by_timestamp(value).await?,
+            Kind::First => Some(self.get_first_offset()),
+            Kind::Last => Some(self.get_last_offset(count)),
+            Kind::Next => self.get_next_offset(consumer).await,
+        };
+
+        match start_offset {
+            Some(start_offset) => {
+                self.get_log_slices_by_offset(start_offset, count, min_size_bytes)
+                    .await
+            }
+            None => Ok(Some(Vec::new())),
+        }
+    }
+
+    async fn get_log_slices_by_offset(
+        &self,
+        mut start_offset: u64,
+        count: u32,
+        min_size_bytes: u64,
+    ) -> Result<Option<Vec<LogSlice>>, Error> {
+        if self.segments.is_empty() {
+            return Ok(Some(Vec::new()));
+        }
+
+        let first_offset = self.get_first_offset();
+        if start_offset < first_offset {
+            start_offset = first_offset;
+        }
+
+        if start_offset > self.current_offset {
+            return Ok(Some(Vec::new()));
+        }
+
+        let end_offset = self.get_end_offset(start_offset, count);
+        if self.is_cached(start_offset, end_offset) {
+            return Ok(None);
+        }
+
+        let segments = self.filter_segments_by_offsets(start_offset, end_offset);
+        let mut index_ranges = Vec::with_capacity(segments.len());
+        for segment in segments {
+            let (offset, count) = Self::get_segment_range(segment, start_offset, end_offset);
+            match segment.find_log_index_range(offset, count).await? {
+                Some(index_range) => index_ranges.push((segment, index_range)),
+                None => return Ok(None),
+            }
+        }
+
+        // The index ranges might be wider than the messages, but they're good enough to skip the small reads.
+        let size_bytes = index_ranges
+            .iter()
+            .map(|(_, index_range)| index_range.end.position - index_range.start.position)
+            .sum::<u64>();
+        if size_bytes < min_size_bytes {
+            return Ok(None);
+        }
+
+        let mut log_slices = Vec::with_capacity(index_ranges.len());
+        for (segment, index_range) in index_ranges {
+            match self
+                .storage
+                .segment
+                .load_log_slice(segment, &index_range)
+                .await?
+            {
+                Some(log_slice) => log_slices.push(log_slice),
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(log_slices))
+    }
+
+    // The first segment might still contain the messages deleted before the log start offset.
+    fn get_first_offset(&self) -> u64 {
+        match self.segments.first() {
+            Some(segment) => segment.start_offset.max(self.log_start_offset),
+            None => 0,
+        }
+    }
+
+    fn get_last_offset(&self, count: u32) -> u64 {
+        let count = (count as u64).min(self.current_offset + 1);
+        1 + self.current_offset - count
+    }
+
+    async fn get_next_offset(&self, consumer: PollingConsumer) -> Option<u64> {
         let (consumer_offsets, consumer_id) = match consumer {
             PollingConsumer::Consumer(consumer_id) => {
                 (self.consumer_offsets.read().await, consumer_id)
@@ -136,7 +241,7 @@ impl Partition {
                 consumer_id,
                 self.id
             );
-            return self.get_first_messages(count).await;
+            return Some(self.get_first_offset());
         }
 
         let consumer_offset = consumer_offset.unwrap().read().await;
@@ -147,7 +252,7 @@ impl Partition {
                 consumer_offset.offset,
                 self.id
             );
-            return Ok(EMPTY_MESSAGES);
+            return None;
         }
 
         let offset = consumer_offset.offset + 1;
@@ -157,8 +262,7 @@ impl Partition {
             self.id,
             offset
         );
-
-        self.get_messages_by_offset(offset, count).await
+        Some(offset)
     }
 
     fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
@@ -185,11 +289,12 @@ impl Partition {
 
     async fn get_messages_from_segments(
         segments: Vec<&Segment>,
-        offset: u64,
-        count: u32,
+        start_offset: u64,
+        end_offset: u64,
     ) -> Result<Vec<Arc<Message>>, Error> {
         let mut messages = Vec::with_capacity(segments.len());
         for segment in segments {
+            let (offset, count) = Self::get_segment_range(segment, start_offset, end_offset);
             let segment_messages = segment.get_messages(offset, count).await?;
             for message in segment_messages {
                 messages.push(message);
@@ -199,75 +304,83 @@ impl Partition {
         Ok(messages)
     }
 
+    // The messages count is limited by the end offset, as the further segments start after the requested offset.
+    fn get_segment_range(segment: &Segment, start_offset: u64, end_offset: u64) -> (u64, u32) {
+        let offset = start_offset.max(segment.start_offset);
+        (offset, (end_offset - offset + 1) as u32)
+    }
+
     fn try_get_messages_from_cache(
         &self,
         start_offset: u64,
         end_offset: u64,
     ) -> Option<Vec<Arc<Message>>> {
-        let messages = self.messages.as_ref()?;
-        if messages.is_empty() || start_offset > end_offset || end_offset > self.current_offset {
-            return None;
-        }
-
-        let first_buffered_offset = messages[0].offset;
-        trace!(
-            "First buffered offset: {} for partition: {}",
-            first_buffered_offset,
-            self.id
-        );
-
-        if start_offset >= first_buffered_offset {
-            return Some(self.load_messages_from_cache(start_offset, end_offset));
-        }
-
-        None
+        self.storage.cache.get(
+            self.stream_id,
+            self.topic_id,
+            self.id,
+            start_offset,
+            end_offset,
+        )
     }
 
-    fn load_messages_from_cache(&self, start_offset: u64, end_offset: u64) -> Vec<Arc<Message>> {
-        trace!(
-            "Loading messages from cache, start offset: {}, end offset: {}...",
+    fn is_cached(&self, start_offset: u64, end_offset: u64) -> bool {
+        self.storage.cache.contains(
+            self.stream_id,
+            self.topic_id,
+            self.id,
             start_offset,
-            end_offset
-        );
-
-        if self.messages.is_none() || start_offset > end_offset {
-            return EMPTY_MESSAGES;
-        }
-
-        let partition_messages = self.messages.as_ref().unwrap();
-        if partition_messages.is_empty() {
-            return EMPTY_MESSAGES;
-        }
+            end_offset,
+        )
+    }
 
-        let messages_count = (1 + end_offset - start_offset) as usize;
-        let first_offset = partition_messages[0].offset;
-        let start_index = start_offset - first_offset;
+    pub async fn append_messages(&mut self, messages: Vec<Message>) -> Result<(), Error> {
+        self.append(messages, None).await
+    }
 
-        let mut messages = Vec::with_capacity(messages_count);
-        for i in start_index..start_index + messages_count as u64 {
-            let message = partition_messages[i as isize].clone();
-            messages.push(message);
-        }
+    pub async fn append_batch(
+        &mut self,
+        messages: Vec<Message>,
+        batch: MessageBatch,
+    ) -> Result<(), Error> {
+        self.append(messages, Some(batch)).await
+    }
 
-        if messages.len() != messages_count {
-            error!(
-                "Loaded {} messages from cache, expected {}.",
-                messages.len(),
-                messages_count
-            );
+    pub(crate) async fn append(
+        &mut self,
+        mut messages: Vec<Message>,
+        batch: Option<MessageBatch>,
+    ) -> Result<(), Error> {
+        let mut batch = batch.filter(|batch| can_store_batch(batch, &messages, self.compression));
+        for message in messages.iter_mut() {
+            if message.id == 0 {
+                message.id = random_id::get();
+            }
         }
 
-        trace!(
-            "Loaded {} messages from cache, start offset: {}, end offset: {}...",
-            messages.len(),
-            start_offset,
-            end_offset
-        );
+        // The batch containing any duplicated message ID is rejected as a whole, so it can be safely sent again.
+        let message_ids = match self.deduplicator.as_mut() {
+            Some(deduplicator) => {
+                deduplicator.evict_expired(timestamp::get());
+                let mut message_ids = HashSet::with_capacity(messages.len());
+                for message in &messages {
+                    if deduplicator.contains(message.id) || !message_ids.insert(message.id) {
+                        warn!(
+                            "Rejected the duplicated message ID: {} for partition with ID: {}.",
+                            message.id, self.id
+                        );
+                        return Err(Error::DuplicateMessageId(message.id, self.id));
+                    }
+                }
 
-        messages
-    }
+                messages
+                    .iter()
+                    .map(|message| (message.id, message.timestamp))
+                    .collect()
+            }
+            None => Vec::new(),
+        };
 
-    pub async fn append_messages(&mut self, messages: Vec<Message>) -> Result<(), Error> {
         let segment = self.segments.last_mut();
         if segment.is_none() {
             return Err(Error::SegmentNotFound);
@@ -292,49 +405,36 @@ impl Partition {
             self.id
         );
 
-        let deduplicate_messages = self.message_ids.is_some();
+        let mut appended_messages = Vec::with_capacity(messages.len());
         for mut message in messages {
-            if message.id == 0 {
-                message.id = random_id::get();
-            }
-            if deduplicate_messages {
-                let message_ids = self.message_ids.as_mut().unwrap();
-                if message_ids.contains_key(&message.id) {
-                    warn!(
-                        "Ignored the duplicated message ID: {} for partition with ID: {}.",
-                        message.id, self.id
-                    );
-                    continue;
-                }
-
-                message_ids.insert(message.id, true);
-            }
-
             if self.should_increment_offset {
                 self.current_offset += 1;
             } else {
                 self.should_increment_offset = true;
             }
-            trace!(
-                "Appending the message with offset: {} to segment with start offset: {} for partition with ID: {}...",
-                self.current_offset,
-                segment.start_offset,
-                self.id
-            );
 
             message.offset = self.current_offset;
-            let message = Arc::new(message);
-            segment.append_message(message.clone()).await?;
-            if self.messages.is_some() {
-                self.messages.as_mut().unwrap().push(message);
+            appended_messages.push(Arc::new(message));
+        }
+
+        // The messages sent together are stored as a single batch.
+        if !appended_messages.is_empty() {
+            if let Some(batch) = batch.as_mut() {
+                batch.header.base_offset = appended_messages[0].offset;
+                batch.header.first_timestamp = appended_messages[0].timestamp;
+                batch.header.max_timestamp = appended_messages[0].timestamp;
             }
 
-            trace!(
-                "Appended the message with offset: {} to segment with start offset: {} for partition with ID: {}.",
-                self.current_offset,
-                segment.start_offset,
-                self.id
-            );
+            self.storage
+                .cache
+                .append(self.stream_id, self.topic_id, self.id, &appended_messages);
+            segment.append_messages(appended_messages, batch).await?;
+        }
+
+        if let Some(deduplicator) = self.deduplicator.as_mut() {
+            for (message_id, timestamp) in message_ids {
+                deduplicator.insert(message_id, timestamp);
+            }
         }
 
         trace!(
@@ -355,18 +455,71 @@ impl Partition {
             segment
                 .persist_messages(self.storage.segment.clone())
                 .await?;
+            if self.config.enforce_sync {
+                self.storage.segment.sync().await?;
+            }
             self.unsaved_messages_count = 0;
+            if segment.is_closed {
+                segment.compress(self.compression).await?;
+            }
         }
 
         Ok(())
     }
 
+    pub(crate) async fn set_deduplication(
+        &mut self,
+        deduplication: Option<DeduplicationWindow>,
+    ) -> Result<(), Error> {
+        self.deduplicator = deduplication.map(MessageDeduplicator::new);
+        self.load_message_ids().await
+    }
+
+    pub(crate) async fn load_message_ids(&mut self) -> Result<(), Error> {
+        let deduplicator = match self.deduplicator.as_mut() {
+            Some(deduplicator) => deduplicator,
+            None => return Ok(()),
+        };
+
+        let now = timestamp::get();
+        let mut ids_count = 0;
+        let mut segments_message_ids = Vec::new();
+        for segment in self.segments.iter().rev() {
+            if segment.is_archived {
+                break;
+            }
+
+            let message_ids = segment.storage.segment.load_message_ids(segment).await?;
+            ids_count += message_ids.len();
+            let oldest_timestamp = message_ids.first().map(|(_, timestamp)| *timestamp);
+            segments_message_ids.push(message_ids);
+            if oldest_timestamp.is_some_and(|oldest_timestamp| {
+                deduplicator.is_covered_by(ids_count, oldest_timestamp, now)
+            }) {
+                break;
+            }
+        }
+
+        for message_ids in segments_message_ids.into_iter().rev() {
+            for (message_id, timestamp) in message_ids {
+                deduplicator.insert(message_id, timestamp);
+            }
+        }
+        deduplicator.evict_expired(now);
+        info!(
+            "Loaded: {} message IDs for deduplication for partition with ID: {}.",
+            deduplicator.get_ids_count(),
+            self.id
+        );
+        Ok(())
+    }
+
     async fn process_new_segment(&mut self, start_offset: u64) -> Result<(), Error> {
         trace!(
             "Current segment is full, creating new segment for partition with ID: {}",
             self.id
         );
-        let new_segment = Segment::create(
+        let mut new_segment = Segment::create(
             self.stream_id,
             self.topic_id,
             self.id,
@@ -375,9 +528,42 @@ impl Partition {
             self.config.segment.clone(),
             self.storage.clone(),
         );
+        new_segment.checksum_validator = self.checksum_validator.clone();
         new_segment.persist().await?;
         self.segments.push(new_segment);
 
         Ok(())
     }
 }
+
+// The stored batch has the consecutive offsets and the shared timestamp of its messages encoded in the header only,
+// thus the records of the sent batch must have the same deltas and IDs as the messages being appended.
+// The compressed batch is stored only if its codec is the one of the topic, otherwise it's decompressed.
+fn can_store_batch(
+    batch: &MessageBatch,
+    messages: &[Message],
+    compression: CompressionAlgorithm,
+) -> bool {
+    if (batch.is_compressed() && batch.header.get_compression().ok() != Some(compression))
+        || messages.is_empty()
+        || batch.records.len() != messages.len()
+        || batch.header.last_offset_delta as usize != messages.len() - 1
+    {
+        return false;
+    }
+
+    let timestamp = messages[0].timestamp;
+    batch
+        .records
+        .iter()
+        .zip(messages)
+        .enumerate()
+        .all(|(index, (record, message))| {
+            record.offset_delta == index as u32
+                && record.timestamp_delta == 0
+                && record.id != 0
+                && record.id == message.id
+                && record.payload == message.payload
+                && message.timestamp == timestamp
+        })
+}
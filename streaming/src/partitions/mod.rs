@@ -1,5 +1,7 @@
+pub mod message_deduplicator;
 pub mod messages;
 pub mod offsets;
 pub mod partition;
 pub mod persistence;
+pub mod segments;
 pub mod storage;
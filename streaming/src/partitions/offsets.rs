@@ -92,6 +92,47 @@ impl Partition {
         Ok(())
     }
 
+    pub async fn is_drained(&self, consumer_group_ids: &[u32]) -> bool {
+        if !self.is_draining {
+            return false;
+        }
+
+        // No messages have been appended to the partition.
+        if !self.should_increment_offset {
+            return true;
+        }
+
+        let consumer_group_offsets = self.consumer_group_offsets.read().await;
+        for consumer_group_id in consumer_group_ids {
+            let consumer_offset = consumer_group_offsets.offsets.get(consumer_group_id);
+            if consumer_offset.is_none() {
+                return false;
+            }
+
+            if consumer_offset.unwrap().read().await.offset < self.current_offset {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    pub async fn delete_offsets(&self) -> Result<(), Error> {
+        for consumer_offsets in [&self.consumer_offsets, &self.consumer_group_offsets] {
+            let mut consumer_offsets = consumer_offsets.write().await;
+            for consumer_offset in consumer_offsets.offsets.values() {
+                let consumer_offset = consumer_offset.read().await;
+                self.storage
+                    .partition
+                    .delete_offset(&consumer_offset)
+                    .await?;
+            }
+            consumer_offsets.offsets.clear();
+        }
+
+        Ok(())
+    }
+
     pub async fn load_offsets(&mut self, consumer_type: ConsumerType) -> Result<(), Error> {
         trace!(
                 "Loading consumer offsets for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
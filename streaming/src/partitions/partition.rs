@@ -1,8 +1,10 @@
 use crate::config::PartitionConfig;
-use crate::message::Message;
+use crate::partitions::message_deduplicator::MessageDeduplicator;
+use crate::segments::checksum_validator::ChecksumValidator;
 use crate::segments::segment::Segment;
 use crate::storage::SystemStorage;
-use ringbuffer::AllocRingBuffer;
+use sdk::compression::CompressionAlgorithm;
+use sdk::topics::create_topic::ChecksumPolicy;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -16,14 +18,19 @@ pub struct Partition {
     pub offsets_path: String,
     pub consumer_offsets_path: String,
     pub consumer_group_offsets_path: String,
+    pub log_start_offset_path: String,
+    pub draining_path: String,
     pub current_offset: u64,
-    pub messages: Option<AllocRingBuffer<Arc<Message>>>,
-    pub message_ids: Option<HashMap<u128, bool>>,
+    pub log_start_offset: u64,
+    pub is_draining: bool,
+    pub deduplicator: Option<MessageDeduplicator>,
     pub unsaved_messages_count: u32,
     pub should_increment_offset: bool,
+    pub compression: CompressionAlgorithm,
     pub(crate) consumer_offsets: RwLock<ConsumerOffsets>,
     pub(crate) consumer_group_offsets: RwLock<ConsumerOffsets>,
     pub(crate) segments: Vec<Segment>,
+    pub(crate) checksum_validator: Arc<ChecksumValidator>,
     pub(crate) config: Arc<PartitionConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -73,6 +80,8 @@ impl Partition {
         let offsets_path = Self::get_offsets_path(&path);
         let consumer_offsets_path = Self::get_consumer_offsets_path(&offsets_path);
         let consumer_group_offsets_path = Self::get_consumer_group_offsets_path(&offsets_path);
+        let log_start_offset_path = Self::get_log_start_offset_path(&offsets_path);
+        let draining_path = Self::get_draining_path(&path);
         let mut partition = Partition {
             stream_id,
             topic_id,
@@ -81,20 +90,17 @@ impl Partition {
             offsets_path,
             consumer_offsets_path,
             consumer_group_offsets_path,
-            messages: match config.messages_buffer {
-                0 => None,
-                _ => Some(AllocRingBuffer::with_capacity(
-                    config.messages_buffer as usize,
-                )),
-            },
-            message_ids: match config.deduplicate_messages {
-                true => Some(HashMap::new()),
-                false => None,
-            },
+            log_start_offset_path,
+            draining_path,
+            deduplicator: None,
             segments: vec![],
+            checksum_validator: Arc::new(ChecksumValidator::default()),
             current_offset: 0,
+            log_start_offset: 0,
+            is_draining: false,
             unsaved_messages_count: 0,
             should_increment_offset: false,
+            compression: CompressionAlgorithm::default(),
             consumer_offsets: RwLock::new(ConsumerOffsets {
                 offsets: HashMap::new(),
             }),
@@ -106,7 +112,7 @@ impl Partition {
         };
 
         if with_segment {
-            let segment = Segment::create(
+            let mut segment = Segment::create(
                 stream_id,
                 topic_id,
                 id,
@@ -115,12 +121,28 @@ impl Partition {
                 partition.config.segment.clone(),
                 partition.storage.clone(),
             );
+            segment.checksum_validator = partition.checksum_validator.clone();
             partition.segments.push(segment);
         }
 
         partition
     }
 
+    pub fn get_checksum_policy(&self) -> ChecksumPolicy {
+        self.checksum_validator.get_policy()
+    }
+
+    pub fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.checksum_validator = Arc::new(ChecksumValidator::new(policy));
+        for segment in self.segments.iter_mut() {
+            segment.checksum_validator = self.checksum_validator.clone();
+        }
+    }
+
+    pub fn get_corrupted_messages_count(&self) -> u64 {
+        self.checksum_validator.get_corrupted_messages_count()
+    }
+
     pub fn get_segments(&self) -> &Vec<Segment> {
         &self.segments
     }
@@ -144,6 +166,14 @@ impl Partition {
     fn get_consumer_group_offsets_path(offsets_path: &str) -> String {
         format!("{}/groups", offsets_path)
     }
+
+    fn get_log_start_offset_path(offsets_path: &str) -> String {
+        format!("{}/log_start", offsets_path)
+    }
+
+    fn get_draining_path(path: &str) -> String {
+        format!("{}/draining", path)
+    }
 }
 
 #[cfg(test)]
@@ -151,7 +181,6 @@ mod tests {
     use crate::config::PartitionConfig;
     use crate::partitions::partition::Partition;
     use crate::storage::tests::get_test_system_storage;
-    use ringbuffer::RingBuffer;
     use std::sync::Arc;
 
     #[test]
@@ -167,7 +196,8 @@ mod tests {
         let offsets_path = Partition::get_offsets_path(&path);
         let consumer_offsets_path = Partition::get_consumer_offsets_path(&offsets_path);
         let consumer_group_offsets_path = Partition::get_consumer_group_offsets_path(&offsets_path);
-        let messages_buffer_capacity = config.messages_buffer as usize;
+        let log_start_offset_path = Partition::get_log_start_offset_path(&offsets_path);
+        let draining_path = Partition::get_draining_path(&path);
 
         let partition = Partition::create(
             stream_id,
@@ -189,40 +219,18 @@ mod tests {
             partition.consumer_group_offsets_path,
             consumer_group_offsets_path
         );
+        assert_eq!(partition.log_start_offset_path, log_start_offset_path);
+        assert_eq!(partition.draining_path, draining_path);
         assert_eq!(partition.current_offset, 0);
+        assert_eq!(partition.log_start_offset, 0);
+        assert!(!partition.is_draining);
         assert_eq!(partition.unsaved_messages_count, 0);
         assert_eq!(partition.segments.len(), 1);
-        assert!(partition.messages.is_some());
-        assert_eq!(
-            partition.messages.as_ref().unwrap().capacity(),
-            messages_buffer_capacity
-        );
         assert!(!partition.should_increment_offset);
-        assert!(partition.messages.as_ref().unwrap().is_empty());
         let consumer_offsets = partition.consumer_offsets.blocking_read();
         assert!(consumer_offsets.offsets.is_empty());
     }
 
-    #[test]
-    fn should_not_initialize_messages_buffer_given_zero_capacity() {
-        let storage = Arc::new(get_test_system_storage());
-        let topic_id = 1;
-        let partitions_path = &format!("/topics/{topic_id}/partitions");
-        let partition = Partition::create(
-            1,
-            1,
-            1,
-            partitions_path,
-            true,
-            Arc::new(PartitionConfig {
-                messages_buffer: 0,
-                ..Default::default()
-            }),
-            storage,
-        );
-        assert!(partition.messages.is_none());
-    }
-
     #[test]
     fn should_not_initialize_segments_given_false_with_segment_parameter() {
         let storage = Arc::new(get_test_system_storage());
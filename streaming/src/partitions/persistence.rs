@@ -14,4 +14,13 @@ impl Partition {
     pub async fn delete(&self) -> Result<(), Error> {
         self.storage.partition.delete(self).await
     }
+
+    pub async fn start_draining(&mut self) -> Result<(), Error> {
+        if self.is_draining {
+            return Ok(());
+        }
+
+        self.is_draining = true;
+        self.storage.partition.save_draining(self).await
+    }
 }
@@ -0,0 +1,258 @@
+use crate::partitions::partition::Partition;
+use crate::remote_store::RemoteSegmentStore;
+use crate::segments::segment::Segment;
+use sdk::error::Error;
+use std::collections::HashMap;
+use tracing::info;
+
+impl Partition {
+    pub fn get_size_bytes(&self) -> u64 {
+        self.segments
+            .iter()
+            .map(|segment| segment.current_size_bytes)
+            .sum()
+    }
+
+    pub async fn get_expired_segments_start_offsets(
+        &self,
+        now: u64,
+        message_expiry: u32,
+    ) -> Result<Vec<u64>, Error> {
+        let mut start_offsets = Vec::new();
+        if self.segments.len() < 2 {
+            return Ok(start_offsets);
+        }
+
+        for segment in self.segments.iter().take(self.segments.len() - 1) {
+            if !segment.is_expired(now, message_expiry).await? {
+                break;
+            }
+
+            start_offsets.push(segment.start_offset);
+        }
+
+        Ok(start_offsets)
+    }
+
+    pub async fn delete_segment(&mut self, start_offset: u64) -> Result<(), Error> {
+        let index = self
+            .segments
+            .iter()
+            .position(|segment| segment.start_offset == start_offset);
+        if index.is_none() {
+            return Err(Error::SegmentNotFound);
+        }
+
+        let segment = self.segments.remove(index.unwrap());
+        self.storage.segment.delete(&segment).await?;
+        info!(
+            "Deleted segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+            segment.start_offset, self.id, self.stream_id, self.topic_id
+        );
+
+        Ok(())
+    }
+
+    // The key of the compaction is the message ID, and the original offsets are preserved.
+    pub async fn compact_segments(
+        &mut self,
+        now: u64,
+        tombstone_retention: u32,
+    ) -> Result<u32, Error> {
+        if self.segments.len() < 2 {
+            return Ok(0);
+        }
+
+        // Only the newest offset of each key is kept in memory, while the messages of the closed segments
+        // are loaded one segment at a time, when the segment is being rewritten.
+        // The archived segments are skipped, as their logs would have to be downloaded.
+        let mut newest_offsets = HashMap::new();
+        for segment in self.segments.iter().filter(|segment| !segment.is_archived) {
+            for (id, offset) in segment.get_message_offsets().await? {
+                newest_offsets.insert(id, offset);
+            }
+        }
+
+        let tombstone_retention = tombstone_retention as u64 * 1_000_000;
+        let segments_count = self.segments.len();
+        let mut removed_messages = 0;
+        let mut empty_segments = Vec::new();
+        for segment in self.segments.iter_mut().take(segments_count - 1) {
+            if !segment.is_closed || segment.is_archived {
+                continue;
+            }
+
+            let messages = segment.get_all_messages().await?;
+            let messages_count = messages.len();
+            let messages = messages
+                .into_iter()
+                .filter(|message| {
+                    newest_offsets.get(&message.id) == Some(&message.offset)
+                        && !(message.payload.is_empty()
+                            && message.timestamp + tombstone_retention <= now)
+                })
+                .collect::<Vec<_>>();
+            if messages.len() == messages_count {
+                continue;
+            }
+
+            removed_messages += (messages_count - messages.len()) as u32;
+            if messages.is_empty() {
+                empty_segments.push(segment.start_offset);
+                continue;
+            }
+
+            segment.overwrite_messages(&messages).await?;
+            segment.compress(self.compression).await?;
+        }
+
+        for start_offset in empty_segments {
+            self.delete_segment(start_offset).await?;
+        }
+
+        if removed_messages > 0 {
+            // The cached messages might have been removed, and they're loaded from the segments on the next poll.
+            self.storage
+                .cache
+                .remove_partition(self.stream_id, self.topic_id, self.id);
+            info!(
+                "Compacted segments, removed {} message(s) for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                removed_messages, self.id, self.stream_id, self.topic_id
+            );
+        }
+
+        Ok(removed_messages)
+    }
+
+    pub async fn delete_archived_segments(&self) -> Result<(), Error> {
+        for segment in self.segments.iter().filter(|segment| segment.is_archived) {
+            self.storage.segment.delete(segment).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn close_segments(&self) -> Result<(), Error> {
+        for segment in self.segments.iter() {
+            self.storage.segment.close(segment).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn archive_segments(
+        &mut self,
+        now: u64,
+        archive_after: u32,
+        remote: &dyn RemoteSegmentStore,
+    ) -> Result<u32, Error> {
+        if self.segments.len() < 2 {
+            return Ok(0);
+        }
+
+        let mut archived_segments = 0;
+        let segments_count = self.segments.len();
+        for segment in self.segments.iter_mut().take(segments_count - 1) {
+            if !segment.is_expired(now, archive_after).await? {
+                break;
+            }
+
+            if !segment.is_archived {
+                archived_segments += 1;
+            }
+            segment.archive(remote).await?;
+        }
+
+        if archived_segments > 0 {
+            info!(
+                "Archived {} segment(s) for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+                archived_segments, self.id, self.stream_id, self.topic_id
+            );
+        }
+
+        Ok(archived_segments)
+    }
+
+    pub async fn purge(&mut self) -> Result<(), Error> {
+        for segment in self.segments.iter() {
+            self.storage.segment.delete(segment).await?;
+        }
+        self.segments.clear();
+        self.storage
+            .cache
+            .remove_partition(self.stream_id, self.topic_id, self.id);
+        self.delete_offsets().await?;
+        if let Some(deduplicator) = self.deduplicator.as_mut() {
+            deduplicator.clear();
+        }
+
+        self.current_offset = 0;
+        self.log_start_offset = 0;
+        self.unsaved_messages_count = 0;
+        self.should_increment_offset = false;
+        self.storage.partition.save_log_start_offset(self).await?;
+        let mut segment = Segment::create(
+            self.stream_id,
+            self.topic_id,
+            self.id,
+            0,
+            &self.path,
+            self.config.segment.clone(),
+            self.storage.clone(),
+        );
+        segment.checksum_validator = self.checksum_validator.clone();
+        segment.persist().await?;
+        self.segments.push(segment);
+
+        info!(
+            "Purged partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+            self.id, self.stream_id, self.topic_id
+        );
+
+        Ok(())
+    }
+
+    pub async fn delete_messages_before(&mut self, offset: u64) -> Result<u32, Error> {
+        if offset > self.current_offset {
+            return Err(Error::InvalidOffset(offset));
+        }
+
+        if offset <= self.log_start_offset {
+            return Ok(0);
+        }
+
+        self.log_start_offset = offset;
+        self.storage.partition.save_log_start_offset(self).await?;
+        let start_offsets = self
+            .segments
+            .iter()
+            .take(self.segments.len() - 1)
+            .take_while(|segment| segment.end_offset < offset)
+            .map(|segment| segment.start_offset)
+            .collect::<Vec<u64>>();
+        for start_offset in start_offsets.iter() {
+            self.delete_segment(*start_offset).await?;
+        }
+
+        info!(
+            "Deleted messages before offset: {}, removed {} segment(s) for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+            offset, start_offsets.len(), self.id, self.stream_id, self.topic_id
+        );
+
+        Ok(start_offsets.len() as u32)
+    }
+
+    pub async fn rebuild_indexes(&mut self) -> Result<(), Error> {
+        for segment in self.segments.iter_mut() {
+            segment.rebuild_indexes().await?;
+        }
+        self.unsaved_messages_count = 0;
+
+        info!(
+            "Rebuilt indexes for {} segment(s) for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+            self.segments.len(), self.id, self.stream_id, self.topic_id
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,17 @@
 use crate::partitions::partition::{ConsumerOffset, Partition};
 use crate::persister::Persister;
+use crate::utils::file;
 use async_trait::async_trait;
 use sdk::consumer_type::ConsumerType;
 use sdk::error::Error;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
 use tokio::fs::create_dir;
+use tokio::io::AsyncReadExt;
 use tracing::{error, info, trace};
 
-use crate::segments::segment::{Segment, LOG_EXTENSION};
+use crate::segments::segment::{Segment, ARCHIVE_EXTENSION, LOG_EXTENSION};
 use crate::storage::{PartitionStorage, Storage};
 
 #[derive(Debug)]
@@ -38,6 +41,35 @@ impl PartitionStorage for FilePartitionStorage {
         );
         Ok(())
     }
+
+    async fn delete_offset(&self, offset: &ConsumerOffset) -> Result<(), Error> {
+        self.persister.delete(&offset.path).await?;
+        trace!("Deleted offset for {}", offset.consumer_id);
+        Ok(())
+    }
+
+    async fn save_log_start_offset(&self, partition: &Partition) -> Result<(), Error> {
+        self.persister
+            .overwrite(
+                &partition.log_start_offset_path,
+                &partition.log_start_offset.to_le_bytes(),
+            )
+            .await?;
+        trace!(
+            "Stored log start offset: {} for partition with ID: {}",
+            partition.log_start_offset,
+            partition.id
+        );
+        Ok(())
+    }
+
+    async fn save_draining(&self, partition: &Partition) -> Result<(), Error> {
+        self.persister
+            .overwrite(&partition.draining_path, &[])
+            .await?;
+        trace!("Marked partition with ID: {} as draining", partition.id);
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -55,6 +87,7 @@ impl Storage<Partition> for FilePartitionStorage {
             ));
         }
 
+        let mut start_offsets = Vec::new();
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let metadata = dir_entry.metadata().await.unwrap();
@@ -62,19 +95,23 @@ impl Storage<Partition> for FilePartitionStorage {
                 continue;
             }
 
+            // The log of the archived segment might be missing, but its archive marker is always present.
             let path = dir_entry.path();
             let extension = path.extension();
-            if extension.is_none() || extension.unwrap() != LOG_EXTENSION {
+            if extension.is_none()
+                || (extension.unwrap() != LOG_EXTENSION && extension.unwrap() != ARCHIVE_EXTENSION)
+            {
                 continue;
             }
 
-            let log_file_name = dir_entry
-                .file_name()
-                .into_string()
-                .unwrap()
-                .replace(&format!(".{}", LOG_EXTENSION), "");
+            let segment_file_name = path.file_stem().unwrap().to_str().unwrap();
+            start_offsets.push(segment_file_name.parse::<u64>().unwrap());
+        }
 
-            let start_offset = log_file_name.parse::<u64>().unwrap();
+        start_offsets.sort();
+        start_offsets.dedup();
+        let segments_count = start_offsets.len();
+        for (index, start_offset) in start_offsets.into_iter().enumerate() {
             let mut segment = Segment::create(
                 partition.stream_id,
                 partition.topic_id,
@@ -84,9 +121,15 @@ impl Storage<Partition> for FilePartitionStorage {
                 partition.config.segment.clone(),
                 partition.storage.clone(),
             );
+            segment.checksum_validator = partition.checksum_validator.clone();
+            // Only the last segment could have been written to when the server was stopped.
+            if index == segments_count - 1 {
+                segment.storage.segment.recover(&segment).await?;
+            }
+
             segment.load().await?;
             if !segment.is_closed {
-                segment.unsaved_messages = Some(Vec::new())
+                segment.unsaved_batches = Some(Vec::new())
             }
 
             // If the first segment has at least a single message, we should increment the offset.
@@ -94,30 +137,16 @@ impl Storage<Partition> for FilePartitionStorage {
                 partition.should_increment_offset = segment.current_size_bytes > 0;
             }
 
-            if partition.config.validate_checksum {
+            // The archived segments would have to be downloaded, so they're not validated.
+            if partition.config.validate_checksum && !segment.is_archived {
                 info!("Validating messages checksum for partition with ID: {} and segment with start offset: {}...", partition.id, segment.start_offset);
                 segment.storage.segment.load_checksums(&segment).await?;
                 info!("Validated messages checksum for partition with ID: {} and segment with start offset: {}.", partition.id, segment.start_offset);
             }
 
-            // Load the unique message IDs for the partition if the deduplication feature is enabled.
-            if partition.message_ids.is_some() {
-                info!("Loading unique message IDs for partition with ID: {} and segment with start offset: {}...", partition.id, segment.start_offset);
-                let partition_message_ids = partition.message_ids.as_mut().unwrap();
-                let message_ids = segment.storage.segment.load_message_ids(&segment).await?;
-                for message_id in message_ids {
-                    partition_message_ids.insert(message_id, true);
-                }
-                info!("Loaded: {} unique message IDs for partition with ID: {} and segment with start offset: {}...", partition_message_ids.len(), partition.id, segment.start_offset);
-            }
-
             partition.segments.push(segment);
         }
 
-        partition
-            .segments
-            .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
-
         let end_offsets = partition
             .segments
             .iter()
@@ -125,13 +154,15 @@ impl Storage<Partition> for FilePartitionStorage {
             .map(|segment| segment.start_offset - 1)
             .collect::<Vec<u64>>();
 
-        let segments_count = partition.segments.len();
         for (end_offset_index, segment) in partition.get_segments_mut().iter_mut().enumerate() {
             if end_offset_index == segments_count - 1 {
                 break;
             }
 
+            // The compacted segments might be smaller than the configured size, but only the last one can be still open.
             segment.end_offset = end_offsets[end_offset_index];
+            segment.is_closed = true;
+            segment.unsaved_batches = None;
         }
 
         let last_segment = partition.segments.last_mut().unwrap();
@@ -140,6 +171,15 @@ impl Storage<Partition> for FilePartitionStorage {
         }
 
         partition.current_offset = last_segment.current_offset;
+        // The log start offset is saved only once the messages are deleted on demand.
+        if Path::new(&partition.log_start_offset_path).exists() {
+            let mut file = file::open(&partition.log_start_offset_path).await?;
+            partition.log_start_offset = file.read_u64_le().await?;
+        }
+
+        partition.is_draining = Path::new(&partition.draining_path).exists();
+
+        partition.load_message_ids().await?;
         partition.load_offsets(ConsumerType::Consumer).await?;
         partition.load_offsets(ConsumerType::ConsumerGroup).await?;
         info!(
@@ -216,6 +256,9 @@ impl Storage<Partition> for FilePartitionStorage {
             "Deleting partition with ID: {} for stream with ID: {} and topic with ID: {}...",
             partition.id, partition.stream_id, partition.topic_id,
         );
+        partition.delete_archived_segments().await?;
+        partition.close_segments().await?;
+        self.persister.close(&partition.path).await?;
         if fs::remove_dir_all(&partition.path).await.is_err() {
             return Err(Error::CannotDeletePartitionDirectory(
                 partition.id,
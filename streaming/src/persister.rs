@@ -1,15 +1,51 @@
 use crate::utils::file;
 use async_trait::async_trait;
+use bytes::Bytes;
 use sdk::error::Error;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::io::{ErrorKind, IoSlice, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
+use tokio::task;
 
 #[async_trait]
 pub trait Persister: Sync + Send {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error>;
     async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), Error>;
     async fn delete(&self, path: &str) -> Result<(), Error>;
+
+    async fn replace(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        let temporary_path = format!("{}.tmp", path);
+        self.overwrite(&temporary_path, bytes).await?;
+        fs::rename(&temporary_path, path).await?;
+        Ok(())
+    }
+
+    async fn append_batch(&self, appends: Vec<FileAppend>) -> Result<(), Error> {
+        for append in appends {
+            self.append(&append.path, &append.buffers.concat()).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&self, _path: &str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn enforce_sync(&self, _path: &str, _enforce_sync: bool) {}
+
+    async fn sync(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct FileAppend {
+    pub path: String,
+    pub buffers: Vec<Bytes>,
 }
 
 impl Debug for dyn Persister {
@@ -73,3 +109,207 @@ impl Persister for FileWithSyncPersister {
         Ok(())
     }
 }
+
+// The batches appended concurrently are flushed to disk with a single fsync (group commit) once the sync is requested.
+#[derive(Debug)]
+pub struct FileHandlePersister {
+    enforce_sync: bool,
+    synced_directories: Mutex<HashMap<String, bool>>,
+    handles: Mutex<HashMap<String, Arc<std::fs::File>>>,
+    unsynced_handles: Mutex<HashMap<String, Arc<std::fs::File>>>,
+    appended_batches: AtomicU64,
+    synced_batches: tokio::sync::Mutex<u64>,
+}
+
+impl FileHandlePersister {
+    pub fn new(enforce_sync: bool) -> Self {
+        Self {
+            enforce_sync,
+            synced_directories: Mutex::new(HashMap::new()),
+            handles: Mutex::new(HashMap::new()),
+            unsynced_handles: Mutex::new(HashMap::new()),
+            appended_batches: AtomicU64::new(0),
+            synced_batches: tokio::sync::Mutex::new(0),
+        }
+    }
+
+    async fn get_handle(&self, path: &str) -> Result<Arc<std::fs::File>, Error> {
+        if let Some(handle) = self.handles.lock().unwrap().get(path) {
+            return Ok(handle.clone());
+        }
+
+        let handle = Arc::new(file::append(path).await?.into_std().await);
+        let mut handles = self.handles.lock().unwrap();
+        Ok(handles.entry(path.to_string()).or_insert(handle).clone())
+    }
+
+    fn is_sync_enforced(&self, path: &str) -> bool {
+        self.synced_directories
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(directory_path, _)| path.starts_with(&format!("{}/", directory_path)))
+            .map_or(self.enforce_sync, |(_, enforce_sync)| *enforce_sync)
+    }
+
+    // The data which wasn't synced yet is left to the OS, as the closed files are either deleted, replaced or full,
+    // and the full ones have been already synced, if the sync is enforced.
+    fn close_handles(&self, path: &str) {
+        let directory_path = format!("{}/", path);
+        let is_closed =
+            |handle_path: &String| handle_path == path || handle_path.starts_with(&directory_path);
+        self.synced_directories
+            .lock()
+            .unwrap()
+            .retain(|synced_path, _| !is_closed(synced_path));
+        self.handles
+            .lock()
+            .unwrap()
+            .retain(|handle_path, _| !is_closed(handle_path));
+        self.unsynced_handles
+            .lock()
+            .unwrap()
+            .retain(|handle_path, _| !is_closed(handle_path));
+    }
+
+    // Only one fsync is performed at a time, covering all the files appended since the previous one,
+    // so the writers waiting for it return immediately, if their batch has been already synced by another one.
+    async fn commit(&self, batch: u64) -> Result<(), Error> {
+        let mut synced_batches = self.synced_batches.lock().await;
+        if *synced_batches >= batch {
+            return Ok(());
+        }
+
+        let appended_batches = self.appended_batches.load(Ordering::SeqCst);
+        let handles = std::mem::take(&mut *self.unsynced_handles.lock().unwrap());
+        let (handles, result) = task::spawn_blocking(move || {
+            let result = handles.values().try_for_each(|handle| handle.sync_all());
+            (handles, result)
+        })
+        .await
+        .map_err(std::io::Error::from)?;
+
+        if let Err(error) = result {
+            // The files remain unsynced, so that the next commit retries them.
+            self.unsynced_handles.lock().unwrap().extend(handles);
+            return Err(error.into());
+        }
+
+        *synced_batches = appended_batches;
+        Ok(())
+    }
+}
+
+unsafe impl Send for FileHandlePersister {}
+unsafe impl Sync for FileHandlePersister {}
+
+#[async_trait]
+impl Persister for FileHandlePersister {
+    async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        self.append_batch(vec![FileAppend {
+            path: path.to_string(),
+            buffers: vec![Bytes::copy_from_slice(bytes)],
+        }])
+        .await
+    }
+
+    async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        self.close_handles(path);
+        let mut file = file::write(path).await?;
+        file.write_all(bytes).await?;
+        if self.is_sync_enforced(path) {
+            file.sync_all().await?;
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), Error> {
+        self.close_handles(path);
+        fs::remove_file(path).await?;
+        Ok(())
+    }
+
+    async fn append_batch(&self, appends: Vec<FileAppend>) -> Result<(), Error> {
+        let mut writes = Vec::with_capacity(appends.len());
+        for append in appends {
+            if append.buffers.iter().all(|buffer| buffer.is_empty()) {
+                continue;
+            }
+
+            let handle = self.get_handle(&append.path).await?;
+            writes.push((append.path, handle, append.buffers));
+        }
+
+        let writes = task::spawn_blocking(move || {
+            for (_, handle, buffers) in writes.iter() {
+                write_all_vectored(handle, buffers)?;
+            }
+            Ok::<_, std::io::Error>(writes)
+        })
+        .await
+        .map_err(std::io::Error::from)??;
+
+        {
+            let mut unsynced_handles = self.unsynced_handles.lock().unwrap();
+            for (path, handle, _) in writes {
+                unsynced_handles.insert(path, handle);
+            }
+        }
+
+        self.appended_batches.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn close(&self, path: &str) -> Result<(), Error> {
+        self.close_handles(path);
+        Ok(())
+    }
+
+    fn enforce_sync(&self, path: &str, enforce_sync: bool) {
+        self.synced_directories
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), enforce_sync);
+    }
+
+    async fn sync(&self) -> Result<(), Error> {
+        let batch = self.appended_batches.load(Ordering::SeqCst);
+        self.commit(batch).await
+    }
+}
+
+// Writes all the buffers, even if the single vectored write accepts only a part of them.
+fn write_all_vectored(mut file: &std::fs::File, buffers: &[Bytes]) -> Result<(), std::io::Error> {
+    let mut index = 0;
+    let mut position = 0;
+    loop {
+        while index < buffers.len() && position == buffers[index].len() {
+            index += 1;
+            position = 0;
+        }
+        if index == buffers.len() {
+            return Ok(());
+        }
+
+        let slices = std::iter::once(IoSlice::new(&buffers[index][position..]))
+            .chain(
+                buffers[index + 1..]
+                    .iter()
+                    .map(|buffer| IoSlice::new(buffer)),
+            )
+            .collect::<Vec<IoSlice>>();
+        let mut written_bytes = match file.write_vectored(&slices) {
+            Ok(0) => return Err(ErrorKind::WriteZero.into()),
+            Ok(written_bytes) => written_bytes,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+
+        while index < buffers.len() && written_bytes >= buffers[index].len() - position {
+            written_bytes -= buffers[index].len() - position;
+            index += 1;
+            position = 0;
+        }
+        position += written_bytes;
+    }
+}
@@ -0,0 +1,83 @@
+use crate::utils::random_id;
+use async_trait::async_trait;
+use sdk::error::Error;
+use std::fmt::Debug;
+use std::path::Path;
+use tokio::fs;
+use tracing::trace;
+
+#[async_trait]
+pub trait RemoteSegmentStore: Sync + Send {
+    async fn upload(&self, key: &str, path: &str) -> Result<(), Error>;
+    async fn download(&self, key: &str, path: &str) -> Result<(), Error>;
+    async fn delete(&self, key: &str) -> Result<(), Error>;
+}
+
+impl Debug for dyn RemoteSegmentStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteSegmentStore")
+            .field("type", &"RemoteSegmentStore")
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub struct ArchiveDirectoryStore {
+    path: String,
+}
+
+impl ArchiveDirectoryStore {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+        }
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        format!("{}/{}", self.path, key)
+    }
+}
+
+unsafe impl Send for ArchiveDirectoryStore {}
+unsafe impl Sync for ArchiveDirectoryStore {}
+
+#[async_trait]
+impl RemoteSegmentStore for ArchiveDirectoryStore {
+    async fn upload(&self, key: &str, path: &str) -> Result<(), Error> {
+        let archive_path = self.get_path(key);
+        if let Some(parent) = Path::new(&archive_path).parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        copy_file(path, &archive_path).await?;
+        trace!("Uploaded file: {} to archive: {}", path, archive_path);
+        Ok(())
+    }
+
+    async fn download(&self, key: &str, path: &str) -> Result<(), Error> {
+        let archive_path = self.get_path(key);
+        copy_file(&archive_path, path).await?;
+        trace!("Downloaded file: {} from archive: {}", path, archive_path);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        let archive_path = self.get_path(key);
+        if Path::new(&archive_path).exists() {
+            fs::remove_file(&archive_path).await?;
+        }
+
+        trace!("Deleted file: {} from archive.", archive_path);
+        Ok(())
+    }
+}
+
+// The file is copied under the unique temporary path first, so the readers never see the partial copy,
+// even if the same file is being copied concurrently.
+async fn copy_file(source_path: &str, target_path: &str) -> Result<(), Error> {
+    let temp_path = format!("{}.{}.tmp", target_path, random_id::get());
+    fs::copy(source_path, &temp_path).await?;
+    fs::File::open(&temp_path).await?.sync_all().await?;
+    fs::rename(&temp_path, target_path).await?;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use sdk::compression::CompressionAlgorithm;
+
+#[derive(Debug)]
+pub struct BlockIndex {
+    pub compression: CompressionAlgorithm,
+    pub blocks: Vec<Block>,
+}
+
+// Positions refer to the uncompressed log, thus the regular indexes remain valid for the compressed segment.
+#[derive(Debug, Clone, Copy)]
+pub struct Block {
+    pub position: u64,
+    pub size_bytes: u32,
+    pub compressed_position: u64,
+    pub compressed_size_bytes: u32,
+}
+
+impl BlockIndex {
+    pub fn get_size_bytes(&self) -> u64 {
+        self.blocks
+            .last()
+            .map(|block| block.position + block.size_bytes as u64)
+            .unwrap_or(0)
+    }
+
+    pub fn get_compressed_size_bytes(&self) -> u64 {
+        self.blocks
+            .last()
+            .map(|block| block.compressed_position + block.compressed_size_bytes as u64)
+            .unwrap_or(0)
+    }
+
+    pub fn get_blocks(&self, start_position: u64, end_position: u64) -> &[Block] {
+        let start_index = self
+            .blocks
+            .partition_point(|block| block.position + block.size_bytes as u64 <= start_position);
+        let end_index = self
+            .blocks
+            .partition_point(|block| block.position < end_position);
+        if start_index >= end_index {
+            return &[];
+        }
+
+        &self.blocks[start_index..end_index]
+    }
+}
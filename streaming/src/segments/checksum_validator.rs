@@ -0,0 +1,133 @@
+use sdk::batch::MessageBatch;
+use sdk::error::Error;
+use sdk::topics::create_topic::ChecksumPolicy;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{error, warn};
+
+#[derive(Debug, Default)]
+pub struct ChecksumValidator {
+    policy: ChecksumPolicy,
+    corrupted_messages_count: AtomicU64,
+}
+
+impl ChecksumValidator {
+    pub fn new(policy: ChecksumPolicy) -> Self {
+        Self {
+            policy,
+            ..Default::default()
+        }
+    }
+
+    pub fn get_policy(&self) -> ChecksumPolicy {
+        self.policy
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.policy != ChecksumPolicy::None
+    }
+
+    pub fn get_corrupted_messages_count(&self) -> u64 {
+        self.corrupted_messages_count.load(Ordering::Relaxed)
+    }
+
+    pub fn validate(&self, batch: &MessageBatch, partition_id: u32) -> Result<bool, Error> {
+        if !self.is_enabled() {
+            return Ok(true);
+        }
+
+        let error = match batch.validate() {
+            Ok(()) => return Ok(true),
+            Err(error) => error,
+        };
+
+        self.corrupted_messages_count
+            .fetch_add(batch.header.records_count as u64, Ordering::Relaxed);
+        match self.policy {
+            ChecksumPolicy::Skip => {
+                warn!(
+                    "Skipped {} corrupted messages of batch with base offset: {} for partition with ID: {}, {}.",
+                    batch.header.records_count, batch.header.base_offset, partition_id, error
+                );
+                Ok(false)
+            }
+            ChecksumPolicy::Log => {
+                error!(
+                    "Serving {} corrupted messages of batch with base offset: {} for partition with ID: {}, {}.",
+                    batch.header.records_count, batch.header.base_offset, partition_id, error
+                );
+                Ok(true)
+            }
+            _ => {
+                error!(
+                    "Cannot read corrupted messages of batch with base offset: {} for partition with ID: {}, {}.",
+                    batch.header.base_offset, partition_id, error
+                );
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message;
+    use crate::message::Message;
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    #[test]
+    fn should_handle_corrupted_batch_according_to_policy() {
+        let batch = get_corrupted_batch();
+
+        let validator = ChecksumValidator::new(ChecksumPolicy::Fail);
+        assert!(validator.validate(&batch, 1).is_err());
+        assert_eq!(validator.get_corrupted_messages_count(), 2);
+
+        let validator = ChecksumValidator::new(ChecksumPolicy::Skip);
+        assert!(!validator.validate(&batch, 1).unwrap());
+        assert_eq!(validator.get_corrupted_messages_count(), 2);
+
+        let validator = ChecksumValidator::new(ChecksumPolicy::Log);
+        assert!(validator.validate(&batch, 1).unwrap());
+        assert_eq!(validator.get_corrupted_messages_count(), 2);
+
+        let validator = ChecksumValidator::new(ChecksumPolicy::None);
+        assert!(validator.validate(&batch, 1).unwrap());
+        assert_eq!(validator.get_corrupted_messages_count(), 0);
+    }
+
+    #[test]
+    fn should_serve_valid_batch_given_any_policy() {
+        let messages = get_messages();
+        let batch = message::create_batch(&messages);
+        for policy in [
+            ChecksumPolicy::Fail,
+            ChecksumPolicy::Skip,
+            ChecksumPolicy::Log,
+        ] {
+            let validator = ChecksumValidator::new(policy);
+            assert!(validator.validate(&batch, 1).unwrap());
+            assert_eq!(validator.get_corrupted_messages_count(), 0);
+        }
+    }
+
+    fn get_corrupted_batch() -> MessageBatch {
+        let mut batch = message::create_batch(&get_messages());
+        batch.header.crc += 1;
+        batch
+    }
+
+    fn get_messages() -> Vec<Arc<Message>> {
+        (0..2)
+            .map(|offset| {
+                Arc::new(Message::create(
+                    offset,
+                    offset,
+                    offset as u128 + 1,
+                    Bytes::from("test"),
+                ))
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,7 @@
 #[derive(Debug)]
 pub struct Index {
     pub relative_offset: u32,
-    pub position: u32,
+    pub position: u64,
 }
 
 #[derive(Debug)]
@@ -19,7 +19,7 @@ impl IndexRange {
             },
             end: Index {
                 relative_offset: u32::MAX - 1,
-                position: u32::MAX,
+                position: u64::MAX,
             },
         }
     }
@@ -0,0 +1,70 @@
+use bytes::Bytes;
+use sdk::batch::BatchHeader;
+use sdk::error::Error;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use tokio::task;
+
+// The file is opened once the slice is found, so it still points to the same content, even if the log is replaced.
+#[derive(Debug)]
+pub struct LogSlice {
+    pub file: File,
+    pub batches: Vec<LogSliceBatch>,
+}
+
+#[derive(Debug)]
+pub struct LogSliceBatch {
+    pub header: BatchHeader,
+    pub position: u64,
+    pub trimmed: Option<Bytes>,
+}
+
+impl LogSlice {
+    pub fn get_size_bytes(&self) -> u64 {
+        self.batches
+            .iter()
+            .map(|batch| batch.header.length as u64)
+            .sum()
+    }
+
+    pub fn get_messages_count(&self) -> u32 {
+        self.batches
+            .iter()
+            .map(|batch| batch.header.records_count)
+            .sum()
+    }
+
+    pub fn get_last_offset(&self) -> Option<u64> {
+        self.batches
+            .last()
+            .map(|batch| batch.header.get_last_offset())
+    }
+
+    pub async fn extend(&self, bytes: &mut Vec<u8>) -> Result<(), Error> {
+        let mut file = self.file.try_clone()?;
+        let batches = self
+            .batches
+            .iter()
+            .map(|batch| (batch.position, batch.header.length, batch.trimmed.clone()))
+            .collect::<Vec<_>>();
+        let mut extended_bytes = std::mem::take(bytes);
+        *bytes = task::spawn_blocking(move || {
+            for (position, length, trimmed) in batches {
+                if let Some(trimmed) = trimmed {
+                    extended_bytes.extend_from_slice(&trimmed);
+                    continue;
+                }
+
+                let batch_start = extended_bytes.len();
+                extended_bytes.resize(batch_start + length as usize, 0);
+                file.seek(SeekFrom::Start(position))?;
+                file.read_exact(&mut extended_bytes[batch_start..])?;
+            }
+            Ok::<_, std::io::Error>(extended_bytes)
+        })
+        .await
+        .map_err(std::io::Error::from)??;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::segments::segment::Segment;
+use crate::utils::timestamp;
+use memmap2::Mmap;
+use sdk::error::Error;
+use std::collections::HashMap;
+use std::fs::File;
+use std::sync::{Arc, Mutex};
+use tracing::trace;
+
+#[derive(Debug, Default)]
+pub struct MappedSegments {
+    maps: Mutex<HashMap<String, MappedSegment>>,
+}
+
+#[derive(Debug)]
+struct MappedSegment {
+    map: Arc<Mmap>,
+    last_read_at: u64,
+}
+
+impl MappedSegments {
+    pub fn get(&self, segment: &Segment) -> Result<Arc<Mmap>, Error> {
+        let now = timestamp::get();
+        let idle_timeout = segment.config.mmap_idle_timeout as u64 * 1_000_000;
+        let mut maps = self.maps.lock().unwrap();
+        maps.retain(|_, mapped_segment| {
+            now.saturating_sub(mapped_segment.last_read_at) < idle_timeout
+        });
+        if let Some(mapped_segment) = maps.get_mut(&segment.log_path) {
+            mapped_segment.last_read_at = now;
+            return Ok(mapped_segment.map.clone());
+        }
+
+        if maps.len() >= segment.config.mmap_max_segments as usize {
+            let least_recently_read = maps
+                .iter()
+                .min_by_key(|(_, mapped_segment)| mapped_segment.last_read_at)
+                .map(|(path, _)| path.clone());
+            if let Some(path) = least_recently_read {
+                trace!("Unmapping the least recently read segment log: {}", path);
+                maps.remove(&path);
+            }
+        }
+
+        let file = File::open(&segment.log_path)?;
+        // SAFETY: the closed segment log is never modified in place, as it's always replaced as a whole by renaming
+        // the new file over it, while the existing map keeps pointing to the previous version until it's removed.
+        let map = Arc::new(unsafe { Mmap::map(&file)? });
+        trace!("Mapped segment log: {}", segment.log_path);
+        maps.insert(
+            segment.log_path.clone(),
+            MappedSegment {
+                map: map.clone(),
+                last_read_at: now,
+            },
+        );
+        Ok(map)
+    }
+
+    pub fn remove(&self, log_path: &str) {
+        if self.maps.lock().unwrap().remove(log_path).is_some() {
+            trace!("Unmapped segment log: {}", log_path);
+        }
+    }
+}
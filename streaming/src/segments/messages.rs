@@ -1,8 +1,12 @@
+use crate::message;
 use crate::message::Message;
 use crate::segments::index::{Index, IndexRange};
-use crate::segments::segment::Segment;
+use crate::segments::segment::{Segment, UnsavedBatch, MAX_REWRITTEN_BATCH_SIZE_BYTES};
+use crate::segments::storage::find_index_range;
 use crate::segments::time_index::TimeIndex;
 use crate::storage::SegmentStorage;
+use sdk::batch::MessageBatch;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
 use std::sync::Arc;
 use tracing::trace;
@@ -25,22 +29,16 @@ impl Segment {
         }
 
         // In case that the partition messages buffer is disabled, we need to check the unsaved messages buffer
-        if self.unsaved_messages.is_none() {
-            return self.load_messages_from_disk(offset, end_offset).await;
-        }
+        let first_offset = match self.get_unsaved_messages().next() {
+            Some(message) => message.offset,
+            None => return self.load_messages_from_disk(offset, end_offset).await,
+        };
 
-        let unsaved_messages = self.unsaved_messages.as_ref().unwrap();
-        if unsaved_messages.is_empty() {
-            return self.load_messages_from_disk(offset, end_offset).await;
-        }
-
-        let first_offset = unsaved_messages[0].offset;
         if end_offset < first_offset {
             return self.load_messages_from_disk(offset, end_offset).await;
         }
 
-        let last_offset = unsaved_messages[unsaved_messages.len() - 1].offset;
-        if end_offset <= last_offset {
+        if offset >= first_offset {
             return Ok(self.load_messages_from_unsaved_buffer(offset, end_offset));
         }
 
@@ -51,16 +49,166 @@ impl Segment {
         Ok(messages)
     }
 
-    fn load_messages_from_unsaved_buffer(&self, offset: u64, end_offset: u64) -> Vec<Arc<Message>> {
-        self.unsaved_messages
-            .as_ref()
-            .unwrap()
+    pub async fn get_message_offsets(&self) -> Result<Vec<(u128, u64)>, Error> {
+        let mut message_offsets = self.storage.segment.load_message_offsets(self).await?;
+        message_offsets.extend(
+            self.get_unsaved_messages()
+                .map(|message| (message.id, message.offset)),
+        );
+        Ok(message_offsets)
+    }
+
+    pub async fn get_all_messages(&self) -> Result<Vec<Arc<Message>>, Error> {
+        self.get_messages(
+            self.start_offset,
+            (self.current_offset - self.start_offset + 1) as u32,
+        )
+        .await
+    }
+
+    pub async fn overwrite_messages(&mut self, messages: &[Arc<Message>]) -> Result<(), Error> {
+        if !self.is_closed {
+            return Err(Error::SegmentNotClosed(
+                self.start_offset,
+                self.partition_id,
+            ));
+        }
+
+        let saved_bytes = self
+            .storage
+            .segment
+            .overwrite_messages(self, messages)
+            .await?;
+        self.current_size_bytes = saved_bytes;
+        self.is_archived = false;
+        self.min_timestamp = messages.first().map_or(0, |message| message.timestamp);
+        self.max_timestamp = messages.last().map_or(0, |message| message.timestamp);
+        self.block_index = None;
+
+        if self.indexes.is_some() {
+            let mut indexes = Vec::new();
+            let mut position = 0;
+            for batch in message::split_batches(messages, MAX_REWRITTEN_BATCH_SIZE_BYTES) {
+                let size_bytes = message::get_batch_size_bytes(batch);
+                if self.should_index(position, size_bytes) {
+                    indexes.push(Index {
+                        relative_offset: (batch[0].offset - self.start_offset) as u32,
+                        position,
+                    });
+                }
+                position += size_bytes as u64;
+            }
+            self.indexes = Some(indexes);
+        }
+
+        if let Some(time_indexes) = self.time_indexes.as_mut() {
+            time_indexes.clear();
+            for message in messages {
+                time_indexes.push(TimeIndex {
+                    relative_offset: (message.offset - self.start_offset) as u32,
+                    timestamp: message.timestamp,
+                });
+            }
+        }
+
+        trace!(
+            "Overwritten segment with start offset: {} for partition with ID: {}, current size: {} bytes.",
+            self.start_offset,
+            self.partition_id,
+            self.current_size_bytes
+        );
+
+        Ok(())
+    }
+
+    pub async fn compress(&mut self, compression: CompressionAlgorithm) -> Result<(), Error> {
+        if compression == CompressionAlgorithm::None || self.is_compressed() || self.is_archived {
+            return Ok(());
+        }
+
+        if !self.is_closed {
+            return Err(Error::SegmentNotClosed(
+                self.start_offset,
+                self.partition_id,
+            ));
+        }
+
+        self.block_index = self
+            .storage
+            .segment
+            .compress_messages(self, compression)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_offset_by_timestamp(&self, timestamp: u64) -> Result<Option<u64>, Error> {
+        if self.is_empty() || timestamp > self.max_timestamp {
+            return Ok(None);
+        }
+
+        let time_index = match &self.time_indexes {
+            Some(time_indexes) => {
+                let position =
+                    time_indexes.partition_point(|time_index| time_index.timestamp < timestamp);
+                time_indexes
+                    .get(position)
+                    .map(|time_index| time_index.relative_offset)
+            }
+            None => self
+                .storage
+                .segment
+                .load_time_index_by_timestamp(self, timestamp)
+                .await?
+                .map(|time_index| time_index.relative_offset),
+        };
+
+        if let Some(relative_offset) = time_index {
+            return Ok(Some(self.start_offset + relative_offset as u64));
+        }
+
+        Ok(self
+            .get_unsaved_messages()
+            .find(|message| message.timestamp >= timestamp)
+            .map(|message| message.offset))
+    }
+
+    fn get_unsaved_messages(&self) -> impl Iterator<Item = &Arc<Message>> {
+        self.unsaved_batches
             .iter()
+            .flatten()
+            .flat_map(|batch| batch.messages.iter())
+    }
+
+    fn load_messages_from_unsaved_buffer(&self, offset: u64, end_offset: u64) -> Vec<Arc<Message>> {
+        self.get_unsaved_messages()
             .filter(|message| message.offset >= offset && message.offset <= end_offset)
             .cloned()
             .collect::<Vec<Arc<Message>>>()
     }
 
+    pub async fn find_log_index_range(
+        &self,
+        mut offset: u64,
+        count: u32,
+    ) -> Result<Option<IndexRange>, Error> {
+        if offset < self.start_offset {
+            offset = self.start_offset;
+        }
+
+        let mut end_offset = offset + (count - 1) as u64;
+        if end_offset > self.current_offset {
+            end_offset = self.current_offset;
+        }
+
+        if let Some(unsaved_message) = self.get_unsaved_messages().next() {
+            if end_offset >= unsaved_message.offset {
+                return Ok(None);
+            }
+        }
+
+        self.find_index_range(offset, end_offset).await
+    }
+
     async fn load_messages_from_disk(
         &self,
         start_offset: u64,
@@ -73,40 +221,44 @@ impl Segment {
             self.current_offset
         );
 
+        match self.find_index_range(start_offset, end_offset).await? {
+            Some(index_range) => self.load_messages_from_segment_file(&index_range).await,
+            None => Ok(EMPTY_MESSAGES),
+        }
+    }
+
+    async fn find_index_range(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Result<Option<IndexRange>, Error> {
         if start_offset > end_offset || end_offset > self.current_offset {
             trace!(
                 "Cannot load messages from disk, invalid offset range: {} - {}.",
                 start_offset,
                 end_offset
             );
-            return Ok(EMPTY_MESSAGES);
+            return Ok(None);
         }
 
         if let Some(indexes) = &self.indexes {
-            let relative_start_offset = start_offset - self.start_offset;
-            let relative_end_offset = end_offset - self.start_offset;
-            let start_index = indexes.get(relative_start_offset as usize);
-            let end_index = indexes.get(1 + relative_end_offset as usize);
-            if start_index.is_some() {
-                let start_position = start_index.unwrap().position;
-                let end_position = match end_index {
-                    Some(index) => index.position,
-                    None => self.current_size_bytes,
-                };
-
-                let index_range = IndexRange {
-                    start: Index {
-                        relative_offset: relative_start_offset as u32,
-                        position: start_position,
-                    },
-                    end: Index {
-                        relative_offset: relative_end_offset as u32,
-                        position: end_position,
-                    },
-                };
-
-                return self.load_messages_from_segment_file(&index_range).await;
+            let relative_start_offset = (start_offset - self.start_offset) as u32;
+            let relative_end_offset = (end_offset - self.start_offset) as u32;
+            let index_range = find_index_range(
+                indexes,
+                relative_start_offset,
+                relative_end_offset,
+                self.current_size_bytes,
+            );
+            if index_range.is_none() {
+                trace!(
+                    "Cannot load messages from disk, index range not found: {} - {}.",
+                    start_offset,
+                    end_offset
+                );
             }
+
+            return Ok(index_range);
         }
 
         let index_range = self
@@ -120,12 +272,9 @@ impl Segment {
                 start_offset,
                 end_offset
             );
-
-            return Ok(EMPTY_MESSAGES);
         }
 
-        self.load_messages_from_segment_file(&index_range.unwrap())
-            .await
+        Ok(index_range)
     }
 
     async fn load_messages_from_segment_file(
@@ -147,33 +296,61 @@ impl Segment {
         Ok(messages)
     }
 
-    pub async fn append_message(&mut self, message: Arc<Message>) -> Result<(), Error> {
+    pub async fn append_messages(
+        &mut self,
+        messages: Vec<Arc<Message>>,
+        batch: Option<MessageBatch>,
+    ) -> Result<(), Error> {
         if self.is_closed {
             return Err(Error::SegmentClosed(self.start_offset, self.partition_id));
         }
 
-        if self.unsaved_messages.is_none() {
-            self.unsaved_messages = Some(Vec::new());
+        if self.unsaved_batches.is_none() {
+            self.unsaved_batches = Some(Vec::new());
         }
 
-        let relative_offset = (message.offset - self.start_offset) as u32;
-        if let Some(indexes) = self.indexes.as_mut() {
-            indexes.push(Index {
-                relative_offset,
-                position: self.current_size_bytes,
-            });
-        }
+        let batches = match batch {
+            Some(batch) => vec![UnsavedBatch {
+                messages,
+                batch: Some(batch),
+            }],
+            None => message::split_batches(&messages, u64::MAX)
+                .into_iter()
+                .map(|messages| UnsavedBatch {
+                    messages: messages.to_vec(),
+                    batch: None,
+                })
+                .collect(),
+        };
+        for batch in batches {
+            let size_bytes = batch.get_size_bytes();
+            let should_index = self.should_index(self.current_size_bytes, size_bytes);
+            if let Some(indexes) = self.indexes.as_mut().filter(|_| should_index) {
+                indexes.push(Index {
+                    relative_offset: (batch.messages[0].offset - self.start_offset) as u32,
+                    position: self.current_size_bytes,
+                });
+            }
 
-        if let Some(time_indexes) = self.time_indexes.as_mut() {
-            time_indexes.push(TimeIndex {
-                relative_offset,
-                timestamp: message.timestamp,
-            });
-        }
+            if self.is_empty() {
+                self.min_timestamp = batch.messages[0].timestamp;
+            }
+
+            for message in &batch.messages {
+                if let Some(time_indexes) = self.time_indexes.as_mut() {
+                    time_indexes.push(TimeIndex {
+                        relative_offset: (message.offset - self.start_offset) as u32,
+                        timestamp: message.timestamp,
+                    });
+                }
+
+                self.max_timestamp = message.timestamp;
+                self.current_offset = message.offset;
+            }
 
-        self.current_size_bytes += message.get_size_bytes(true);
-        self.current_offset = message.offset;
-        self.unsaved_messages.as_mut().unwrap().push(message);
+            self.current_size_bytes += size_bytes as u64;
+            self.unsaved_batches.as_mut().unwrap().push(batch);
+        }
 
         Ok(())
     }
@@ -182,32 +359,27 @@ impl Segment {
         &mut self,
         storage: Arc<dyn SegmentStorage>,
     ) -> Result<(), Error> {
-        if self.unsaved_messages.is_none() {
-            return Ok(());
-        }
-
-        let unsaved_messages = self.unsaved_messages.as_ref().unwrap();
-        if unsaved_messages.is_empty() {
-            return Ok(());
-        }
+        let unsaved_batches = match self.unsaved_batches.as_ref() {
+            Some(unsaved_batches) if !unsaved_batches.is_empty() => unsaved_batches,
+            _ => return Ok(()),
+        };
 
+        let messages_count = unsaved_batches
+            .iter()
+            .map(|batch| batch.messages.len())
+            .sum::<usize>();
         trace!(
             "Saving {} messages on disk in segment with start offset: {} for partition with ID: {}...",
-            unsaved_messages.len(),
+            messages_count,
             self.start_offset,
             self.partition_id
         );
 
-        let saved_bytes = storage.save_messages(&self, unsaved_messages).await?;
-        let current_position = self.current_size_bytes - saved_bytes;
-        storage
-            .save_index(self, current_position, unsaved_messages)
-            .await?;
-        storage.save_time_index(self, unsaved_messages).await?;
+        let saved_bytes = storage.save_batches(self, unsaved_batches).await?;
 
         trace!(
             "Saved {} messages on disk in segment with start offset: {} for partition with ID: {}, total bytes written: {}.",
-            unsaved_messages.len(),
+            messages_count,
             self.start_offset,
             self.partition_id,
             saved_bytes
@@ -216,9 +388,9 @@ impl Segment {
         if self.is_full() {
             self.end_offset = self.current_offset;
             self.is_closed = true;
-            self.unsaved_messages = None;
+            self.unsaved_batches = None;
         } else {
-            self.unsaved_messages.as_mut().unwrap().clear();
+            self.unsaved_batches.as_mut().unwrap().clear();
         }
 
         Ok(())
@@ -1,4 +1,8 @@
+pub mod block_index;
+pub mod checksum_validator;
 pub mod index;
+pub mod log_slice;
+pub mod mapped_segments;
 pub mod messages;
 pub mod persistence;
 pub mod segment;
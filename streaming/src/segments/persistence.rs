@@ -1,3 +1,4 @@
+use crate::remote_store::RemoteSegmentStore;
 use crate::segments::segment::Segment;
 use sdk::error::Error;
 
@@ -10,4 +11,32 @@ impl Segment {
     pub async fn persist(&self) -> Result<(), Error> {
         self.storage.segment.save(self).await
     }
+
+    pub async fn rebuild_indexes(&mut self) -> Result<(), Error> {
+        let storage = self.storage.clone();
+        storage.segment.rebuild_indexes(self).await?;
+        self.persist_messages(storage.segment.clone()).await?;
+        if self.indexes.is_some() {
+            self.indexes = Some(storage.segment.load_all_indexes(self).await?);
+        }
+
+        if self.time_indexes.is_some() {
+            self.time_indexes = Some(storage.segment.load_all_time_indexes(self).await?);
+        }
+
+        Ok(())
+    }
+
+    pub async fn archive(&mut self, remote: &dyn RemoteSegmentStore) -> Result<(), Error> {
+        if !self.is_closed {
+            return Err(Error::SegmentNotClosed(
+                self.start_offset,
+                self.partition_id,
+            ));
+        }
+
+        self.storage.segment.archive(self, remote).await?;
+        self.is_archived = true;
+        Ok(())
+    }
 }
@@ -1,14 +1,27 @@
 use crate::config::SegmentConfig;
+use crate::message;
 use crate::message::Message;
+use crate::segments::block_index::BlockIndex;
+use crate::segments::checksum_validator::ChecksumValidator;
 use crate::segments::index::Index;
 use crate::segments::time_index::TimeIndex;
 use crate::storage::SystemStorage;
+use bytes::Bytes;
+use sdk::batch::{MessageBatch, RECORD_HEADER_SIZE};
+use sdk::error::Error;
 use std::sync::Arc;
 
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
 pub const TIME_INDEX_EXTENSION: &str = "timeindex";
-pub const MAX_SIZE_BYTES: u32 = 1024 * 1024 * 1024;
+pub const BLOCK_INDEX_EXTENSION: &str = "blocks";
+pub const ARCHIVE_EXTENSION: &str = "archived";
+// The relative offsets are stored as u32 and each message takes at least its 28 bytes record header,
+// thus the bigger segment could contain the offsets which can't be indexed.
+pub const MAX_SIZE_BYTES: u64 = (u32::MAX as u64 + 1) * RECORD_HEADER_SIZE as u64;
+// The sparse index points to the batches, and the whole batch is read to find any of its messages,
+// thus the batches written when the segment is rewritten (e.g. by the compaction) are limited in size.
+pub const MAX_REWRITTEN_BATCH_SIZE_BYTES: u64 = 64 * 1024;
 
 #[derive(Debug)]
 pub struct Segment {
@@ -21,15 +34,44 @@ pub struct Segment {
     pub index_path: String,
     pub log_path: String,
     pub time_index_path: String,
-    pub current_size_bytes: u32,
+    pub block_index_path: String,
+    pub archive_path: String,
+    pub current_size_bytes: u64,
+    pub min_timestamp: u64,
+    pub max_timestamp: u64,
     pub is_closed: bool,
-    pub(crate) unsaved_messages: Option<Vec<Arc<Message>>>,
+    pub is_archived: bool,
+    pub(crate) unsaved_batches: Option<Vec<UnsavedBatch>>,
     pub(crate) config: Arc<SegmentConfig>,
     pub(crate) indexes: Option<Vec<Index>>,
     pub(crate) time_indexes: Option<Vec<TimeIndex>>,
+    pub(crate) block_index: Option<BlockIndex>,
+    pub(crate) checksum_validator: Arc<ChecksumValidator>,
     pub(crate) storage: Arc<SystemStorage>,
 }
 
+#[derive(Debug)]
+pub struct UnsavedBatch {
+    pub messages: Vec<Arc<Message>>,
+    pub batch: Option<MessageBatch>,
+}
+
+impl UnsavedBatch {
+    pub fn get_size_bytes(&self) -> u32 {
+        match &self.batch {
+            Some(batch) => batch.get_size_bytes(),
+            None => message::get_batch_size_bytes(&self.messages),
+        }
+    }
+
+    pub fn get_buffers(&self) -> Vec<Bytes> {
+        match &self.batch {
+            Some(batch) => batch.get_buffers(),
+            None => message::create_batch(&self.messages).get_buffers(),
+        }
+    }
+}
+
 impl Segment {
     pub fn create(
         stream_id: u32,
@@ -52,7 +94,11 @@ impl Segment {
             log_path: Self::get_log_path(&path),
             index_path: Self::get_index_path(&path),
             time_index_path: Self::get_time_index_path(&path),
+            block_index_path: Self::get_block_index_path(&path),
+            archive_path: Self::get_archive_path(&path),
             current_size_bytes: 0,
+            min_timestamp: 0,
+            max_timestamp: 0,
             indexes: match config.cache_indexes {
                 true => Some(Vec::new()),
                 false => None,
@@ -61,8 +107,11 @@ impl Segment {
                 true => Some(Vec::new()),
                 false => None,
             },
-            unsaved_messages: None,
+            block_index: None,
+            checksum_validator: Arc::new(ChecksumValidator::default()),
+            unsaved_batches: None,
             is_closed: false,
+            is_archived: false,
             config,
             storage,
         }
@@ -72,6 +121,54 @@ impl Segment {
         self.current_size_bytes >= self.config.size_bytes
     }
 
+    pub fn should_index(&self, position: u64, size_bytes: u32) -> bool {
+        let index_interval_bytes = self.config.index_interval_bytes as u64;
+        if index_interval_bytes == 0 {
+            return true;
+        }
+
+        let next_boundary = position.div_ceil(index_interval_bytes) * index_interval_bytes;
+        next_boundary < position + size_bytes as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.current_size_bytes == 0
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.block_index.is_some()
+    }
+
+    pub async fn is_expired(&self, now: u64, message_expiry: u32) -> Result<bool, Error> {
+        if !self.is_closed {
+            return Ok(false);
+        }
+
+        let last_timestamp = match &self.time_indexes {
+            Some(time_indexes) => time_indexes.last().map(|time_index| time_index.timestamp),
+            None => self
+                .storage
+                .segment
+                .load_last_time_index(self)
+                .await?
+                .map(|time_index| time_index.timestamp),
+        };
+
+        if last_timestamp.is_none() {
+            return Ok(false);
+        }
+
+        let message_expiry = message_expiry as u64 * 1_000_000;
+        Ok(last_timestamp.unwrap() + message_expiry <= now)
+    }
+
+    pub fn get_remote_key(&self) -> String {
+        format!(
+            "{}/{}/{}/{:0>20}.{}",
+            self.stream_id, self.topic_id, self.partition_id, self.start_offset, LOG_EXTENSION
+        )
+    }
+
     fn get_path(partition_path: &str, start_offset: u64) -> String {
         format!("{}/{:0>20}", partition_path, start_offset)
     }
@@ -87,6 +184,14 @@ impl Segment {
     fn get_time_index_path(path: &str) -> String {
         format!("{}.{}", path, TIME_INDEX_EXTENSION)
     }
+
+    fn get_block_index_path(path: &str) -> String {
+        format!("{}.{}", path, BLOCK_INDEX_EXTENSION)
+    }
+
+    fn get_archive_path(path: &str) -> String {
+        format!("{}.{}", path, ARCHIVE_EXTENSION)
+    }
 }
 
 #[cfg(test)]
@@ -128,13 +233,41 @@ mod tests {
         assert_eq!(segment.log_path, log_path);
         assert_eq!(segment.index_path, index_path);
         assert_eq!(segment.time_index_path, time_index_path);
-        assert!(segment.unsaved_messages.is_none());
+        assert!(segment.unsaved_batches.is_none());
         assert!(segment.indexes.is_some());
         assert!(segment.time_indexes.is_some());
+        assert!(!segment.is_compressed());
         assert!(!segment.is_closed);
+        assert!(!segment.is_archived);
         assert!(!segment.is_full());
     }
 
+    #[test]
+    fn should_index_only_messages_reaching_the_next_interval_boundary() {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SegmentConfig {
+            index_interval_bytes: 250,
+            ..SegmentConfig::default()
+        });
+        let segment = Segment::create(1, 2, 3, 0, "/topics/2/3", config, storage);
+
+        let indexed_positions = (0..10)
+            .map(|message| message * 100)
+            .filter(|position| segment.should_index(*position, 100))
+            .collect::<Vec<u64>>();
+
+        assert_eq!(indexed_positions, vec![0, 200, 500, 700]);
+    }
+
+    #[test]
+    fn should_index_every_message_when_interval_is_not_set() {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SegmentConfig::default());
+        let segment = Segment::create(1, 2, 3, 0, "/topics/2/3", config, storage);
+
+        assert!((0..10).all(|message| segment.should_index(message * 100, 100)));
+    }
+
     #[test]
     fn should_not_initialize_indexes_cache_when_disabled() {
         let storage = Arc::new(get_test_system_storage());
@@ -1,32 +1,232 @@
+use crate::message;
 use crate::message::Message;
-use crate::persister::Persister;
+use crate::persister::{FileAppend, Persister};
+use crate::remote_store::RemoteSegmentStore;
 use async_trait::async_trait;
 use bytes::Bytes;
+use sdk::batch::{BatchHeader, MessageBatch, BATCH_HEADER_SIZE};
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
 use std::io::SeekFrom;
+use std::path::Path;
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
+use tokio::fs;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, BufReader};
 use tracing::log::{trace, warn};
 use tracing::{error, info};
 
+use crate::segments::block_index::{Block, BlockIndex};
 use crate::segments::index::{Index, IndexRange};
-use crate::segments::segment::Segment;
+use crate::segments::log_slice::{LogSlice, LogSliceBatch};
+use crate::segments::mapped_segments::MappedSegments;
+use crate::segments::segment::{Segment, UnsavedBatch, MAX_REWRITTEN_BATCH_SIZE_BYTES};
 use crate::segments::time_index::TimeIndex;
 use crate::storage::{SegmentStorage, Storage};
-use crate::utils::{checksum, file};
+use crate::utils::file;
 
 const EMPTY_INDEXES: Vec<Index> = vec![];
 const EMPTY_TIME_INDEXES: Vec<TimeIndex> = vec![];
-const INDEX_SIZE: u32 = 4;
+// Relative offset + Position
+const INDEX_SIZE: u32 = 12;
+// Relative offset + Timestamp
+const TIME_INDEX_SIZE: u32 = 12;
+// Position + Size + Compressed position + Compressed size
+const BLOCK_SIZE: u32 = 24;
+// The uncompressed size of the batches stored in a single compressed block
+const BLOCK_MAX_SIZE_BYTES: u32 = 64 * 1024;
+// The compressed log is saved next to the original one, until the block index is saved
+const COMPRESSED_LOG_EXTENSION: &str = "compressed";
 
 #[derive(Debug)]
 pub struct FileSegmentStorage {
     persister: Arc<dyn Persister>,
+    mapped_segments: MappedSegments,
 }
 
 impl FileSegmentStorage {
     pub fn new(persister: Arc<dyn Persister>) -> Self {
-        Self { persister }
+        Self {
+            persister,
+            mapped_segments: MappedSegments::default(),
+        }
+    }
+
+    async fn overwrite_file(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        let temp_path = format!("{}.tmp", path);
+        self.persister.overwrite(&temp_path, bytes).await?;
+        fs::rename(&temp_path, path).await?;
+        self.persister.close(path).await?;
+        self.mapped_segments.remove(path);
+        Ok(())
+    }
+
+    // The block index is saved only once the compressed log is complete, so it commits the compression.
+    async fn complete_compression(&self, segment: &Segment) -> Result<(), Error> {
+        let compressed_log_path = get_compressed_log_path(segment);
+        if !Path::new(&compressed_log_path).exists() {
+            return Ok(());
+        }
+
+        if !Path::new(&segment.block_index_path).exists() {
+            warn!(
+                "Compression of segment with start offset: {} and partition with ID: {} didn't complete, the compressed log will be deleted.",
+                segment.start_offset, segment.partition_id
+            );
+            fs::remove_file(&compressed_log_path).await?;
+            return Ok(());
+        }
+
+        fs::rename(&compressed_log_path, &segment.log_path).await?;
+        self.persister.close(&segment.log_path).await?;
+        self.mapped_segments.remove(&segment.log_path);
+        Ok(())
+    }
+
+    async fn load_mapped_batches_by_range(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+        on_batch: impl FnMut(u64, MessageBatch) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        if segment.is_archived {
+            restore_archived_log(segment).await?;
+        }
+
+        if index_range.end.position == 0 {
+            return Ok(());
+        }
+
+        let map = self.mapped_segments.get(segment)?;
+        let start_position = index_range.start.position;
+        if start_position >= map.len() as u64 {
+            return Ok(());
+        }
+
+        let mut reader = &map[start_position as usize..];
+        read_batches(
+            &mut reader,
+            start_position,
+            index_range.end.position,
+            on_batch,
+        )
+        .await
+    }
+
+    async fn load_block_index(&self, segment: &Segment) -> Result<Option<BlockIndex>, Error> {
+        if !Path::new(&segment.block_index_path).exists() {
+            return Ok(None);
+        }
+
+        let bytes = fs::read(&segment.block_index_path).await?;
+        if bytes.is_empty() || (bytes.len() - 1) % BLOCK_SIZE as usize != 0 {
+            return Err(Error::CannotReadBlockIndex(
+                segment.block_index_path.clone(),
+            ));
+        }
+
+        let compression = CompressionAlgorithm::from_code(bytes[0]);
+        if compression.is_err() {
+            return Err(Error::CannotReadBlockIndex(
+                segment.block_index_path.clone(),
+            ));
+        }
+
+        let blocks = bytes[1..]
+            .chunks_exact(BLOCK_SIZE as usize)
+            .map(|chunk| Block {
+                position: u64::from_le_bytes(chunk[..8].try_into().unwrap()),
+                size_bytes: u32::from_le_bytes(chunk[8..12].try_into().unwrap()),
+                compressed_position: u64::from_le_bytes(chunk[12..20].try_into().unwrap()),
+                compressed_size_bytes: u32::from_le_bytes(chunk[20..24].try_into().unwrap()),
+            })
+            .collect();
+
+        trace!(
+            "Loaded block index for segment with start offset: {} and partition with ID: {}.",
+            segment.start_offset,
+            segment.partition_id
+        );
+        Ok(Some(BlockIndex {
+            compression: compression.unwrap(),
+            blocks,
+        }))
+    }
+
+    async fn load_archived_size(&self, segment: &Segment) -> Result<Option<u64>, Error> {
+        if !Path::new(&segment.archive_path).exists() {
+            return Ok(None);
+        }
+
+        let bytes = fs::read(&segment.archive_path).await?;
+        if bytes.len() != 8 {
+            return Err(Error::CannotRestoreArchivedSegment(
+                segment.start_offset,
+                segment.partition_id,
+            ));
+        }
+
+        Ok(Some(u64::from_le_bytes(bytes.try_into().unwrap())))
+    }
+
+    async fn has_valid_indexes(&self, segment: &Segment) -> Result<bool, Error> {
+        if !Path::new(&segment.index_path).exists() || !Path::new(&segment.time_index_path).exists()
+        {
+            return Ok(false);
+        }
+
+        // The index might be sparse, while the time index always contains all the messages.
+        let index_size = fs::metadata(&segment.index_path).await?.len();
+        let time_index_size = fs::metadata(&segment.time_index_path).await?.len();
+        if index_size % INDEX_SIZE as u64 != 0
+            || time_index_size % TIME_INDEX_SIZE as u64 != 0
+            || index_size / INDEX_SIZE as u64 > time_index_size / TIME_INDEX_SIZE as u64
+        {
+            return Ok(false);
+        }
+
+        if index_size == 0 {
+            return Ok(time_index_size == 0 && segment.current_size_bytes == 0);
+        }
+
+        let first_index = load_index_at(&segment.index_path, 0).await?;
+        let last_index = load_index_at(&segment.index_path, index_size - INDEX_SIZE as u64).await?;
+        let last_time_index = self.load_last_time_index(segment).await?.unwrap();
+        if first_index.position != 0
+            || last_index.position >= segment.current_size_bytes
+            || last_index.relative_offset > last_time_index.relative_offset
+        {
+            return Ok(false);
+        }
+
+        // The positions in the compressed segment are logical, so the last messages can't be read directly,
+        // while the archived segment would have to be downloaded first.
+        if segment.is_compressed() || segment.is_archived {
+            return Ok(true);
+        }
+
+        // The batches following the last index entry must end exactly with the log, the last one ending with the last time index entry.
+        let mut log_file = file::open(&segment.log_path).await?;
+        log_file.seek(SeekFrom::Start(last_index.position)).await?;
+        let mut bytes =
+            Vec::with_capacity((segment.current_size_bytes - last_index.position) as usize);
+        log_file.read_to_end(&mut bytes).await?;
+        let mut first_offset = None;
+        let mut last_offset = None;
+        let mut position = 0;
+        while position + BATCH_HEADER_SIZE as usize <= bytes.len() {
+            let header = match BatchHeader::from_bytes(&bytes[position..]) {
+                Ok(header) => header,
+                Err(_) => return Ok(false),
+            };
+            first_offset.get_or_insert(header.base_offset);
+            last_offset = Some(header.get_last_offset());
+            position += header.length as usize;
+        }
+
+        Ok(position == bytes.len()
+            && first_offset == Some(segment.start_offset + last_index.relative_offset as u64)
+            && last_offset == Some(segment.start_offset + last_time_index.relative_offset as u64))
     }
 }
 
@@ -41,10 +241,47 @@ impl Storage<Segment> for FileSegmentStorage {
             "Loading segment from disk for start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {} ...",
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
-        let log_file = file::open(&segment.log_path).await?;
-        let file_size = log_file.metadata().await.unwrap().len() as u32;
+        self.complete_compression(segment).await?;
+        // The archived segment keeps the size of its log, which might be no longer available locally.
+        let file_size = match self.load_archived_size(segment).await? {
+            Some(archived_size) => {
+                segment.is_archived = true;
+                archived_size
+            }
+            None => {
+                let log_file = file::open(&segment.log_path).await?;
+                log_file.metadata().await.unwrap().len()
+            }
+        };
         segment.current_size_bytes = file_size;
 
+        if let Some(block_index) = self.load_block_index(segment).await? {
+            // The block index is saved after the compressed log, so it has to cover the whole log.
+            if block_index.get_compressed_size_bytes() != file_size {
+                error!(
+                    "Block index for segment with start offset: {} and partition with ID: {} covers {} bytes, but the log has {} bytes.",
+                    segment.start_offset,
+                    segment.partition_id,
+                    block_index.get_compressed_size_bytes(),
+                    file_size
+                );
+                return Err(Error::CannotReadBlockIndex(
+                    segment.block_index_path.clone(),
+                ));
+            }
+
+            segment.current_size_bytes = block_index.get_size_bytes();
+            segment.block_index = Some(block_index);
+        }
+
+        if !self.has_valid_indexes(segment).await? {
+            warn!(
+                "Indexes for segment with start offset: {} and partition with ID: {} are missing or don't match the log, rebuilding...",
+                segment.start_offset, segment.partition_id
+            );
+            self.rebuild_indexes(segment).await?;
+        }
+
         info!(
             "Segment log file for start offset {}, current offset: {}, and partition with ID: {} for topic with ID: {} and stream with ID: {} has {} bytes of size.",
             segment.start_offset, segment.current_offset, segment.partition_id, segment.topic_id, segment.stream_id, segment.current_size_bytes
@@ -67,6 +304,8 @@ impl Storage<Segment> for FileSegmentStorage {
             if !time_indexes.is_empty() {
                 let last_index = time_indexes.last().unwrap();
                 segment.current_offset = segment.start_offset + last_index.relative_offset as u64;
+                segment.min_timestamp = time_indexes.first().unwrap().timestamp;
+                segment.max_timestamp = last_index.timestamp;
                 segment.time_indexes = Some(time_indexes);
             }
 
@@ -82,6 +321,9 @@ impl Storage<Segment> for FileSegmentStorage {
             let last_timeindex = self.load_last_time_index(segment).await?;
             if let Some(last_index) = last_timeindex {
                 segment.current_offset = segment.start_offset + last_index.relative_offset as u64;
+                let mut file = file::open(&segment.time_index_path).await?;
+                segment.min_timestamp = load_time_index_at(&mut file, 0).await?.timestamp;
+                segment.max_timestamp = last_index.timestamp;
                 info!(
                 "Loaded last time index for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
                 segment.start_offset,
@@ -146,9 +388,18 @@ impl Storage<Segment> for FileSegmentStorage {
             "Deleting segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}...",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
         );
-        self.persister.delete(&segment.log_path).await?;
+        self.mapped_segments.remove(&segment.log_path);
+        if Path::new(&segment.log_path).exists() {
+            self.persister.delete(&segment.log_path).await?;
+        }
         self.persister.delete(&segment.index_path).await?;
         self.persister.delete(&segment.time_index_path).await?;
+        if Path::new(&segment.block_index_path).exists() {
+            self.persister.delete(&segment.block_index_path).await?;
+        }
+        if segment.is_archived {
+            delete_archived_log(segment).await?;
+        }
         info!(
             "Deleted segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
@@ -167,71 +418,606 @@ impl SegmentStorage for FileSegmentStorage {
         let mut messages = Vec::with_capacity(
             1 + (index_range.end.relative_offset - index_range.start.relative_offset) as usize,
         );
-        load_messages_by_range(segment, index_range, |message: Message| {
-            messages.push(Arc::new(message));
+        let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
+        let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
+        let on_batch = |_, batch: MessageBatch| {
+            if !segment
+                .checksum_validator
+                .validate(&batch, segment.partition_id)?
+            {
+                return Ok(());
+            }
+
+            // The sparse index points to the nearest earlier batch, and the batch might contain the messages
+            // outside of the range, so these are skipped.
+            for message in message::get_batch_messages(batch) {
+                if message.offset >= start_offset && message.offset <= end_offset {
+                    messages.push(Arc::new(message));
+                }
+            }
             Ok(())
-        })
-        .await?;
+        };
+        // The closed segments never change, so they can be read from the memory map, unless they're compressed.
+        if segment.config.mmap_reads && segment.is_closed && !segment.is_compressed() {
+            self.load_mapped_batches_by_range(segment, index_range, on_batch)
+                .await?;
+        } else {
+            load_batches_by_range(segment, index_range, on_batch).await?;
+        }
         trace!("Loaded {} messages from disk.", messages.len());
         Ok(messages)
     }
 
-    async fn save_messages(
+    async fn load_log_slice(
         &self,
         segment: &Segment,
-        messages: &[Arc<Message>],
-    ) -> Result<u32, Error> {
-        let messages_size = messages
+        index_range: &IndexRange,
+    ) -> Result<Option<LogSlice>, Error> {
+        // The compressed log doesn't contain the payloads as they are.
+        if segment.is_compressed() {
+            return Ok(None);
+        }
+
+        if segment.is_archived {
+            restore_archived_log(segment).await?;
+        }
+
+        let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
+        let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
+        let mut file = file::open(&segment.log_path).await?;
+        let file_size = file.metadata().await?.len();
+        let end_position = index_range.end.position.min(file_size);
+        let mut batches = Vec::new();
+        let mut position = index_range.start.position;
+        // Only the headers are read, the batches within the range are sent straight from the file.
+        let mut header_bytes = [0; BATCH_HEADER_SIZE as usize];
+        while position + BATCH_HEADER_SIZE as u64 <= end_position {
+            file.seek(SeekFrom::Start(position)).await?;
+            file.read_exact(&mut header_bytes).await?;
+            let header = BatchHeader::from_bytes(&header_bytes)?;
+            let batch_position = position;
+            position += header.length as u64;
+            if position > file_size {
+                break;
+            }
+
+            if header.get_last_offset() < start_offset {
+                continue;
+            }
+
+            if header.base_offset > end_offset {
+                break;
+            }
+
+            if header.base_offset >= start_offset && header.get_last_offset() <= end_offset {
+                batches.push(LogSliceBatch {
+                    header,
+                    position: batch_position,
+                    trimmed: None,
+                });
+                continue;
+            }
+
+            // The batch containing the messages outside of the range is trimmed in memory.
+            let mut bytes = vec![0; header.length as usize];
+            file.seek(SeekFrom::Start(batch_position)).await?;
+            file.read_exact(&mut bytes).await?;
+            let messages =
+                message::get_batch_messages(MessageBatch::from_bytes(&Bytes::from(bytes))?)
+                    .into_iter()
+                    .filter(|message| {
+                        message.offset >= start_offset && message.offset <= end_offset
+                    })
+                    .map(Arc::new)
+                    .collect::<Vec<_>>();
+            for messages in message::split_batches(&messages, u64::MAX) {
+                let batch = message::create_batch(messages);
+                let mut bytes = Vec::with_capacity(batch.get_size_bytes() as usize);
+                batch.extend(&mut bytes);
+                batches.push(LogSliceBatch {
+                    header: batch.header,
+                    position: batch_position,
+                    trimmed: Some(Bytes::from(bytes)),
+                });
+            }
+        }
+
+        trace!(
+            "Found {} batches in the log slice of segment with start offset: {}.",
+            batches.len(),
+            segment.start_offset
+        );
+        Ok(Some(LogSlice {
+            file: file.into_std().await,
+            batches,
+        }))
+    }
+
+    async fn save_batches(
+        &self,
+        segment: &Segment,
+        batches: &[UnsavedBatch],
+    ) -> Result<u64, Error> {
+        let batches_size = batches
             .iter()
-            .map(|message| message.get_size_bytes(true))
-            .sum::<u32>();
+            .map(|batch| batch.get_size_bytes() as u64)
+            .sum::<u64>();
+
+        // The payloads are written as they are, next to their headers, without copying them into a single buffer.
+        let mut log_buffers = Vec::new();
+        let mut index_bytes = Vec::with_capacity(batches.len() * INDEX_SIZE as usize);
+        let mut time_index_bytes = Vec::new();
+        let mut position = segment.current_size_bytes - batches_size;
+        for batch in batches {
+            let size_bytes = batch.get_size_bytes();
+            if segment.should_index(position, size_bytes) {
+                trace!("Persisting index for position: {}", position);
+                let relative_offset = (batch.messages[0].offset - segment.start_offset) as u32;
+                index_bytes.extend(relative_offset.to_le_bytes());
+                index_bytes.extend(position.to_le_bytes());
+            }
+            for message in &batch.messages {
+                let relative_offset = (message.offset - segment.start_offset) as u32;
+                time_index_bytes.extend(relative_offset.to_le_bytes());
+                time_index_bytes.extend(message.timestamp.to_le_bytes());
+            }
+            log_buffers.extend(batch.get_buffers());
+            position += size_bytes as u64;
+        }
+
+        let appends = vec![
+            FileAppend {
+                path: segment.log_path.clone(),
+                buffers: log_buffers,
+            },
+            FileAppend {
+                path: segment.index_path.clone(),
+                buffers: vec![Bytes::from(index_bytes)],
+            },
+            FileAppend {
+                path: segment.time_index_path.clone(),
+                buffers: vec![Bytes::from(time_index_bytes)],
+            },
+        ];
+        if self.persister.append_batch(appends).await.is_err() {
+            return Err(Error::CannotSaveMessagesToSegment);
+        }
+
+        // The full segment is closed and won't be appended anymore.
+        if segment.is_full() {
+            self.persister.close(&segment.log_path).await?;
+            self.persister.close(&segment.index_path).await?;
+            self.persister.close(&segment.time_index_path).await?;
+        }
+
+        Ok(batches_size)
+    }
+
+    async fn overwrite_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u64, Error> {
+        let mut log_bytes = Vec::new();
+        let mut index_bytes = Vec::new();
+        let mut time_index_bytes = Vec::with_capacity(messages.len() * TIME_INDEX_SIZE as usize);
+        for messages in message::split_batches(messages, MAX_REWRITTEN_BATCH_SIZE_BYTES) {
+            let batch = message::create_batch(messages);
+            let position = log_bytes.len() as u64;
+            if segment.should_index(position, batch.get_size_bytes()) {
+                let relative_offset = (batch.header.base_offset - segment.start_offset) as u32;
+                index_bytes.extend(relative_offset.to_le_bytes());
+                index_bytes.extend(position.to_le_bytes());
+            }
+            for message in messages {
+                let relative_offset = (message.offset - segment.start_offset) as u32;
+                time_index_bytes.extend(relative_offset.to_le_bytes());
+                time_index_bytes.extend(message.timestamp.to_le_bytes());
+            }
+            batch.extend(&mut log_bytes);
+        }
+
+        // The compacted log isn't compressed, so the block index is deleted before it replaces the compressed one,
+        // otherwise the stale block index would no longer match the log.
+        if Path::new(&segment.block_index_path).exists() {
+            self.persister.delete(&segment.block_index_path).await?;
+        }
+
+        // Each file is written under the temporary path first and then renamed, so it's never left partially written,
+        // while the indexes which don't match the replaced log are rebuilt when the segment is loaded.
+        if self
+            .overwrite_file(&segment.log_path, &log_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveMessagesToSegment);
+        }
 
-        let mut bytes = Vec::with_capacity(messages_size as usize);
-        for message in messages {
-            message.extend(&mut bytes, true);
+        if self
+            .overwrite_file(&segment.index_path, &index_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveIndexToSegment);
         }
 
+        if self
+            .overwrite_file(&segment.time_index_path, &time_index_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveTimeIndexToSegment);
+        }
+
+        // The new log is kept locally, until the segment is archived again.
+        if segment.is_archived {
+            delete_archived_log(segment).await?;
+        }
+
+        info!(
+            "Overwritten segment with start offset: {} for partition with ID: {} with {} messages.",
+            segment.start_offset,
+            segment.partition_id,
+            messages.len()
+        );
+
+        Ok(log_bytes.len() as u64)
+    }
+
+    async fn compress_messages(
+        &self,
+        segment: &Segment,
+        compression: CompressionAlgorithm,
+    ) -> Result<Option<BlockIndex>, Error> {
+        let log_bytes = fs::read(&segment.log_path).await?;
+        let mut blocks = Vec::new();
+        let mut compressed_log_bytes = Vec::new();
+        let mut block_position = 0;
+        let mut position = 0;
+        while position < log_bytes.len() {
+            if position + BATCH_HEADER_SIZE as usize > log_bytes.len() {
+                return Err(Error::InvalidSegmentSize(log_bytes.len() as u64));
+            }
+
+            let header = BatchHeader::from_bytes(&log_bytes[position..])?;
+            position += header.length as usize;
+            if position > log_bytes.len() {
+                return Err(Error::InvalidSegmentSize(log_bytes.len() as u64));
+            }
+
+            // The blocks always contain the whole batches, so that each one can be decompressed on its own.
+            if position - block_position < BLOCK_MAX_SIZE_BYTES as usize
+                && position < log_bytes.len()
+            {
+                continue;
+            }
+
+            let compressed_bytes = compression.compress(&log_bytes[block_position..position])?;
+            blocks.push(Block {
+                position: block_position as u64,
+                size_bytes: (position - block_position) as u32,
+                compressed_position: compressed_log_bytes.len() as u64,
+                compressed_size_bytes: compressed_bytes.len() as u32,
+            });
+            compressed_log_bytes.extend(compressed_bytes);
+            block_position = position;
+        }
+
+        if compressed_log_bytes.len() >= log_bytes.len() {
+            info!(
+                "Compression: {} doesn't reduce the size of segment with start offset: {} and partition with ID: {}, the segment will remain uncompressed.",
+                compression, segment.start_offset, segment.partition_id
+            );
+            return Ok(None);
+        }
+
+        let mut block_index_bytes = Vec::with_capacity(1 + blocks.len() * BLOCK_SIZE as usize);
+        block_index_bytes.extend(compression.as_code().to_le_bytes());
+        for block in blocks.iter() {
+            block_index_bytes.extend(block.position.to_le_bytes());
+            block_index_bytes.extend(block.size_bytes.to_le_bytes());
+            block_index_bytes.extend(block.compressed_position.to_le_bytes());
+            block_index_bytes.extend(block.compressed_size_bytes.to_le_bytes());
+        }
+
+        // The compressed log is saved first and the block index last, as it commits the compression,
+        // thus the interrupted compression is either completed or discarded when the segment is loaded.
         if self
             .persister
-            .append(&segment.log_path, &bytes)
+            .overwrite(&get_compressed_log_path(segment), &compressed_log_bytes)
             .await
             .is_err()
         {
             return Err(Error::CannotSaveMessagesToSegment);
         }
 
-        Ok(messages_size)
+        if self
+            .overwrite_file(&segment.block_index_path, &block_index_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveBlockIndexToSegment);
+        }
+
+        if self.complete_compression(segment).await.is_err() {
+            return Err(Error::CannotSaveMessagesToSegment);
+        }
+
+        info!(
+            "Compressed segment with start offset: {} for partition with ID: {} using: {}, size: {} -> {} bytes.",
+            segment.start_offset,
+            segment.partition_id,
+            compression,
+            log_bytes.len(),
+            compressed_log_bytes.len()
+        );
+
+        Ok(Some(BlockIndex {
+            compression,
+            blocks,
+        }))
+    }
+
+    async fn recover(&self, segment: &Segment) -> Result<(), Error> {
+        // The compressed segment is always closed and replaced as a whole, thus it can't contain a partial write,
+        // and its log is verified against the block index when the segment is loaded.
+        if Path::new(&segment.block_index_path).exists() {
+            return Ok(());
+        }
+
+        info!(
+            "Recovering segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        let log_bytes = Bytes::from(fs::read(&segment.log_path).await?);
+        let mut index_bytes = Vec::new();
+        let mut time_index_bytes = Vec::new();
+        let mut last_offset = None;
+        let mut position = 0;
+        while position + BATCH_HEADER_SIZE as usize <= log_bytes.len() {
+            let batch = match MessageBatch::from_bytes(&log_bytes.slice(position..)) {
+                Ok(batch) if batch.validate().is_ok() => batch,
+                _ => break,
+            };
+
+            let header = batch.header;
+            if header.base_offset < segment.start_offset
+                || matches!(last_offset, Some(last) if header.base_offset <= last)
+            {
+                break;
+            }
+
+            if segment.should_index(position as u64, header.length) {
+                let relative_offset = (header.base_offset - segment.start_offset) as u32;
+                index_bytes.extend(relative_offset.to_le_bytes());
+                index_bytes.extend((position as u64).to_le_bytes());
+            }
+            for record in &batch.records {
+                let offset = header.base_offset + record.offset_delta as u64;
+                let timestamp = header.first_timestamp + record.timestamp_delta as u64;
+                time_index_bytes.extend(((offset - segment.start_offset) as u32).to_le_bytes());
+                time_index_bytes.extend(timestamp.to_le_bytes());
+            }
+            last_offset = Some(header.get_last_offset());
+            position += header.length as usize;
+        }
+
+        if position < log_bytes.len() {
+            warn!(
+                "Segment with start offset: {} for partition with ID: {} contains an incomplete or corrupted batch after offset: {:?}, truncating {} byte(s) at position: {}.",
+                segment.start_offset,
+                segment.partition_id,
+                last_offset,
+                log_bytes.len() - position,
+                position
+            );
+            let log_file = OpenOptions::new()
+                .write(true)
+                .open(&segment.log_path)
+                .await?;
+            log_file.set_len(position as u64).await?;
+            log_file.sync_all().await?;
+        }
+
+        let index_size = fs::metadata(&segment.index_path)
+            .await
+            .map_or(0, |metadata| metadata.len() as usize);
+        let time_index_size = fs::metadata(&segment.time_index_path)
+            .await
+            .map_or(0, |metadata| metadata.len() as usize);
+        if index_size != index_bytes.len() || time_index_size != time_index_bytes.len() {
+            warn!(
+                "Indexes for segment with start offset: {} for partition with ID: {} don't match the log ({} and {} bytes, expected {} and {} bytes), rebuilding...",
+                segment.start_offset,
+                segment.partition_id,
+                index_size,
+                time_index_size,
+                index_bytes.len(),
+                time_index_bytes.len()
+            );
+            if self
+                .overwrite_file(&segment.index_path, &index_bytes)
+                .await
+                .is_err()
+            {
+                return Err(Error::CannotSaveIndexToSegment);
+            }
+
+            if self
+                .overwrite_file(&segment.time_index_path, &time_index_bytes)
+                .await
+                .is_err()
+            {
+                return Err(Error::CannotSaveTimeIndexToSegment);
+            }
+        }
+
+        info!(
+            "Recovered segment with start offset: {} for partition with ID: {}, last offset: {:?}.",
+            segment.start_offset, segment.partition_id, last_offset
+        );
+        Ok(())
+    }
+
+    async fn rebuild_indexes(&self, segment: &Segment) -> Result<(), Error> {
+        info!(
+            "Rebuilding indexes for segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        let mut index_bytes = Vec::new();
+        let mut time_index_bytes = Vec::new();
+        load_batches_by_range(
+            segment,
+            &IndexRange::max_range(),
+            |position, batch: MessageBatch| {
+                if segment.should_index(position, batch.get_size_bytes()) {
+                    let relative_offset = (batch.header.base_offset - segment.start_offset) as u32;
+                    index_bytes.extend(relative_offset.to_le_bytes());
+                    index_bytes.extend(position.to_le_bytes());
+                }
+                for message in message::get_batch_messages(batch) {
+                    let relative_offset = (message.offset - segment.start_offset) as u32;
+                    time_index_bytes.extend(relative_offset.to_le_bytes());
+                    time_index_bytes.extend(message.timestamp.to_le_bytes());
+                }
+                Ok(())
+            },
+        )
+        .await?;
+
+        if self
+            .overwrite_file(&segment.index_path, &index_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveIndexToSegment);
+        }
+
+        if self
+            .overwrite_file(&segment.time_index_path, &time_index_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveTimeIndexToSegment);
+        }
+
+        info!(
+            "Rebuilt {} indexes for segment with start offset: {} for partition with ID: {}.",
+            index_bytes.len() / INDEX_SIZE as usize,
+            segment.start_offset,
+            segment.partition_id
+        );
+        Ok(())
+    }
+
+    async fn archive(
+        &self,
+        segment: &Segment,
+        remote: &dyn RemoteSegmentStore,
+    ) -> Result<(), Error> {
+        // The marker is saved only after the upload, and the local log is deleted afterwards, so the archiving can be
+        // safely repeated, e.g. to remove the local copy of the archived log, which was downloaded for reading.
+        if !segment.is_archived {
+            info!(
+                "Archiving segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+                segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+            );
+            let log_size = fs::metadata(&segment.log_path).await?.len();
+            if remote
+                .upload(&segment.get_remote_key(), &segment.log_path)
+                .await
+                .is_err()
+            {
+                return Err(Error::CannotArchiveSegment(
+                    segment.start_offset,
+                    segment.partition_id,
+                ));
+            }
+
+            if self
+                .persister
+                .overwrite(&segment.archive_path, &log_size.to_le_bytes())
+                .await
+                .is_err()
+            {
+                return Err(Error::CannotArchiveSegment(
+                    segment.start_offset,
+                    segment.partition_id,
+                ));
+            }
+        }
+
+        self.mapped_segments.remove(&segment.log_path);
+        if Path::new(&segment.log_path).exists() {
+            self.persister.delete(&segment.log_path).await?;
+        }
+
+        info!(
+            "Archived segment with start offset: {} for partition with ID: {}.",
+            segment.start_offset, segment.partition_id
+        );
+        Ok(())
     }
 
-    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error> {
+    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<(u128, u64)>, Error> {
         let mut message_ids = Vec::new();
-        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
-            message_ids.push(message.id);
-            Ok(())
-        })
+        load_batches_by_range(
+            segment,
+            &IndexRange::max_range(),
+            |_, batch: MessageBatch| {
+                let first_timestamp = batch.header.first_timestamp;
+                message_ids.extend(
+                    batch
+                        .records
+                        .iter()
+                        .map(|record| (record.id, first_timestamp + record.timestamp_delta as u64)),
+                );
+                Ok(())
+            },
+        )
         .await?;
         trace!("Loaded {} message IDs from disk.", message_ids.len());
         Ok(message_ids)
     }
 
+    // The batches are read one by one, and only the IDs and the offsets of their messages are kept.
+    async fn load_message_offsets(&self, segment: &Segment) -> Result<Vec<(u128, u64)>, Error> {
+        let mut message_offsets = Vec::new();
+        load_batches_by_range(
+            segment,
+            &IndexRange::max_range(),
+            |_, batch: MessageBatch| {
+                let base_offset = batch.header.base_offset;
+                message_offsets.extend(
+                    batch
+                        .records
+                        .iter()
+                        .map(|record| (record.id, base_offset + record.offset_delta as u64)),
+                );
+                Ok(())
+            },
+        )
+        .await?;
+        trace!(
+            "Loaded {} message offsets from disk.",
+            message_offsets.len()
+        );
+        Ok(message_offsets)
+    }
+
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error> {
-        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
-            let calculated_checksum = checksum::get(&message.payload);
-            trace!(
-                "Loaded message for offset: {}, checksum: {}, expected: {}",
-                message.offset,
-                calculated_checksum,
-                message.checksum
-            );
-            if calculated_checksum != message.checksum {
-                return Err(Error::InvalidMessageChecksum(
-                    calculated_checksum,
-                    message.checksum,
-                    message.offset,
-                ));
-            }
-            Ok(())
-        })
+        load_batches_by_range(
+            segment,
+            &IndexRange::max_range(),
+            |_, batch: MessageBatch| {
+                trace!(
+                    "Loaded batch for base offset: {}, checksum: {}",
+                    batch.header.base_offset,
+                    batch.header.crc
+                );
+                batch.validate()
+            },
+        )
         .await?;
         Ok(())
     }
@@ -245,11 +1031,20 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(EMPTY_INDEXES);
         }
 
-        let indexes_count = file_size / 4;
+        let indexes_count = file_size / INDEX_SIZE as usize;
         let mut indexes = Vec::with_capacity(indexes_count);
         let mut reader = BufReader::new(file);
         for offset in 0..indexes_count {
-            let position = reader.read_u32_le().await;
+            let relative_offset = reader.read_u32_le().await;
+            if relative_offset.is_err() {
+                error!(
+                    "Cannot read relative offset from index file for offset: {}.",
+                    offset
+                );
+                break;
+            }
+
+            let position = reader.read_u64_le().await;
             if position.is_err() {
                 error!(
                     "Cannot read position from index file for offset: {}.",
@@ -259,7 +1054,7 @@ impl SegmentStorage for FileSegmentStorage {
             }
 
             indexes.push(Index {
-                relative_offset: offset as u32,
+                relative_offset: relative_offset.unwrap(),
                 position: position.unwrap(),
             });
         }
@@ -281,7 +1076,7 @@ impl SegmentStorage for FileSegmentStorage {
         &self,
         segment: &Segment,
         segment_start_offset: u64,
-        mut index_start_offset: u64,
+        index_start_offset: u64,
         index_end_offset: u64,
     ) -> Result<Option<IndexRange>, Error> {
         trace!(
@@ -299,94 +1094,48 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(None);
         }
 
-        let mut file = file::open(&segment.index_path).await?;
-        let file_length = file.metadata().await?.len() as u32;
-        if file_length == 0 {
-            trace!("Index file is empty.");
+        if index_end_offset < segment_start_offset {
+            trace!(
+                "Index end offset: {} is lower than segment start offset: {}.",
+                index_end_offset,
+                segment_start_offset
+            );
             return Ok(None);
         }
 
-        trace!("Index file length: {}.", file_length);
-        if index_start_offset < segment_start_offset {
-            index_start_offset = segment_start_offset - 1;
-        }
-
-        let relative_start_offset = (index_start_offset - segment_start_offset) as u32;
-        let relative_end_offset = (index_end_offset - segment_start_offset) as u32;
-        let start_seek_position = relative_start_offset * INDEX_SIZE;
-        let mut end_seek_position = relative_end_offset * INDEX_SIZE;
-        if end_seek_position >= file_length {
-            end_seek_position = file_length - INDEX_SIZE;
-        }
-
-        if start_seek_position >= end_seek_position {
-            trace!(
-                "Start seek position: {} is greater than or equal to end seek position: {}.",
-                start_seek_position,
-                end_seek_position
-            );
+        let indexes = self.load_all_indexes(segment).await?;
+        if indexes.is_empty() {
+            trace!("Index file is empty.");
             return Ok(None);
         }
 
-        trace!(
-            "Seeking to index range: {}...{}, position range: {}...{}",
+        let relative_start_offset = index_start_offset.saturating_sub(segment_start_offset) as u32;
+        let relative_end_offset = (index_end_offset - segment_start_offset) as u32;
+        let index_range = find_index_range(
+            &indexes,
             relative_start_offset,
             relative_end_offset,
-            start_seek_position,
-            end_seek_position
+            segment.current_size_bytes,
         );
-        file.seek(SeekFrom::Start(start_seek_position as u64))
-            .await?;
-        let start_position = file.read_u32_le().await?;
-        file.seek(SeekFrom::Start(end_seek_position as u64)).await?;
-        let mut end_position = file.read_u32_le().await?;
-        if end_position == 0 {
-            end_position = file_length;
+        if index_range.is_none() {
+            trace!(
+                "Index range: {}...{} was not found.",
+                relative_start_offset,
+                relative_end_offset
+            );
+            return Ok(None);
         }
 
+        let index_range = index_range.unwrap();
         trace!(
             "Loaded index range: {}...{}, position range: {}...{}",
-            relative_start_offset,
-            relative_end_offset,
-            start_position,
-            end_position
+            index_range.start.relative_offset,
+            index_range.end.relative_offset,
+            index_range.start.position,
+            index_range.end.position
         );
 
-        Ok(Some(IndexRange {
-            start: Index {
-                relative_offset: relative_start_offset,
-                position: start_position,
-            },
-            end: Index {
-                relative_offset: relative_end_offset,
-                position: end_position,
-            },
-        }))
-    }
-
-    async fn save_index(
-        &self,
-        segment: &Segment,
-        mut current_position: u32,
-        messages: &[Arc<Message>],
-    ) -> Result<(), Error> {
-        let mut bytes = Vec::with_capacity(messages.len() * 4);
-        for message in messages {
-            trace!("Persisting index for position: {}", current_position);
-            bytes.extend(current_position.to_le_bytes());
-            current_position += message.get_size_bytes(true);
-        }
-
-        if self
-            .persister
-            .append(&segment.index_path, &bytes)
-            .await
-            .is_err()
-        {
-            return Err(Error::CannotSaveIndexToSegment);
-        }
-
-        Ok(())
+        Ok(Some(index_range))
     }
 
     async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, Error> {
@@ -398,10 +1147,19 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(EMPTY_TIME_INDEXES);
         }
 
-        let indexes_count = file_size / 8;
+        let indexes_count = file_size / TIME_INDEX_SIZE as usize;
         let mut indexes = Vec::with_capacity(indexes_count);
         let mut reader = BufReader::new(file);
         for offset in 0..indexes_count {
+            let relative_offset = reader.read_u32_le().await;
+            if relative_offset.is_err() {
+                error!(
+                    "Cannot read relative offset from time index file for offset: {}.",
+                    offset
+                );
+                break;
+            }
+
             let timestamp = reader.read_u64_le().await;
             if timestamp.is_err() {
                 error!(
@@ -412,7 +1170,7 @@ impl SegmentStorage for FileSegmentStorage {
             }
 
             indexes.push(TimeIndex {
-                relative_offset: offset as u32,
+                relative_offset: relative_offset.unwrap(),
                 timestamp: timestamp.unwrap(),
             });
         }
@@ -439,13 +1197,13 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(None);
         }
 
-        let indexes_count = file_size / 8;
-        let last_index_position = file_size - 8;
+        let last_index_position = file_size - TIME_INDEX_SIZE as usize;
         file.seek(SeekFrom::Start(last_index_position as u64))
             .await?;
+        let relative_offset = file.read_u32_le().await?;
         let timestamp = file.read_u64_le().await?;
         let index = TimeIndex {
-            relative_offset: indexes_count as u32 - 1,
+            relative_offset,
             timestamp,
         };
 
@@ -453,34 +1211,124 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(Some(index))
     }
 
-    async fn save_time_index(
+    async fn load_time_index_by_timestamp(
         &self,
         segment: &Segment,
-        messages: &[Arc<Message>],
-    ) -> Result<(), Error> {
-        let mut bytes = Vec::with_capacity(messages.len() * 8);
-        for message in messages {
-            bytes.extend(message.timestamp.to_le_bytes());
+        timestamp: u64,
+    ) -> Result<Option<TimeIndex>, Error> {
+        trace!(
+            "Loading time index for timestamp: {} from file...",
+            timestamp
+        );
+        let mut file = file::open(&segment.time_index_path).await?;
+        let file_size = file.metadata().await?.len();
+        let indexes_count = file_size / TIME_INDEX_SIZE as u64;
+        // The timestamps are increasing, so only the entries halving the searched range are read.
+        let mut low = 0;
+        let mut high = indexes_count;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let time_index = load_time_index_at(&mut file, middle * TIME_INDEX_SIZE as u64).await?;
+            if time_index.timestamp < timestamp {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
         }
 
-        if self
-            .persister
-            .append(&segment.time_index_path, &bytes)
-            .await
-            .is_err()
-        {
-            return Err(Error::CannotSaveTimeIndexToSegment);
+        if low == indexes_count {
+            trace!("Time index for timestamp: {} was not found.", timestamp);
+            return Ok(None);
         }
 
+        let index = load_time_index_at(&mut file, low * TIME_INDEX_SIZE as u64).await?;
+        trace!("Loaded time index from file: {:?}", index);
+        Ok(Some(index))
+    }
+
+    async fn sync(&self) -> Result<(), Error> {
+        self.persister.sync().await
+    }
+
+    async fn close(&self, segment: &Segment) -> Result<(), Error> {
+        self.mapped_segments.remove(&segment.log_path);
         Ok(())
     }
 }
 
-async fn load_messages_by_range(
+fn get_compressed_log_path(segment: &Segment) -> String {
+    format!("{}.{}", segment.log_path, COMPRESSED_LOG_EXTENSION)
+}
+
+async fn restore_archived_log(segment: &Segment) -> Result<(), Error> {
+    if Path::new(&segment.log_path).exists() {
+        return Ok(());
+    }
+
+    info!(
+        "Restoring archived segment with start offset: {} for partition with ID: {}...",
+        segment.start_offset, segment.partition_id
+    );
+    let remote = segment.storage.remote.as_ref();
+    if remote.is_none()
+        || remote
+            .unwrap()
+            .download(&segment.get_remote_key(), &segment.log_path)
+            .await
+            .is_err()
+    {
+        return Err(Error::CannotRestoreArchivedSegment(
+            segment.start_offset,
+            segment.partition_id,
+        ));
+    }
+
+    Ok(())
+}
+
+async fn delete_archived_log(segment: &Segment) -> Result<(), Error> {
+    if let Some(remote) = segment.storage.remote.as_ref() {
+        remote.delete(&segment.get_remote_key()).await?;
+    }
+
+    fs::remove_file(&segment.archive_path).await?;
+    Ok(())
+}
+
+async fn load_index_at(path: &str, position: u64) -> Result<Index, Error> {
+    let mut file = file::open(path).await?;
+    file.seek(SeekFrom::Start(position)).await?;
+    let relative_offset = file.read_u32_le().await?;
+    let position = file.read_u64_le().await?;
+    Ok(Index {
+        relative_offset,
+        position,
+    })
+}
+
+async fn load_time_index_at(file: &mut File, position: u64) -> Result<TimeIndex, Error> {
+    file.seek(SeekFrom::Start(position)).await?;
+    let relative_offset = file.read_u32_le().await?;
+    let timestamp = file.read_u64_le().await?;
+    Ok(TimeIndex {
+        relative_offset,
+        timestamp,
+    })
+}
+
+async fn load_batches_by_range(
     segment: &Segment,
     index_range: &IndexRange,
-    mut on_message: impl FnMut(Message) -> Result<(), Error>,
+    on_batch: impl FnMut(u64, MessageBatch) -> Result<(), Error>,
 ) -> Result<(), Error> {
+    if segment.is_archived {
+        restore_archived_log(segment).await?;
+    }
+
+    if let Some(block_index) = &segment.block_index {
+        return load_compressed_batches_by_range(segment, block_index, index_range, on_batch).await;
+    }
+
     let file = file::open(&segment.log_path).await?;
     let file_size = file.metadata().await?.len();
     if file_size == 0 {
@@ -493,51 +1341,112 @@ async fn load_messages_by_range(
 
     let mut reader = BufReader::new(file);
     reader
-        .seek(SeekFrom::Start(index_range.start.position as u64))
+        .seek(SeekFrom::Start(index_range.start.position))
         .await?;
+    read_batches(
+        &mut reader,
+        index_range.start.position,
+        index_range.end.position,
+        on_batch,
+    )
+    .await
+}
 
-    let mut read_messages = 0;
-    let messages_count =
-        (1 + index_range.end.relative_offset - index_range.start.relative_offset) as usize;
-
-    while read_messages < messages_count {
-        let offset = reader.read_u64_le().await;
-        if offset.is_err() {
-            break;
-        }
-
-        let timestamp = reader.read_u64_le().await;
-        if timestamp.is_err() {
-            return Err(Error::CannotReadMessageTimestamp);
-        }
+async fn load_compressed_batches_by_range(
+    segment: &Segment,
+    block_index: &BlockIndex,
+    index_range: &IndexRange,
+    on_batch: impl FnMut(u64, MessageBatch) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let blocks = block_index.get_blocks(index_range.start.position, index_range.end.position);
+    if blocks.is_empty() {
+        return Ok(());
+    }
 
-        let id = reader.read_u128_le().await;
-        if id.is_err() {
-            return Err(Error::CannotReadMessageId);
-        }
+    let mut file = file::open(&segment.log_path).await?;
+    let mut bytes = Vec::new();
+    for block in blocks {
+        let mut compressed_bytes = vec![0; block.compressed_size_bytes as usize];
+        file.seek(SeekFrom::Start(block.compressed_position))
+            .await?;
+        file.read_exact(&mut compressed_bytes).await?;
+        bytes.extend(block_index.compression.decompress(&compressed_bytes)?);
+    }
 
-        let checksum = reader.read_u32_le().await;
-        if checksum.is_err() {
-            return Err(Error::CannotReadMessageChecksum);
-        }
+    let start_position = index_range.start.position.max(blocks[0].position);
+    let mut reader = &bytes[(start_position - blocks[0].position) as usize..];
+    read_batches(
+        &mut reader,
+        start_position,
+        index_range.end.position,
+        on_batch,
+    )
+    .await
+}
 
-        let length = reader.read_u32_le().await;
-        if length.is_err() {
-            return Err(Error::CannotReadMessageLength);
+async fn read_batches(
+    reader: &mut (impl AsyncRead + Unpin),
+    start_position: u64,
+    end_position: u64,
+    mut on_batch: impl FnMut(u64, MessageBatch) -> Result<(), Error>,
+) -> Result<(), Error> {
+    // The offsets might not be contiguous (e.g. in the compacted segments), thus the batches are read until the end position is reached.
+    let mut position = start_position;
+    let mut header_bytes = [0; BATCH_HEADER_SIZE as usize];
+    while position < end_position {
+        if reader.read_exact(&mut header_bytes).await.is_err() {
+            break;
         }
 
-        let mut payload = vec![0; length.unwrap() as usize];
-        if reader.read_exact(&mut payload).await.is_err() {
+        let header = BatchHeader::from_bytes(&header_bytes)?;
+        let mut bytes = Vec::with_capacity(header.length as usize);
+        bytes.extend(header_bytes);
+        bytes.resize(header.length as usize, 0);
+        if reader
+            .read_exact(&mut bytes[BATCH_HEADER_SIZE as usize..])
+            .await
+            .is_err()
+        {
             return Err(Error::CannotReadMessagePayload);
         }
 
-        let offset = offset.unwrap();
-        let timestamp = timestamp.unwrap();
-        let id = id.unwrap();
-        let checksum = checksum.unwrap();
-        let message = Message::create(offset, timestamp, id, Bytes::from(payload), checksum);
-        read_messages += 1;
-        on_message(message)?;
+        // The payloads of the decoded messages point to the batch, which is read with a single allocation.
+        let batch = MessageBatch::from_bytes(&Bytes::from(bytes))?;
+        on_batch(position, batch)?;
+        position += header.length as u64;
     }
     Ok(())
 }
+
+pub(crate) fn find_index_range(
+    indexes: &[Index],
+    relative_start_offset: u32,
+    relative_end_offset: u32,
+    segment_size_bytes: u64,
+) -> Option<IndexRange> {
+    let end_index = indexes.partition_point(|index| index.relative_offset <= relative_end_offset);
+    if end_index == 0 || relative_start_offset > relative_end_offset {
+        return None;
+    }
+
+    // The index might be sparse, thus the reading starts from the nearest entry preceding the start offset.
+    let start_index = indexes
+        .partition_point(|index| index.relative_offset <= relative_start_offset)
+        .max(1)
+        - 1;
+    let end_position = match indexes.get(end_index) {
+        Some(index) => index.position,
+        None => segment_size_bytes,
+    };
+
+    Some(IndexRange {
+        start: Index {
+            relative_offset: relative_start_offset,
+            position: indexes[start_index].position,
+        },
+        end: Index {
+            relative_offset: relative_end_offset,
+            position: end_position,
+        },
+    })
+}
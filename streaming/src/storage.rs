@@ -1,9 +1,13 @@
+use crate::cache::MessagesCache;
 use crate::message::Message;
 use crate::partitions::partition::{ConsumerOffset, Partition};
 use crate::partitions::storage::FilePartitionStorage;
 use crate::persister::{FilePersister, Persister};
+use crate::remote_store::RemoteSegmentStore;
+use crate::segments::block_index::BlockIndex;
 use crate::segments::index::{Index, IndexRange};
-use crate::segments::segment::Segment;
+use crate::segments::log_slice::LogSlice;
+use crate::segments::segment::{Segment, UnsavedBatch};
 use crate::segments::storage::FileSegmentStorage;
 use crate::segments::time_index::TimeIndex;
 use crate::streams::storage::FileStreamStorage;
@@ -12,6 +16,7 @@ use crate::topics::consumer_group::ConsumerGroup;
 use crate::topics::storage::FileTopicStorage;
 use crate::topics::topic::Topic;
 use async_trait::async_trait;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
@@ -24,10 +29,13 @@ pub trait Storage<T>: Sync + Send {
 }
 
 #[async_trait]
-pub trait StreamStorage: Storage<Stream> {}
+pub trait StreamStorage: Storage<Stream> {
+    async fn save_info(&self, stream: &Stream) -> Result<(), Error>;
+}
 
 #[async_trait]
 pub trait TopicStorage: Storage<Topic> {
+    async fn save_info(&self, topic: &Topic) -> Result<(), Error>;
     async fn save_consumer_group(
         &self,
         topic: &Topic,
@@ -44,6 +52,9 @@ pub trait TopicStorage: Storage<Topic> {
 #[async_trait]
 pub trait PartitionStorage: Storage<Partition> {
     async fn save_offset(&self, offset: &ConsumerOffset) -> Result<(), Error>;
+    async fn delete_offset(&self, offset: &ConsumerOffset) -> Result<(), Error>;
+    async fn save_log_start_offset(&self, partition: &Partition) -> Result<(), Error>;
+    async fn save_draining(&self, partition: &Partition) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -53,12 +64,32 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         index_range: &IndexRange,
     ) -> Result<Vec<Arc<Message>>, Error>;
-    async fn save_messages(
+    async fn load_log_slice(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+    ) -> Result<Option<LogSlice>, Error>;
+    async fn save_batches(&self, segment: &Segment, batches: &[UnsavedBatch])
+        -> Result<u64, Error>;
+    async fn overwrite_messages(
         &self,
         segment: &Segment,
         messages: &[Arc<Message>],
-    ) -> Result<u32, Error>;
-    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error>;
+    ) -> Result<u64, Error>;
+    async fn compress_messages(
+        &self,
+        segment: &Segment,
+        compression: CompressionAlgorithm,
+    ) -> Result<Option<BlockIndex>, Error>;
+    async fn recover(&self, segment: &Segment) -> Result<(), Error>;
+    async fn rebuild_indexes(&self, segment: &Segment) -> Result<(), Error>;
+    async fn archive(
+        &self,
+        segment: &Segment,
+        remote: &dyn RemoteSegmentStore,
+    ) -> Result<(), Error>;
+    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<(u128, u64)>, Error>;
+    async fn load_message_offsets(&self, segment: &Segment) -> Result<Vec<(u128, u64)>, Error>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error>;
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error>;
     async fn load_index_range(
@@ -68,19 +99,15 @@ pub trait SegmentStorage: Storage<Segment> {
         index_start_offset: u64,
         index_end_offset: u64,
     ) -> Result<Option<IndexRange>, Error>;
-    async fn save_index(
-        &self,
-        segment: &Segment,
-        current_position: u32,
-        messages: &[Arc<Message>],
-    ) -> Result<(), Error>;
     async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, Error>;
     async fn load_last_time_index(&self, segment: &Segment) -> Result<Option<TimeIndex>, Error>;
-    async fn save_time_index(
+    async fn load_time_index_by_timestamp(
         &self,
         segment: &Segment,
-        messages: &[Arc<Message>],
-    ) -> Result<(), Error>;
+        timestamp: u64,
+    ) -> Result<Option<TimeIndex>, Error>;
+    async fn sync(&self) -> Result<(), Error>;
+    async fn close(&self, segment: &Segment) -> Result<(), Error>;
 }
 
 #[derive(Debug)]
@@ -89,6 +116,8 @@ pub struct SystemStorage {
     pub topic: Arc<dyn TopicStorage>,
     pub partition: Arc<dyn PartitionStorage>,
     pub segment: Arc<dyn SegmentStorage>,
+    pub remote: Option<Arc<dyn RemoteSegmentStore>>,
+    pub cache: Arc<MessagesCache>,
 }
 
 impl SystemStorage {
@@ -98,6 +127,8 @@ impl SystemStorage {
             topic: Arc::new(FileTopicStorage::new(persister.clone())),
             partition: Arc::new(FilePartitionStorage::new(persister.clone())),
             segment: Arc::new(FileSegmentStorage::new(persister.clone())),
+            remote: None,
+            cache: Arc::new(MessagesCache::default()),
         }
     }
 }
@@ -136,13 +167,17 @@ impl Debug for dyn SegmentStorage {
 pub(crate) mod tests {
     use crate::message::Message;
     use crate::partitions::partition::Partition;
+    use crate::remote_store::RemoteSegmentStore;
+    use crate::segments::block_index::BlockIndex;
     use crate::segments::index::{Index, IndexRange};
-    use crate::segments::segment::Segment;
+    use crate::segments::log_slice::LogSlice;
+    use crate::segments::segment::{Segment, UnsavedBatch};
     use crate::segments::time_index::TimeIndex;
     use crate::storage::*;
     use crate::streams::stream::Stream;
     use crate::topics::topic::Topic;
     use async_trait::async_trait;
+    use sdk::compression::CompressionAlgorithm;
     use std::sync::Arc;
 
     struct TestStreamStorage {}
@@ -165,7 +200,12 @@ pub(crate) mod tests {
         }
     }
 
-    impl StreamStorage for TestStreamStorage {}
+    #[async_trait]
+    impl StreamStorage for TestStreamStorage {
+        async fn save_info(&self, _stream: &Stream) -> Result<(), Error> {
+            Ok(())
+        }
+    }
 
     #[async_trait]
     impl Storage<Topic> for TestTopicStorage {
@@ -184,6 +224,10 @@ pub(crate) mod tests {
 
     #[async_trait]
     impl TopicStorage for TestTopicStorage {
+        async fn save_info(&self, _topic: &Topic) -> Result<(), Error> {
+            Ok(())
+        }
+
         async fn save_consumer_group(
             &self,
             _topic: &Topic,
@@ -225,6 +269,18 @@ pub(crate) mod tests {
         async fn save_offset(&self, _offset: &ConsumerOffset) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn delete_offset(&self, _offset: &ConsumerOffset) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save_log_start_offset(&self, _partition: &Partition) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save_draining(&self, _partition: &Partition) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -252,15 +308,62 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
-        async fn save_messages(
+        async fn load_log_slice(
+            &self,
+            _segment: &Segment,
+            _index_range: &IndexRange,
+        ) -> Result<Option<LogSlice>, Error> {
+            Ok(None)
+        }
+
+        async fn save_batches(
+            &self,
+            _segment: &Segment,
+            _batches: &[UnsavedBatch],
+        ) -> Result<u64, Error> {
+            Ok(0)
+        }
+
+        async fn overwrite_messages(
             &self,
             _segment: &Segment,
             _messages: &[Arc<Message>],
-        ) -> Result<u32, Error> {
+        ) -> Result<u64, Error> {
             Ok(0)
         }
 
-        async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<u128>, Error> {
+        async fn compress_messages(
+            &self,
+            _segment: &Segment,
+            _compression: CompressionAlgorithm,
+        ) -> Result<Option<BlockIndex>, Error> {
+            Ok(None)
+        }
+
+        async fn recover(&self, _segment: &Segment) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn rebuild_indexes(&self, _segment: &Segment) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn archive(
+            &self,
+            _segment: &Segment,
+            _remote: &dyn RemoteSegmentStore,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<(u128, u64)>, Error> {
+            Ok(vec![])
+        }
+
+        async fn load_message_offsets(
+            &self,
+            _segment: &Segment,
+        ) -> Result<Vec<(u128, u64)>, Error> {
             Ok(vec![])
         }
 
@@ -282,15 +385,6 @@ pub(crate) mod tests {
             Ok(None)
         }
 
-        async fn save_index(
-            &self,
-            _segment: &Segment,
-            _current_position: u32,
-            _messages: &[Arc<Message>],
-        ) -> Result<(), Error> {
-            Ok(())
-        }
-
         async fn load_all_time_indexes(&self, _segment: &Segment) -> Result<Vec<TimeIndex>, Error> {
             Ok(vec![])
         }
@@ -302,11 +396,19 @@ pub(crate) mod tests {
             Ok(None)
         }
 
-        async fn save_time_index(
+        async fn load_time_index_by_timestamp(
             &self,
             _segment: &Segment,
-            _messages: &[Arc<Message>],
-        ) -> Result<(), Error> {
+            _timestamp: u64,
+        ) -> Result<Option<TimeIndex>, Error> {
+            Ok(None)
+        }
+
+        async fn sync(&self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn close(&self, _segment: &Segment) -> Result<(), Error> {
             Ok(())
         }
     }
@@ -317,6 +419,8 @@ pub(crate) mod tests {
             topic: Arc::new(TestTopicStorage {}),
             partition: Arc::new(TestPartitionStorage {}),
             segment: Arc::new(TestSegmentStorage {}),
+            remote: None,
+            cache: Arc::new(MessagesCache::default()),
         }
     }
 }
@@ -2,19 +2,39 @@ use crate::storage::SegmentStorage;
 use crate::streams::stream::Stream;
 use sdk::error::Error;
 use std::sync::Arc;
+use tracing::info;
 
 impl Stream {
     pub async fn load(&mut self) -> Result<(), Error> {
         let storage = self.storage.clone();
-        storage.stream.load(self).await
+        storage.stream.load(self).await?;
+        self.topics_ids = self
+            .topics
+            .values()
+            .map(|topic| (topic.name.clone(), topic.id))
+            .collect();
+        Ok(())
     }
 
     pub async fn persist(&self) -> Result<(), Error> {
         self.storage.stream.save(self).await
     }
 
+    pub async fn update(&mut self, name: &str) -> Result<(), Error> {
+        let previous_name = std::mem::replace(&mut self.name, name.to_string());
+        if let Err(error) = self.storage.stream.save_info(self).await {
+            self.name = previous_name;
+            return Err(error);
+        }
+
+        info!("Updated stream with ID: {}, name: '{}'.", self.id, name);
+        Ok(())
+    }
+
     pub async fn delete(&self) -> Result<(), Error> {
-        self.storage.stream.delete(self).await
+        self.storage.stream.delete(self).await?;
+        self.storage.cache.remove_stream(self.id);
+        Ok(())
     }
 
     pub async fn persist_messages(&self, storage: Arc<dyn SegmentStorage>) -> Result<(), Error> {
@@ -27,7 +27,22 @@ impl FileStreamStorage {
 unsafe impl Send for FileStreamStorage {}
 unsafe impl Sync for FileStreamStorage {}
 
-impl StreamStorage for FileStreamStorage {}
+#[async_trait]
+impl StreamStorage for FileStreamStorage {
+    async fn save_info(&self, stream: &Stream) -> Result<(), Error> {
+        if self
+            .persister
+            .replace(&stream.info_path, stream.name.as_bytes())
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotUpdateStreamInfo(stream.id));
+        }
+
+        info!("Stream info for stream with ID: {} was updated.", stream.id);
+        Ok(())
+    }
+}
 
 #[async_trait]
 impl Storage<Stream> for FileStreamStorage {
@@ -139,6 +154,15 @@ impl Storage<Stream> for FileStreamStorage {
 
     async fn delete(&self, stream: &Stream) -> Result<(), Error> {
         info!("Deleting stream with ID: {}...", stream.id);
+        for topic in stream.get_topics() {
+            for partition in topic.get_partitions() {
+                let partition = partition.read().await;
+                partition.delete_archived_segments().await?;
+                partition.close_segments().await?;
+            }
+        }
+
+        self.persister.close(&stream.path).await?;
         if fs::remove_dir_all(&stream.path).await.is_err() {
             return Err(Error::CannotDeleteStreamDirectory(stream.id));
         }
@@ -1,30 +1,68 @@
+use crate::segments::segment;
 use crate::streams::stream::Stream;
 use crate::topics::topic::Topic;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
+use sdk::identifier::Identifier;
+use sdk::topics::create_topic::{
+    ChecksumPolicy, CleanupPolicy, DeduplicationWindow, TopicConfigOverrides,
+};
 use tracing::info;
 
 impl Stream {
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_topic(
         &mut self,
-        id: u32,
+        id: Option<u32>,
         name: &str,
         partitions_count: u32,
+        message_expiry: Option<u32>,
+        max_topic_size_bytes: Option<u64>,
+        cleanup_policy: CleanupPolicy,
+        compression: CompressionAlgorithm,
+        deduplication: Option<DeduplicationWindow>,
+        checksum_policy: ChecksumPolicy,
+        overrides: TopicConfigOverrides,
     ) -> Result<(), Error> {
+        if self.topics_ids.contains_key(name) {
+            return Err(Error::TopicNameAlreadyExists(name.to_string(), self.id));
+        }
+
+        let id = match id {
+            Some(id) => id,
+            None => self.get_next_topic_id()?,
+        };
         if self.topics.contains_key(&id) {
             return Err(Error::TopicAlreadyExists(id, self.id));
         }
 
+        // The same limit applies to the segment size configured for the server.
+        if overrides
+            .segment_size_bytes
+            .is_some_and(|size_bytes| size_bytes > segment::MAX_SIZE_BYTES)
+        {
+            return Err(Error::InvalidTopicConfigOverrides);
+        }
+
         let topic = Topic::create(
             self.id,
             id,
             name,
             partitions_count,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            checksum_policy,
+            overrides,
             &self.topics_path,
             self.config.topic.clone(),
             self.storage.clone(),
         );
         topic.persist().await?;
         self.topics.insert(id, topic);
+        self.topics_ids.insert(name.to_string(), id);
         info!(
             "Created topic: {} with ID: {}, partitions: {}",
             name, id, partitions_count
@@ -32,7 +70,8 @@ impl Stream {
         Ok(())
     }
 
-    pub fn get_topic(&self, id: u32) -> Result<&Topic, Error> {
+    pub fn get_topic(&self, identifier: &Identifier) -> Result<&Topic, Error> {
+        let id = self.get_topic_id(identifier)?;
         let topic = self.topics.get(&id);
         if topic.is_none() {
             return Err(Error::TopicNotFound(id, self.id));
@@ -41,7 +80,63 @@ impl Stream {
         Ok(topic.unwrap())
     }
 
-    pub fn get_topic_mut(&mut self, id: u32) -> Result<&mut Topic, Error> {
+    pub fn get_topic_id(&self, identifier: &Identifier) -> Result<u32, Error> {
+        match identifier {
+            Identifier::Numeric(id) => Ok(*id),
+            Identifier::Name(name) => match self.topics_ids.get(name) {
+                Some(id) => Ok(*id),
+                None => Err(Error::TopicNameNotFound(name.to_string(), self.id)),
+            },
+        }
+    }
+
+    // The server assigns the next ID after the highest one, thus the IDs of the deleted topics might be reused.
+    fn get_next_topic_id(&self) -> Result<u32, Error> {
+        match self.topics.keys().max() {
+            Some(id) => id.checked_add(1).ok_or(Error::InvalidTopicId),
+            None => Ok(1),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_topic(
+        &mut self,
+        identifier: &Identifier,
+        name: &str,
+        message_expiry: Option<u32>,
+        max_topic_size_bytes: Option<u64>,
+        cleanup_policy: CleanupPolicy,
+        compression: CompressionAlgorithm,
+        deduplication: Option<DeduplicationWindow>,
+        checksum_policy: ChecksumPolicy,
+    ) -> Result<(), Error> {
+        let id = self.get_topic_id(identifier)?;
+        if let Some(topic_id) = self.topics_ids.get(name) {
+            if *topic_id != id {
+                return Err(Error::TopicNameAlreadyExists(name.to_string(), self.id));
+            }
+        }
+
+        let topic = self.get_topic_mut(&Identifier::Numeric(id))?;
+        let previous_name = topic.name.clone();
+        topic
+            .update(
+                name,
+                message_expiry,
+                max_topic_size_bytes,
+                cleanup_policy,
+                compression,
+                deduplication,
+                checksum_policy,
+            )
+            .await?;
+        self.topics_ids.remove(&previous_name);
+        self.topics_ids.insert(name.to_string(), id);
+        Ok(())
+    }
+
+    pub fn get_topic_mut(&mut self, identifier: &Identifier) -> Result<&mut Topic, Error> {
+        let id = self.get_topic_id(identifier)?;
         let topic = self.topics.get_mut(&id);
         if topic.is_none() {
             return Err(Error::TopicNotFound(id, self.id));
@@ -58,13 +153,15 @@ impl Stream {
         self.topics.values_mut().collect()
     }
 
-    pub async fn delete_topic(&mut self, id: u32) -> Result<(), Error> {
+    pub async fn delete_topic(&mut self, identifier: &Identifier) -> Result<(), Error> {
+        let id = self.get_topic_id(identifier)?;
         let topic = self.topics.remove(&id);
         if topic.is_none() {
             return Err(Error::TopicNotFound(id, self.id));
         }
 
         let topic = topic.unwrap();
+        self.topics_ids.remove(&topic.name);
         if topic.delete().await.is_err() {
             return Err(Error::CannotDeleteTopic(id, self.id));
         }
@@ -1,10 +1,15 @@
+use crate::cache::MessagesCache;
 use crate::clients::client_manager::{Client, ClientManager, Transport};
-use crate::config::SystemConfig;
-use crate::persister::*;
-use crate::storage::{SegmentStorage, SystemStorage};
+use crate::config::{StorageKind, SystemConfig};
+use crate::migration;
+use crate::persister::FileHandlePersister;
+use crate::remote_store::ArchiveDirectoryStore;
+use crate::storage::SystemStorage;
 use crate::streams::stream::Stream;
+use crate::utils::timestamp;
 use futures::future::join_all;
 use sdk::error::Error;
+use sdk::identifier::Identifier;
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::path::Path;
@@ -18,7 +23,9 @@ pub struct System {
     pub base_path: String,
     pub streams_path: String,
     pub storage: Arc<SystemStorage>,
+    memory_storage: Arc<SystemStorage>,
     streams: HashMap<u32, Stream>,
+    streams_ids: HashMap<String, u32>,
     config: Arc<SystemConfig>,
     client_manager: Arc<RwLock<ClientManager>>,
 }
@@ -27,9 +34,24 @@ impl System {
     pub fn create(config: Arc<SystemConfig>) -> System {
         let base_path = config.path.to_string();
         let streams_path = format!("{}/{}", base_path, &config.stream.path);
-        let persister: Arc<dyn Persister> = match config.stream.topic.partition.enforce_sync {
-            true => Arc::new(FileWithSyncPersister {}),
-            false => Arc::new(FilePersister {}),
+        let persister = Arc::new(FileHandlePersister::new(
+            config.stream.topic.partition.enforce_sync,
+        ));
+
+        // The in-memory streams share a single store, which is also used for all the streams of the in-memory system.
+        // Their messages are kept in memory anyway, thus only the file storage uses the cache.
+        let memory_storage = Arc::new(SystemStorage::memory());
+        let storage = match config.storage.kind {
+            StorageKind::File => {
+                let mut storage = SystemStorage::new(persister);
+                storage.cache = Arc::new(MessagesCache::new(Self::get_cache_size_bytes(&config)));
+                if config.archive.enabled {
+                    storage.remote =
+                        Some(Arc::new(ArchiveDirectoryStore::new(&config.archive.path)));
+                }
+                Arc::new(storage)
+            }
+            StorageKind::Memory => memory_storage.clone(),
         };
 
         System {
@@ -37,12 +59,36 @@ impl System {
             base_path,
             streams_path,
             streams: HashMap::new(),
-            storage: Arc::new(SystemStorage::new(persister)),
+            streams_ids: HashMap::new(),
+            storage,
+            memory_storage,
             client_manager: Arc::new(RwLock::new(ClientManager::new())),
         }
     }
 
+    fn get_cache_size_bytes(config: &SystemConfig) -> u64 {
+        if !config.cache.enabled {
+            return 0;
+        }
+
+        match config.cache.get_size_bytes() {
+            Some(size_bytes) => size_bytes,
+            None => {
+                error!(
+                    "Invalid cache size: '{}', the cache is disabled.",
+                    config.cache.size
+                );
+                0
+            }
+        }
+    }
+
     pub async fn init(&mut self) -> Result<(), Error> {
+        if self.config.storage.kind == StorageKind::Memory {
+            info!("System uses the in-memory storage, there are no streams to load.");
+            return Ok(());
+        }
+
         if !Path::new(&self.base_path).exists() && create_dir(&self.base_path).await.is_err() {
             return Err(Error::CannotCreateBaseDirectory);
         }
@@ -53,6 +99,7 @@ impl System {
 
         info!("Initializing system...");
         let now = Instant::now();
+        migration::migrate(&self.base_path, &self.streams_path).await?;
         self.load_streams().await?;
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
         Ok(())
@@ -102,6 +149,7 @@ impl System {
 
         join_all(load_streams).await;
         for stream in loaded_streams.lock().await.drain(..) {
+            self.streams_ids.insert(stream.name.clone(), stream.id);
             self.streams.insert(stream.id, stream);
         }
 
@@ -113,7 +161,8 @@ impl System {
         self.streams.values().collect()
     }
 
-    pub fn get_stream(&self, id: u32) -> Result<&Stream, Error> {
+    pub fn get_stream(&self, identifier: &Identifier) -> Result<&Stream, Error> {
+        let id = self.get_stream_id(identifier)?;
         let stream = self.streams.get(&id);
         if stream.is_none() {
             return Err(Error::StreamNotFound(id));
@@ -122,7 +171,8 @@ impl System {
         Ok(stream.unwrap())
     }
 
-    pub fn get_stream_mut(&mut self, id: u32) -> Result<&mut Stream, Error> {
+    pub fn get_stream_mut(&mut self, identifier: &Identifier) -> Result<&mut Stream, Error> {
+        let id = self.get_stream_id(identifier)?;
         let stream = self.streams.get_mut(&id);
         if stream.is_none() {
             return Err(Error::StreamNotFound(id));
@@ -131,49 +181,257 @@ impl System {
         Ok(stream.unwrap())
     }
 
-    pub async fn create_stream(&mut self, id: u32, name: &str) -> Result<(), Error> {
+    pub fn get_stream_id(&self, identifier: &Identifier) -> Result<u32, Error> {
+        match identifier {
+            Identifier::Numeric(id) => Ok(*id),
+            Identifier::Name(name) => match self.streams_ids.get(name) {
+                Some(id) => Ok(*id),
+                None => Err(Error::StreamNameNotFound(name.to_string())),
+            },
+        }
+    }
+
+    // The server assigns the next ID after the highest one, thus the IDs of the deleted streams might be reused.
+    fn get_next_stream_id(&self) -> Result<u32, Error> {
+        match self.streams.keys().max() {
+            Some(id) => id.checked_add(1).ok_or(Error::InvalidStreamId),
+            None => Ok(1),
+        }
+    }
+
+    pub async fn create_stream(&mut self, id: Option<u32>, name: &str) -> Result<(), Error> {
+        if self.streams_ids.contains_key(name) {
+            return Err(Error::StreamNameAlreadyExists(name.to_string()));
+        }
+
+        let id = match id {
+            Some(id) => id,
+            None => self.get_next_stream_id()?,
+        };
         if self.streams.contains_key(&id) {
             return Err(Error::StreamAlreadyExists(id));
         }
 
+        let storage = match self.config.storage.is_memory_stream(id) {
+            true => self.memory_storage.clone(),
+            false => self.storage.clone(),
+        };
         let stream = Stream::create(
             id,
             name,
             &self.streams_path,
             self.config.stream.clone(),
-            self.storage.clone(),
+            storage,
         );
         stream.persist().await?;
         self.streams.insert(stream.id, stream);
+        self.streams_ids.insert(name.to_string(), id);
         info!("Created stream with ID: {}, name: '{}'.", id, name);
         Ok(())
     }
 
-    pub async fn delete_stream(&mut self, id: u32) -> Result<(), Error> {
-        let stream = self.get_stream_mut(id)?;
+    pub async fn update_stream(
+        &mut self,
+        identifier: &Identifier,
+        name: &str,
+    ) -> Result<(), Error> {
+        let id = self.get_stream_id(identifier)?;
+        if let Some(stream_id) = self.streams_ids.get(name) {
+            if *stream_id != id {
+                return Err(Error::StreamNameAlreadyExists(name.to_string()));
+            }
+        }
+
+        let stream = self.get_stream_mut(&Identifier::Numeric(id))?;
+        let previous_name = stream.name.clone();
+        stream.update(name).await?;
+        self.streams_ids.remove(&previous_name);
+        self.streams_ids.insert(name.to_string(), id);
+        Ok(())
+    }
+
+    pub async fn delete_stream(&mut self, identifier: &Identifier) -> Result<(), Error> {
+        let stream = self.get_stream_mut(identifier)?;
         stream.delete().await?;
+        let id = stream.id;
+        let name = stream.name.clone();
         self.streams.remove(&id);
+        self.streams_ids.remove(&name);
         Ok(())
     }
 
-    pub async fn shutdown(&mut self, storage: Arc<dyn SegmentStorage>) -> Result<(), Error> {
-        self.persist_messages(storage.clone()).await?;
+    pub async fn shutdown(&mut self) -> Result<(), Error> {
+        self.persist_messages(true).await?;
         Ok(())
     }
 
-    pub async fn persist_messages(&self, storage: Arc<dyn SegmentStorage>) -> Result<(), Error> {
+    pub async fn persist_messages(&self, enforce_sync: bool) -> Result<(), Error> {
         trace!("Saving buffered messages on disk...");
         for stream in self.streams.values() {
-            stream.persist_messages(storage.clone()).await?;
+            stream
+                .persist_messages(stream.storage.segment.clone())
+                .await?;
+        }
+
+        if enforce_sync {
+            self.storage.segment.sync().await?;
+        }
+
+        // The messages are saved periodically, so are the cache stats logged.
+        let cache = &self.storage.cache;
+        if cache.is_enabled() {
+            info!(
+                "Messages cache: {} hit(s), {} miss(es), {} message(s) of size: {}/{} bytes.",
+                cache.get_hits(),
+                cache.get_misses(),
+                cache.get_messages_count(),
+                cache.get_size_bytes(),
+                cache.get_max_size_bytes()
+            );
+        }
+
+        Ok(())
+    }
+
+    pub async fn clean_messages(&self) -> Result<(), Error> {
+        trace!("Deleting expired segments...");
+        let now = timestamp::get();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                topic.delete_expired_segments(now).await?;
+                topic.delete_oldest_segments().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_drained_partitions(&mut self) -> Result<(), Error> {
+        trace!("Deleting drained partitions...");
+        for stream in self.streams.values_mut() {
+            for topic in stream.get_topics_mut() {
+                topic.delete_drained_partitions().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn compact_segments(&self, tombstone_retention: u32) -> Result<(), Error> {
+        trace!("Compacting segments...");
+        let now = timestamp::get();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                topic.compact_segments(now, tombstone_retention).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn archive_segments(&self, archive_after: u32) -> Result<(), Error> {
+        if self.storage.remote.is_none() {
+            trace!("Archive is disabled, skipping the segments archiving.");
+            return Ok(());
+        }
+
+        trace!("Archiving segments...");
+        let now = timestamp::get();
+        for stream in self.streams.values() {
+            // The in-memory streams have no remote store, as their segments aren't kept on disk.
+            let remote = stream.storage.remote.as_ref();
+            if remote.is_none() {
+                continue;
+            }
+
+            for topic in stream.get_topics() {
+                topic
+                    .archive_segments(now, archive_after, remote.unwrap().as_ref())
+                    .await?;
+            }
         }
 
         Ok(())
     }
 
+    pub async fn rebuild_indexes(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        self.get_stream(stream_id)?
+            .get_topic(topic_id)?
+            .rebuild_indexes(partition_id)
+            .await
+    }
+
+    pub async fn delete_messages_before(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), Error> {
+        self.get_stream(stream_id)?
+            .get_topic(topic_id)?
+            .delete_messages_before(partition_id, offset)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn purge_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), Error> {
+        self.get_stream(stream_id)?
+            .get_topic(topic_id)?
+            .purge()
+            .await
+    }
+
+    pub async fn purge_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        self.get_stream(stream_id)?
+            .get_topic(topic_id)?
+            .purge_partition(partition_id)
+            .await
+    }
+
+    pub async fn create_partitions(
+        &mut self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<u32, Error> {
+        self.get_stream_mut(stream_id)?
+            .get_topic_mut(topic_id)?
+            .create_partitions(partitions_count)
+            .await
+    }
+
+    pub async fn delete_partitions(
+        &mut self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), Error> {
+        self.get_stream_mut(stream_id)?
+            .get_topic_mut(topic_id)?
+            .delete_partitions(partitions_count)
+            .await?;
+        Ok(())
+    }
+
     pub async fn create_consumer_group(
         &mut self,
-        stream_id: u32,
-        topic_id: u32,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
         consumer_group_id: u32,
     ) -> Result<(), Error> {
         self.get_stream_mut(stream_id)?
@@ -185,15 +443,14 @@ impl System {
 
     pub async fn delete_consumer_group(
         &mut self,
-        stream_id: u32,
-        topic_id: u32,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
         consumer_group_id: u32,
     ) -> Result<(), Error> {
-        let consumer_group = self
-            .get_stream_mut(stream_id)?
-            .get_topic_mut(topic_id)?
-            .delete_consumer_group(consumer_group_id)
-            .await?;
+        let stream = self.get_stream_mut(stream_id)?;
+        let topic = stream.get_topic_mut(topic_id)?;
+        let (stream_id, topic_id) = (topic.stream_id, topic.id);
+        let consumer_group = topic.delete_consumer_group(consumer_group_id).await?;
 
         let client_manager = self.client_manager.read().await;
         let consumer_group = consumer_group.read().await;
@@ -210,17 +467,17 @@ impl System {
     pub async fn join_consumer_group(
         &self,
         client_id: u32,
-        stream_id: u32,
-        topic_id: u32,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
         consumer_group_id: u32,
     ) -> Result<(), Error> {
-        self.get_stream(stream_id)?
-            .get_topic(topic_id)?
+        let topic = self.get_stream(stream_id)?.get_topic(topic_id)?;
+        topic
             .join_consumer_group(consumer_group_id, client_id)
             .await?;
         let client_manager = self.client_manager.read().await;
         client_manager
-            .join_consumer_group(client_id, stream_id, topic_id, consumer_group_id)
+            .join_consumer_group(client_id, topic.stream_id, topic.id, consumer_group_id)
             .await?;
         Ok(())
     }
@@ -228,17 +485,17 @@ impl System {
     pub async fn leave_consumer_group(
         &self,
         client_id: u32,
-        stream_id: u32,
-        topic_id: u32,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
         consumer_group_id: u32,
     ) -> Result<(), Error> {
-        self.get_stream(stream_id)?
-            .get_topic(topic_id)?
+        let topic = self.get_stream(stream_id)?.get_topic(topic_id)?;
+        topic
             .leave_consumer_group(consumer_group_id, client_id)
             .await?;
         let client_manager = self.client_manager.read().await;
         client_manager
-            .leave_consumer_group(client_id, stream_id, topic_id, consumer_group_id)
+            .leave_consumer_group(client_id, topic.stream_id, topic.id, consumer_group_id)
             .await?;
         Ok(())
     }
@@ -274,7 +531,12 @@ impl System {
 
         for (stream_id, topic_id, consumer_group_id) in consumer_groups.iter() {
             if let Err(error) = self
-                .leave_consumer_group(client_id, *stream_id, *topic_id, *consumer_group_id)
+                .leave_consumer_group(
+                    client_id,
+                    &Identifier::Numeric(*stream_id),
+                    &Identifier::Numeric(*topic_id),
+                    *consumer_group_id,
+                )
                 .await
             {
                 error!(
@@ -88,6 +88,17 @@ impl ConsumerGroup {
         }
     }
 
+    pub async fn reassign_partitions(&mut self, partitions_count: u32) {
+        self.partitions_count = partitions_count;
+        trace!(
+            "Reassigning {} partitions in consumer group: {} for topic with ID: {}",
+            partitions_count,
+            self.id,
+            self.topic_id
+        );
+        self.assign_partitions().await;
+    }
+
     async fn assign_partitions(&mut self) {
         let mut members = self.members.values_mut().collect::<Vec<_>>();
         if members.is_empty() {
@@ -247,4 +258,35 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn should_assign_added_partitions_to_the_existing_members() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup {
+            topic_id: 1,
+            id: 1,
+            partitions_count: 1,
+            members: HashMap::new(),
+        };
+
+        consumer_group.add_member(member1_id).await;
+        consumer_group.add_member(member2_id).await;
+        consumer_group.reassign_partitions(4).await;
+        assert_eq!(consumer_group.partitions_count, 4);
+        let member1 = consumer_group.members.get(&member1_id).unwrap();
+        let member2 = consumer_group.members.get(&member2_id).unwrap();
+        let member1 = member1.read().await;
+        let member2 = member2.read().await;
+        assert_eq!(member1.partitions.len(), 2);
+        assert_eq!(member2.partitions.len(), 2);
+        let members_partitions = member1
+            .get_partitions()
+            .into_iter()
+            .chain(member2.get_partitions().into_iter())
+            .collect::<Vec<_>>();
+        for partition_id in 1..=consumer_group.partitions_count {
+            assert!(members_partitions.contains(&partition_id));
+        }
+    }
 }
@@ -97,6 +97,8 @@ mod tests {
     use super::*;
     use crate::config::TopicConfig;
     use crate::storage::tests::get_test_system_storage;
+    use sdk::compression::CompressionAlgorithm;
+    use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, TopicConfigOverrides};
     use std::sync::Arc;
 
     #[tokio::test]
@@ -207,6 +209,13 @@ mod tests {
             id,
             name,
             partitions_count,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
             topics_path,
             config,
             storage,
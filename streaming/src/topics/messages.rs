@@ -1,7 +1,8 @@
 use crate::message::Message;
 use crate::polling_consumer::PollingConsumer;
+use crate::segments::log_slice::LogSlice;
 use crate::topics::topic::Topic;
-use ringbuffer::RingBufferWrite;
+use sdk::batch::MessageBatch;
 use sdk::error::Error;
 use sdk::messages::poll_messages::Kind;
 use sdk::messages::send_messages::KeyKind;
@@ -34,11 +35,53 @@ impl Topic {
         }
     }
 
+    pub async fn get_log_slices(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        kind: Kind,
+        value: u64,
+        count: u32,
+        min_size_bytes: u64,
+    ) -> Result<Option<Vec<LogSlice>>, Error> {
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(Error::PartitionNotFound(partition_id));
+        }
+
+        let partition = partition.unwrap();
+        let partition = partition.read().await;
+        partition
+            .get_log_slices(consumer, kind, value, count, min_size_bytes)
+            .await
+    }
+
     pub async fn append_messages(
         &self,
         key_kind: KeyKind,
         key_value: u32,
         messages: Vec<Message>,
+    ) -> Result<(), Error> {
+        self.append(key_kind, key_value, messages, None).await
+    }
+
+    pub async fn append_batch(
+        &self,
+        key_kind: KeyKind,
+        key_value: u32,
+        messages: Vec<Message>,
+        batch: MessageBatch,
+    ) -> Result<(), Error> {
+        self.append(key_kind, key_value, messages, Some(batch))
+            .await
+    }
+
+    async fn append(
+        &self,
+        key_kind: KeyKind,
+        key_value: u32,
+        messages: Vec<Message>,
+        batch: Option<MessageBatch>,
     ) -> Result<(), Error> {
         if messages.is_empty() {
             return Ok(());
@@ -46,10 +89,10 @@ impl Topic {
 
         let partition_id = match key_kind {
             KeyKind::PartitionId => key_value,
-            KeyKind::EntityId => self.calculate_partition_id(key_value),
+            KeyKind::EntityId => self.calculate_partition_id(key_value).await,
         };
 
-        self.append_messages_to_partition(partition_id, messages)
+        self.append_messages_to_partition(partition_id, messages, batch)
             .await
     }
 
@@ -57,6 +100,7 @@ impl Topic {
         &self,
         partition_id: u32,
         messages: Vec<Message>,
+        batch: Option<MessageBatch>,
     ) -> Result<(), Error> {
         let partition = self.partitions.get(&partition_id);
         if partition.is_none() {
@@ -65,16 +109,19 @@ impl Topic {
 
         let partition = partition.unwrap();
         let mut partition = partition.write().await;
-        partition.append_messages(messages).await?;
+        if partition.is_draining {
+            return Err(Error::PartitionDraining(partition_id, self.id));
+        }
+
+        partition.append(messages, batch).await?;
         Ok(())
     }
 
-    fn calculate_partition_id(&self, entity_id: u32) -> u32 {
-        let partitions_count = self.partitions.len() as u32;
-        let mut partition_id = entity_id % partitions_count;
-        if partition_id == 0 {
-            partition_id = partitions_count;
-        }
+    async fn calculate_partition_id(&self, entity_id: u32) -> u32 {
+        let partition_id = KeyKind::calculate_entity_partition_id(
+            entity_id,
+            self.get_active_partitions_count().await,
+        );
         trace!(
             "Calculated partition ID: {} for key: {}",
             partition_id,
@@ -84,25 +131,33 @@ impl Topic {
     }
 
     pub(crate) async fn load_messages_to_cache(&mut self) -> Result<(), Error> {
-        let messages_buffer_size = self.config.partition.messages_buffer as u64;
-        if messages_buffer_size == 0 {
+        let cache = self.storage.cache.clone();
+        if !cache.is_enabled() {
             return Ok(());
         }
 
-        for (_, partition) in self.partitions.iter_mut() {
-            let mut partition = partition.write().await;
-            if partition.segments.is_empty() {
-                trace!("No segments found for partition with ID: {}", partition.id);
+        for (_, partition) in self.partitions.iter() {
+            let partition = partition.read().await;
+            let segment = match partition.segments.last() {
+                Some(segment) if !segment.is_empty() => segment,
+                _ => {
+                    trace!("No messages found for partition with ID: {}", partition.id);
+                    continue;
+                }
+            };
+
+            if segment.current_size_bytes > cache.get_max_size_bytes() - cache.get_size_bytes() {
+                trace!(
+                    "Messages of partition with ID: {} for topic with ID: {} and stream with ID: {} don't fit in the cache.",
+                    partition.id,
+                    partition.topic_id,
+                    partition.stream_id
+                );
                 continue;
             }
 
-            let end_offset = partition.segments.last().unwrap().current_offset;
-            let start_offset = if end_offset + 1 >= messages_buffer_size {
-                end_offset + 1 - messages_buffer_size
-            } else {
-                0
-            };
-
+            let start_offset = segment.start_offset;
+            let end_offset = segment.current_offset;
             let messages_count = (end_offset - start_offset + 1) as u32;
             trace!(
                 "Loading {} messages for partition with ID: {} for topic with ID: {} and stream with ID: {} from offset: {} to offset: {}...",
@@ -114,20 +169,17 @@ impl Topic {
                 end_offset
             );
 
-            let messages = partition
-                .get_messages_by_offset(start_offset, messages_count)
-                .await?;
-
-            if partition.messages.is_some() {
-                let partition_messages = partition.messages.as_mut().unwrap();
-                for message in messages {
-                    partition_messages.push(message);
-                }
-            }
+            let messages = segment.get_messages(start_offset, messages_count).await?;
+            cache.append(
+                partition.stream_id,
+                partition.topic_id,
+                partition.id,
+                &messages,
+            );
 
             trace!(
                 "Loaded {} messages for partition with ID: {} for topic with ID: {} and stream with ID: {} from offset: {} to offset: {}.",
-                messages_count,
+                messages.len(),
                 partition.id,
                 partition.topic_id,
                 partition.stream_id,
@@ -143,10 +195,12 @@ impl Topic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cache::MessagesCache;
     use crate::config::TopicConfig;
     use crate::storage::tests::get_test_system_storage;
     use bytes::Bytes;
-    use ringbuffer::RingBufferExt;
+    use sdk::compression::CompressionAlgorithm;
+    use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, TopicConfigOverrides};
 
     #[tokio::test]
     async fn given_partition_id_key_messages_should_be_appended_only_to_the_chosen_partition() {
@@ -157,7 +211,7 @@ mod tests {
 
         for entity_id in 1..=messages_count {
             let payload = Bytes::from("test");
-            let messages = vec![Message::empty(1, entity_id as u128, payload, 1)];
+            let messages = vec![Message::empty(1, entity_id as u128, payload)];
             topic
                 .append_messages(KeyKind::PartitionId, partition_id, messages)
                 .await
@@ -168,7 +222,10 @@ mod tests {
         assert_eq!(partitions.len(), partitions_count as usize);
         for partition in partitions {
             let partition = partition.read().await;
-            let messages = partition.messages.as_ref().unwrap().to_vec();
+            let messages = partition
+                .get_messages_by_offset(0, messages_count)
+                .await
+                .unwrap();
             if partition.id == partition_id {
                 assert_eq!(messages.len() as u32, messages_count);
             } else {
@@ -185,7 +242,7 @@ mod tests {
 
         for entity_id in 1..=partitions_count * messages_per_partition_count {
             let payload = Bytes::from("test");
-            let messages = vec![Message::empty(1, entity_id as u128, payload, 1)];
+            let messages = vec![Message::empty(1, entity_id as u128, payload)];
             topic
                 .append_messages(KeyKind::EntityId, entity_id, messages)
                 .await
@@ -196,19 +253,22 @@ mod tests {
         assert_eq!(partitions.len(), partitions_count as usize);
         for partition in partitions {
             let partition = partition.read().await;
-            let messages = partition.messages.as_ref().unwrap().to_vec();
+            let messages = partition
+                .get_messages_by_offset(0, messages_per_partition_count)
+                .await
+                .unwrap();
             assert_eq!(messages.len() as u32, messages_per_partition_count);
         }
     }
 
-    #[test]
-    fn given_multiple_partitions_calculate_partition_id_should_return_next_partition_id() {
+    #[tokio::test]
+    async fn given_multiple_partitions_calculate_partition_id_should_return_next_partition_id() {
         let partitions_count = 3;
         let messages_per_partition_count = 1000;
         let topic = init_topic(partitions_count);
 
         for entity_id in 1..=partitions_count * messages_per_partition_count {
-            let partition_id = topic.calculate_partition_id(entity_id);
+            let partition_id = topic.calculate_partition_id(entity_id).await;
             let mut expected_partition_id = entity_id % partitions_count;
             if expected_partition_id == 0 {
                 expected_partition_id = partitions_count;
@@ -218,8 +278,35 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn given_draining_partition_messages_should_be_appended_only_to_the_active_partitions() {
+        let partitions_count = 3;
+        let topic = init_topic(partitions_count);
+        let draining_partition = topic.partitions.get(&partitions_count).unwrap();
+        draining_partition
+            .write()
+            .await
+            .start_draining()
+            .await
+            .unwrap();
+
+        let messages = vec![Message::empty(1, 1, Bytes::from("test"))];
+        let result = topic
+            .append_messages(KeyKind::PartitionId, partitions_count, messages)
+            .await;
+        assert!(matches!(result, Err(Error::PartitionDraining(3, 2))));
+
+        assert_eq!(topic.get_active_partitions_count().await, 2);
+        for entity_id in 1..=10 {
+            let partition_id = topic.calculate_partition_id(entity_id).await;
+            assert_ne!(partition_id, partitions_count);
+        }
+    }
+
     fn init_topic(partitions_count: u32) -> Topic {
-        let storage = Arc::new(get_test_system_storage());
+        let mut storage = get_test_system_storage();
+        storage.cache = Arc::new(MessagesCache::new(1024 * 1024));
+        let storage = Arc::new(storage);
         let stream_id = 1;
         let id = 2;
         let topics_path = "/topics";
@@ -231,6 +318,13 @@ mod tests {
             id,
             name,
             partitions_count,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
             topics_path,
             config,
             storage,
@@ -2,6 +2,8 @@ pub mod consumer_group;
 pub mod consumer_groups;
 pub mod messages;
 pub mod offsets;
+pub mod partitions;
 pub mod persistence;
+pub mod segments;
 pub mod storage;
 pub mod topic;
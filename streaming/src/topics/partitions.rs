@@ -0,0 +1,120 @@
+use crate::topics::topic::Topic;
+use sdk::error::Error;
+use sdk::topics::create_topic::MAX_PARTITIONS_COUNT;
+use tokio::sync::RwLock;
+use tracing::info;
+
+impl Topic {
+    pub fn get_partitions_count(&self) -> u32 {
+        self.partitions.len() as u32
+    }
+
+    // The draining partitions must remain the highest-numbered ones, so no partitions are created meanwhile.
+    pub async fn create_partitions(&mut self, partitions_count: u32) -> Result<u32, Error> {
+        let previous_partitions_count = self.get_partitions_count();
+        if partitions_count == 0
+            || previous_partitions_count + partitions_count > MAX_PARTITIONS_COUNT
+        {
+            return Err(Error::InvalidTopicPartitions);
+        }
+
+        if self.get_active_partitions_count().await < previous_partitions_count {
+            return Err(Error::TopicPartitionsDraining(self.id, self.stream_id));
+        }
+
+        let current_partitions_count = previous_partitions_count + partitions_count;
+        for partition_id in previous_partitions_count + 1..=current_partitions_count {
+            let partition = self.create_partition(partition_id, true);
+            partition.persist().await?;
+            self.partitions.insert(partition_id, RwLock::new(partition));
+        }
+
+        for consumer_group in self.consumer_groups.values() {
+            consumer_group
+                .write()
+                .await
+                .reassign_partitions(current_partitions_count)
+                .await;
+        }
+
+        info!(
+            "Created {} partition(s) for topic with ID: {} and stream with ID: {}, partitions count: {} -> {}.",
+            partitions_count, self.id, self.stream_id, previous_partitions_count, current_partitions_count
+        );
+        Ok(previous_partitions_count)
+    }
+
+    pub async fn get_active_partitions_count(&self) -> u32 {
+        let mut partitions_count = self.get_partitions_count();
+        while let Some(partition) = self.partitions.get(&partitions_count) {
+            if !partition.read().await.is_draining {
+                break;
+            }
+
+            partitions_count -= 1;
+        }
+
+        partitions_count
+    }
+
+    pub async fn delete_partitions(&mut self, partitions_count: u32) -> Result<u32, Error> {
+        let active_partitions_count = self.get_active_partitions_count().await;
+        if partitions_count == 0 || partitions_count >= active_partitions_count {
+            return Err(Error::InvalidTopicPartitions);
+        }
+
+        for partition_id in active_partitions_count - partitions_count + 1..=active_partitions_count
+        {
+            let partition = self.partitions.get(&partition_id);
+            if partition.is_none() {
+                return Err(Error::PartitionNotFound(partition_id));
+            }
+
+            partition.unwrap().write().await.start_draining().await?;
+        }
+
+        info!(
+            "Started draining {} partition(s) for topic with ID: {} and stream with ID: {}, active partitions count: {} -> {}.",
+            partitions_count, self.id, self.stream_id, active_partitions_count, active_partitions_count - partitions_count
+        );
+        self.delete_drained_partitions().await
+    }
+
+    pub async fn delete_drained_partitions(&mut self) -> Result<u32, Error> {
+        let consumer_group_ids = self.consumer_groups.keys().copied().collect::<Vec<u32>>();
+        let mut partition_id = self.get_partitions_count();
+        let mut deleted_partitions = 0;
+        while let Some(partition) = self.partitions.get(&partition_id) {
+            if !partition.read().await.is_drained(&consumer_group_ids).await {
+                break;
+            }
+
+            let partition = self.partitions.remove(&partition_id).unwrap();
+            partition.read().await.delete().await?;
+            self.storage
+                .cache
+                .remove_partition(self.stream_id, self.id, partition_id);
+            deleted_partitions += 1;
+            partition_id -= 1;
+        }
+
+        if deleted_partitions == 0 {
+            return Ok(0);
+        }
+
+        let partitions_count = self.get_partitions_count();
+        for consumer_group in self.consumer_groups.values() {
+            consumer_group
+                .write()
+                .await
+                .reassign_partitions(partitions_count)
+                .await;
+        }
+
+        info!(
+            "Deleted {} drained partition(s) for topic with ID: {} and stream with ID: {}, partitions count: {}.",
+            deleted_partitions, self.id, self.stream_id, partitions_count
+        );
+        Ok(deleted_partitions)
+    }
+}
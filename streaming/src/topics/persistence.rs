@@ -1,7 +1,10 @@
 use crate::storage::SegmentStorage;
 use crate::topics::topic::Topic;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, DeduplicationWindow};
 use std::sync::Arc;
+use tracing::info;
 
 impl Topic {
     pub async fn load(&mut self) -> Result<(), Error> {
@@ -14,8 +17,65 @@ impl Topic {
         self.storage.topic.save(self).await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &mut self,
+        name: &str,
+        message_expiry: Option<u32>,
+        max_topic_size_bytes: Option<u64>,
+        cleanup_policy: CleanupPolicy,
+        compression: CompressionAlgorithm,
+        deduplication: Option<DeduplicationWindow>,
+        checksum_policy: ChecksumPolicy,
+    ) -> Result<(), Error> {
+        let is_deduplication_changed = self.deduplication != deduplication;
+        let previous_settings = (
+            std::mem::replace(&mut self.name, name.to_string()),
+            std::mem::replace(&mut self.message_expiry, message_expiry),
+            std::mem::replace(&mut self.max_topic_size_bytes, max_topic_size_bytes),
+            std::mem::replace(&mut self.cleanup_policy, cleanup_policy),
+            std::mem::replace(&mut self.compression, compression),
+            std::mem::replace(&mut self.deduplication, deduplication),
+            std::mem::replace(&mut self.checksum_policy, checksum_policy),
+        );
+        if let Err(error) = self.storage.topic.save_info(self).await {
+            (
+                self.name,
+                self.message_expiry,
+                self.max_topic_size_bytes,
+                self.cleanup_policy,
+                self.compression,
+                self.deduplication,
+                self.checksum_policy,
+            ) = previous_settings;
+            return Err(error);
+        }
+
+        // The buffered messages are saved first, so that their IDs are loaded into the new deduplicators.
+        if is_deduplication_changed {
+            self.persist_messages(self.storage.segment.clone()).await?;
+        }
+
+        for partition in self.get_partitions() {
+            let mut partition = partition.write().await;
+            partition.compression = compression;
+            partition.set_checksum_policy(checksum_policy);
+            if is_deduplication_changed {
+                partition.set_deduplication(deduplication).await?;
+            }
+        }
+
+        info!(
+            "Updated topic with ID: {} for stream with ID: {}, name: '{}'.",
+            self.id, self.stream_id, name
+        );
+        Ok(())
+    }
+
     pub async fn delete(&self) -> Result<(), Error> {
-        self.storage.topic.delete(self).await
+        self.storage.topic.delete(self).await?;
+        self.storage.cache.remove_topic(self.stream_id, self.id);
+        Ok(())
     }
 
     pub async fn persist_messages(&self, storage: Arc<dyn SegmentStorage>) -> Result<(), Error> {
@@ -24,6 +84,9 @@ impl Topic {
             for segment in partition.get_segments_mut() {
                 segment.persist_messages(storage.clone()).await?;
             }
+            if partition.config.enforce_sync {
+                storage.sync().await?;
+            }
         }
 
         Ok(())
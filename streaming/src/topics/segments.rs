@@ -0,0 +1,173 @@
+use crate::remote_store::RemoteSegmentStore;
+use crate::topics::topic::Topic;
+use sdk::error::Error;
+use sdk::topics::create_topic::CleanupPolicy;
+use tracing::info;
+
+impl Topic {
+    pub async fn get_size_bytes(&self) -> u64 {
+        let mut size_bytes = 0;
+        for partition in self.partitions.values() {
+            size_bytes += partition.read().await.get_size_bytes();
+        }
+
+        size_bytes
+    }
+
+    pub async fn delete_expired_segments(&self, now: u64) -> Result<u32, Error> {
+        if self.message_expiry.is_none() {
+            return Ok(0);
+        }
+
+        let message_expiry = self.message_expiry.unwrap();
+        let mut deleted_segments = 0;
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            let start_offsets = partition
+                .get_expired_segments_start_offsets(now, message_expiry)
+                .await?;
+            for start_offset in start_offsets {
+                partition.delete_segment(start_offset).await?;
+                deleted_segments += 1;
+            }
+        }
+
+        if deleted_segments > 0 {
+            info!(
+                "Deleted {} expired segment(s) for topic with ID: {} and stream with ID: {}.",
+                deleted_segments, self.id, self.stream_id
+            );
+        }
+
+        Ok(deleted_segments)
+    }
+
+    pub async fn delete_oldest_segments(&self) -> Result<u32, Error> {
+        if self.max_topic_size_bytes.is_none() {
+            return Ok(0);
+        }
+
+        let max_topic_size_bytes = self.max_topic_size_bytes.unwrap();
+        let mut deleted_segments = 0;
+        while self.get_size_bytes().await > max_topic_size_bytes {
+            let mut oldest_segment = None;
+            let mut largest_partition_size_bytes = 0;
+            for (partition_id, partition) in self.partitions.iter() {
+                let partition = partition.read().await;
+                if partition.segments.len() < 2 || !partition.segments[0].is_closed {
+                    continue;
+                }
+
+                let size_bytes = partition.get_size_bytes();
+                if size_bytes > largest_partition_size_bytes {
+                    largest_partition_size_bytes = size_bytes;
+                    oldest_segment = Some((*partition_id, partition.segments[0].start_offset));
+                }
+            }
+
+            if oldest_segment.is_none() {
+                break;
+            }
+
+            let (partition_id, start_offset) = oldest_segment.unwrap();
+            let mut partition = self.partitions.get(&partition_id).unwrap().write().await;
+            partition.delete_segment(start_offset).await?;
+            deleted_segments += 1;
+        }
+
+        if deleted_segments > 0 {
+            info!(
+                "Deleted {} segment(s) exceeding the max size: {} bytes for topic with ID: {} and stream with ID: {}.",
+                deleted_segments, max_topic_size_bytes, self.id, self.stream_id
+            );
+        }
+
+        Ok(deleted_segments)
+    }
+
+    pub async fn compact_segments(&self, now: u64, tombstone_retention: u32) -> Result<u32, Error> {
+        if self.cleanup_policy != CleanupPolicy::Compact {
+            return Ok(0);
+        }
+
+        let mut removed_messages = 0;
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            removed_messages += partition.compact_segments(now, tombstone_retention).await?;
+        }
+
+        if removed_messages > 0 {
+            info!(
+                "Compacted {} message(s) for topic with ID: {} and stream with ID: {}.",
+                removed_messages, self.id, self.stream_id
+            );
+        }
+
+        Ok(removed_messages)
+    }
+
+    pub async fn archive_segments(
+        &self,
+        now: u64,
+        archive_after: u32,
+        remote: &dyn RemoteSegmentStore,
+    ) -> Result<u32, Error> {
+        let mut archived_segments = 0;
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            archived_segments += partition
+                .archive_segments(now, archive_after, remote)
+                .await?;
+        }
+
+        Ok(archived_segments)
+    }
+
+    pub async fn rebuild_indexes(&self, partition_id: u32) -> Result<(), Error> {
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(Error::PartitionNotFound(partition_id));
+        }
+
+        let mut partition = partition.unwrap().write().await;
+        partition.rebuild_indexes().await
+    }
+
+    pub async fn purge(&self) -> Result<(), Error> {
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            partition.purge().await?;
+        }
+
+        info!(
+            "Purged topic with ID: {} and stream with ID: {}.",
+            self.id, self.stream_id
+        );
+
+        Ok(())
+    }
+
+    pub async fn delete_messages_before(
+        &self,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<u32, Error> {
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(Error::PartitionNotFound(partition_id));
+        }
+
+        let mut partition = partition.unwrap().write().await;
+        partition.delete_messages_before(offset).await
+    }
+
+    pub async fn purge_partition(&self, partition_id: u32) -> Result<(), Error> {
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(Error::PartitionNotFound(partition_id));
+        }
+
+        let mut partition = partition.unwrap().write().await;
+        partition.purge().await
+    }
+}
@@ -1,12 +1,16 @@
-use crate::partitions::partition::Partition;
 use crate::persister::Persister;
 use crate::storage::{Storage, TopicStorage};
 use crate::topics::consumer_group::ConsumerGroup;
 use crate::topics::topic::Topic;
 use async_trait::async_trait;
 use futures::future::join_all;
+use sdk::compression::CompressionAlgorithm;
 use sdk::error::Error;
+use sdk::topics::create_topic::{
+    ChecksumPolicy, CleanupPolicy, DeduplicationWindow, TopicConfigOverrides,
+};
 use std::path::Path;
+use std::str::from_utf8;
 use std::sync::Arc;
 use tokio::fs;
 use tokio::fs::{create_dir, OpenOptions};
@@ -23,6 +27,12 @@ impl FileTopicStorage {
     pub fn new(persister: Arc<dyn Persister>) -> Self {
         Self { persister }
     }
+
+    // The overwritten files of the topic are synced as configured for its partitions, including the overrides.
+    fn enforce_sync(&self, topic: &Topic) {
+        self.persister
+            .enforce_sync(&topic.path, topic.get_partition_config().enforce_sync);
+    }
 }
 
 unsafe impl Send for FileTopicStorage {}
@@ -30,6 +40,24 @@ unsafe impl Sync for FileTopicStorage {}
 
 #[async_trait]
 impl TopicStorage for FileTopicStorage {
+    async fn save_info(&self, topic: &Topic) -> Result<(), Error> {
+        self.enforce_sync(topic);
+        if self
+            .persister
+            .replace(&topic.info_path, &get_topic_info_bytes(topic))
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotUpdateTopicInfo(topic.id, topic.stream_id));
+        }
+
+        info!(
+            "Topic info for topic with ID: {} for stream with ID: {} was updated.",
+            topic.id, topic.stream_id
+        );
+        Ok(())
+    }
+
     async fn save_consumer_group(
         &self,
         topic: &Topic,
@@ -141,17 +169,64 @@ impl Storage<Topic> for FileTopicStorage {
             return Err(Error::CannotOpenTopicInfo(topic.id, topic.stream_id));
         }
 
-        let mut topic_info = String::new();
+        let mut topic_info = Vec::new();
         if topic_info_file
             .unwrap()
-            .read_to_string(&mut topic_info)
+            .read_to_end(&mut topic_info)
             .await
             .is_err()
+            || topic_info.len() < 36
         {
             return Err(Error::CannotReadTopicInfo(topic.id, topic.stream_id));
         }
 
-        topic.name = topic_info;
+        let message_expiry = u32::from_le_bytes(topic_info[..4].try_into()?);
+        let max_topic_size_bytes = u64::from_le_bytes(topic_info[4..12].try_into()?);
+        let cleanup_policy = CleanupPolicy::from_code(topic_info[12]);
+        if cleanup_policy.is_err() {
+            return Err(Error::CannotReadTopicInfo(topic.id, topic.stream_id));
+        }
+
+        let compression = CompressionAlgorithm::from_code(topic_info[13]);
+        if compression.is_err() {
+            return Err(Error::CannotReadTopicInfo(topic.id, topic.stream_id));
+        }
+
+        let deduplication = DeduplicationWindow::from_bytes(&topic_info[14..22]);
+        if deduplication.is_err() {
+            return Err(Error::CannotReadTopicInfo(topic.id, topic.stream_id));
+        }
+
+        let checksum_policy = ChecksumPolicy::from_code(topic_info[22]);
+        if checksum_policy.is_err() {
+            return Err(Error::CannotReadTopicInfo(topic.id, topic.stream_id));
+        }
+
+        let overrides = TopicConfigOverrides::from_bytes(&topic_info[23..36]);
+        if overrides.is_err() {
+            return Err(Error::CannotReadTopicInfo(topic.id, topic.stream_id));
+        }
+
+        let name = from_utf8(&topic_info[36..]);
+        if name.is_err() {
+            return Err(Error::CannotReadTopicInfo(topic.id, topic.stream_id));
+        }
+
+        topic.name = name.unwrap().to_string();
+        topic.message_expiry = match message_expiry {
+            0 => None,
+            _ => Some(message_expiry),
+        };
+        topic.max_topic_size_bytes = match max_topic_size_bytes {
+            0 => None,
+            _ => Some(max_topic_size_bytes),
+        };
+        topic.cleanup_policy = cleanup_policy.unwrap();
+        topic.compression = compression.unwrap();
+        topic.deduplication = deduplication.unwrap();
+        topic.checksum_policy = checksum_policy.unwrap();
+        topic.overrides = overrides.unwrap();
+        self.enforce_sync(topic);
         let dir_entries = fs::read_dir(&topic.get_partitions_path()).await;
         if dir_entries.is_err() {
             return Err(Error::CannotReadPartitions(topic.id, topic.stream_id));
@@ -173,15 +248,7 @@ impl Storage<Topic> for FileTopicStorage {
             }
 
             let partition_id = partition_id.unwrap();
-            let partition = Partition::create(
-                topic.stream_id,
-                topic.id,
-                partition_id,
-                &topic.get_partitions_path(),
-                false,
-                topic.config.partition.clone(),
-                topic.storage.clone(),
-            );
+            let partition = topic.create_partition(partition_id, false);
             unloaded_partitions.push(partition);
         }
 
@@ -229,6 +296,8 @@ impl Storage<Topic> for FileTopicStorage {
             return Err(Error::CannotCreateTopicDirectory(topic.id, topic.stream_id));
         }
 
+        self.enforce_sync(topic);
+
         if create_dir(&topic.get_partitions_path()).await.is_err() {
             return Err(Error::CannotCreatePartitionsDirectory(
                 topic.stream_id,
@@ -245,7 +314,7 @@ impl Storage<Topic> for FileTopicStorage {
 
         if self
             .persister
-            .overwrite(&topic.info_path, topic.name.as_bytes())
+            .overwrite(&topic.info_path, &get_topic_info_bytes(topic))
             .await
             .is_err()
         {
@@ -276,6 +345,13 @@ impl Storage<Topic> for FileTopicStorage {
             "Deleting topic with ID: {} for stream with ID: {}...",
             topic.id, topic.stream_id
         );
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            partition.delete_archived_segments().await?;
+            partition.close_segments().await?;
+        }
+
+        self.persister.close(&topic.path).await?;
         if fs::remove_dir_all(&topic.path).await.is_err() {
             return Err(Error::CannotDeleteTopicDirectory(topic.id, topic.stream_id));
         }
@@ -288,3 +364,16 @@ impl Storage<Topic> for FileTopicStorage {
         Ok(())
     }
 }
+
+fn get_topic_info_bytes(topic: &Topic) -> Vec<u8> {
+    let mut topic_info = Vec::with_capacity(36 + topic.name.len());
+    topic_info.extend(topic.message_expiry.unwrap_or(0).to_le_bytes());
+    topic_info.extend(topic.max_topic_size_bytes.unwrap_or(0).to_le_bytes());
+    topic_info.extend(topic.cleanup_policy.as_code().to_le_bytes());
+    topic_info.extend(topic.compression.as_code().to_le_bytes());
+    topic_info.extend(DeduplicationWindow::as_bytes(topic.deduplication));
+    topic_info.extend(topic.checksum_policy.as_code().to_le_bytes());
+    topic_info.extend(topic.overrides.as_bytes());
+    topic_info.extend(topic.name.as_bytes());
+    topic_info
+}
@@ -1,7 +1,12 @@
-use crate::config::TopicConfig;
+use crate::config::{PartitionConfig, SegmentConfig, TopicConfig};
+use crate::partitions::message_deduplicator::MessageDeduplicator;
 use crate::partitions::partition::Partition;
 use crate::storage::SystemStorage;
 use crate::topics::consumer_group::ConsumerGroup;
+use sdk::compression::CompressionAlgorithm;
+use sdk::topics::create_topic::{
+    ChecksumPolicy, CleanupPolicy, DeduplicationWindow, TopicConfigOverrides,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -14,6 +19,13 @@ pub struct Topic {
     pub id: u32,
     pub name: String,
     pub path: String,
+    pub message_expiry: Option<u32>,
+    pub max_topic_size_bytes: Option<u64>,
+    pub cleanup_policy: CleanupPolicy,
+    pub compression: CompressionAlgorithm,
+    pub deduplication: Option<DeduplicationWindow>,
+    pub checksum_policy: ChecksumPolicy,
+    pub overrides: TopicConfigOverrides,
     pub(crate) info_path: String,
     pub(crate) config: Arc<TopicConfig>,
     pub(crate) partitions: HashMap<u32, RwLock<Partition>>,
@@ -29,14 +41,37 @@ impl Topic {
         config: Arc<TopicConfig>,
         storage: Arc<SystemStorage>,
     ) -> Topic {
-        Topic::create(stream_id, id, "", 0, topics_path, config, storage)
+        Topic::create(
+            stream_id,
+            id,
+            "",
+            0,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
+            topics_path,
+            config,
+            storage,
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         stream_id: u32,
         id: u32,
         name: &str,
         partitions_count: u32,
+        message_expiry: Option<u32>,
+        max_topic_size_bytes: Option<u64>,
+        cleanup_policy: CleanupPolicy,
+        compression: CompressionAlgorithm,
+        deduplication: Option<DeduplicationWindow>,
+        checksum_policy: ChecksumPolicy,
+        overrides: TopicConfigOverrides,
         topics_path: &str,
         config: Arc<TopicConfig>,
         storage: Arc<SystemStorage>,
@@ -51,6 +86,13 @@ impl Topic {
             partitions: HashMap::new(),
             path,
             info_path,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            checksum_policy,
+            overrides,
             config: config.clone(),
             storage: storage.clone(),
             consumer_groups: HashMap::new(),
@@ -58,15 +100,7 @@ impl Topic {
 
         topic.partitions = (1..partitions_count + 1)
             .map(|partition_id| {
-                let partition = Partition::create(
-                    stream_id,
-                    topic.id,
-                    partition_id,
-                    &topic.get_partitions_path(),
-                    true,
-                    config.partition.clone(),
-                    storage.clone(),
-                );
+                let partition = topic.create_partition(partition_id, true);
                 (partition_id, RwLock::new(partition))
             })
             .collect();
@@ -74,6 +108,46 @@ impl Topic {
         topic
     }
 
+    pub fn create_partition(&self, partition_id: u32, with_segment: bool) -> Partition {
+        let mut partition = Partition::create(
+            self.stream_id,
+            self.id,
+            partition_id,
+            &self.get_partitions_path(),
+            with_segment,
+            self.get_partition_config(),
+            self.storage.clone(),
+        );
+        partition.compression = self.compression;
+        partition.deduplicator = self.deduplication.map(MessageDeduplicator::new);
+        partition.set_checksum_policy(self.checksum_policy);
+        partition
+    }
+
+    pub fn get_partition_config(&self) -> Arc<PartitionConfig> {
+        let config = &self.config.partition;
+        if self.overrides == TopicConfigOverrides::default() {
+            return config.clone();
+        }
+
+        Arc::new(PartitionConfig {
+            path: config.path.clone(),
+            segment: Arc::new(SegmentConfig {
+                size_bytes: self
+                    .overrides
+                    .segment_size_bytes
+                    .unwrap_or(config.segment.size_bytes),
+                ..*config.segment.as_ref()
+            }),
+            messages_required_to_save: self
+                .overrides
+                .messages_required_to_save
+                .unwrap_or(config.messages_required_to_save),
+            enforce_sync: self.overrides.enforce_sync.unwrap_or(config.enforce_sync),
+            validate_checksum: config.validate_checksum,
+        })
+    }
+
     pub fn get_partitions(&self) -> Vec<&RwLock<Partition>> {
         self.partitions.values().collect()
     }
@@ -112,6 +186,20 @@ mod tests {
         let topics_path = "/topics";
         let name = "test";
         let partitions_count = 3;
+        let message_expiry = Some(10);
+        let max_topic_size_bytes = Some(1000);
+        let cleanup_policy = CleanupPolicy::Compact;
+        let compression = CompressionAlgorithm::Gzip;
+        let deduplication = Some(DeduplicationWindow {
+            max_ids: Some(100),
+            expiry: None,
+        });
+        let checksum_policy = ChecksumPolicy::Skip;
+        let overrides = TopicConfigOverrides {
+            segment_size_bytes: Some(1000),
+            messages_required_to_save: None,
+            enforce_sync: Some(true),
+        };
         let config = Arc::new(TopicConfig::default());
         let path = Topic::get_path(id, topics_path);
         let info_path = Topic::get_info_path(&path);
@@ -121,8 +209,15 @@ mod tests {
             id,
             name,
             partitions_count,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            checksum_policy,
+            overrides,
             topics_path,
-            config,
+            config.clone(),
             storage,
         );
 
@@ -131,6 +226,13 @@ mod tests {
         assert_eq!(topic.path, path);
         assert_eq!(topic.info_path, info_path);
         assert_eq!(topic.name, name);
+        assert_eq!(topic.message_expiry, message_expiry);
+        assert_eq!(topic.max_topic_size_bytes, max_topic_size_bytes);
+        assert_eq!(topic.cleanup_policy, cleanup_policy);
+        assert_eq!(topic.compression, compression);
+        assert_eq!(topic.deduplication, deduplication);
+        assert_eq!(topic.checksum_policy, checksum_policy);
+        assert_eq!(topic.overrides, overrides);
         assert_eq!(topic.partitions.len(), partitions_count as usize);
 
         for (id, partition) in topic.partitions {
@@ -138,7 +240,16 @@ mod tests {
             assert_eq!(partition.stream_id, stream_id);
             assert_eq!(partition.topic_id, topic.id);
             assert_eq!(partition.id, id);
+            assert_eq!(partition.compression, compression);
+            assert!(partition.deduplicator.is_some());
+            assert_eq!(partition.get_checksum_policy(), checksum_policy);
             assert_eq!(partition.segments.len(), 1);
+            assert_eq!(partition.config.segment.size_bytes, 1000);
+            assert_eq!(
+                partition.config.messages_required_to_save,
+                config.partition.messages_required_to_save
+            );
+            assert!(partition.config.enforce_sync);
         }
     }
 }
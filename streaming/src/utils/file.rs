@@ -9,5 +9,10 @@ pub async fn append(path: &str) -> Result<File, std::io::Error> {
 }
 
 pub async fn write(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().create(true).write(true).open(path).await
+    OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(path)
+        .await
 }
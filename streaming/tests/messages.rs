@@ -2,12 +2,14 @@ mod common;
 
 use crate::common::TestSetup;
 use bytes::Bytes;
+use futures::future::join_all;
 use std::sync::Arc;
-use streaming::config::PartitionConfig;
+use streaming::config::{PartitionConfig, SegmentConfig};
 use streaming::message::Message;
 use streaming::partitions::partition::Partition;
+use streaming::persister::FileHandlePersister;
 use streaming::storage::SystemStorage;
-use streaming::utils::{checksum, timestamp};
+use streaming::utils::timestamp;
 
 #[tokio::test]
 async fn should_persist_messages_and_then_load_them_from_disk() {
@@ -38,8 +40,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         let timestamp = timestamp::get();
         let id = i as u128;
         let payload = Bytes::from(format!("message {}", i));
-        let checksum = checksum::get(&payload);
-        let message = Message::create(offset, timestamp, id, payload, checksum);
+        let message = Message::create(offset, timestamp, id, payload);
         appended_messages.push(message.clone());
         messages.push(message);
     }
@@ -69,8 +70,81 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         assert_eq!(loaded_message.offset, appended_message.offset);
         assert_eq!(loaded_message.timestamp, appended_message.timestamp);
         assert_eq!(loaded_message.id, appended_message.id);
-        assert_eq!(loaded_message.checksum, appended_message.checksum);
         assert_eq!(loaded_message.length, appended_message.length);
         assert_eq!(loaded_message.payload, appended_message.payload);
     }
 }
+
+#[tokio::test]
+async fn should_persist_messages_of_many_partitions_with_open_files_and_group_commit() {
+    let setup = TestSetup::init().await;
+    let persister = Arc::new(FileHandlePersister::new(true));
+    let storage = Arc::new(SystemStorage::new(persister));
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        enforce_sync: true,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1000,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partitions = Vec::new();
+    for partition_id in 1..=3 {
+        let partition = Partition::create(
+            1,
+            1,
+            partition_id,
+            &setup.path,
+            true,
+            config.clone(),
+            storage.clone(),
+        );
+        partition.persist().await.unwrap();
+        partitions.push(partition);
+    }
+
+    join_all(partitions.iter_mut().map(|partition| async move {
+        for i in 1..=12 {
+            let payload = Bytes::from(format!("{:0>200}", i));
+            let message = Message::empty(i, i as u128, payload);
+            partition.append_messages(vec![message]).await.unwrap();
+        }
+    }))
+    .await;
+
+    // The partition is recreated under the same path, so the files of the deleted one must have been closed.
+    partitions[0].delete().await.unwrap();
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=3 {
+        let payload = Bytes::from(format!("{:0>200}", i));
+        let message = Message::empty(i, i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    partitions[0] = partition;
+
+    for partition in partitions.iter() {
+        let mut loaded_partition = Partition::empty(
+            1,
+            1,
+            partition.id,
+            &setup.path,
+            config.clone(),
+            storage.clone(),
+        );
+        loaded_partition.load().await.unwrap();
+        assert_eq!(loaded_partition.current_offset, partition.current_offset);
+        assert_eq!(
+            loaded_partition.get_segments().len(),
+            partition.get_segments().len()
+        );
+        let messages = loaded_partition.get_messages_by_offset(0, 3).await.unwrap();
+        assert_eq!(messages.len(), 3);
+        for (index, message) in messages.iter().enumerate() {
+            assert_eq!(message.offset, index as u64);
+            assert_eq!(message.payload, Bytes::from(format!("{:0>200}", index + 1)));
+        }
+    }
+}
@@ -1,12 +1,27 @@
 mod common;
 
 use crate::common::TestSetup;
-use ringbuffer::RingBuffer;
+use bytes::Bytes;
+use sdk::batch::{MessageBatch, Record};
+use sdk::compression::CompressionAlgorithm;
+use sdk::error::Error;
+use sdk::messages::poll_messages::Kind;
+use sdk::topics::create_topic::{ChecksumPolicy, DeduplicationWindow};
 use std::sync::Arc;
+use streaming::config::{PartitionConfig, SegmentConfig};
+use streaming::message;
+use streaming::message::Message;
+use streaming::migration;
+use streaming::partitions::message_deduplicator::MessageDeduplicator;
 use streaming::partitions::partition::Partition;
+use streaming::polling_consumer::PollingConsumer;
+use streaming::remote_store::ArchiveDirectoryStore;
 use streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
 use streaming::storage::SystemStorage;
+use streaming::utils::timestamp;
 use tokio::fs;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
 
 #[tokio::test]
 async fn should_persist_partition_with_segment() {
@@ -81,14 +96,6 @@ async fn should_load_existing_partition_from_disk() {
             loaded_partition.should_increment_offset,
             partition.should_increment_offset
         );
-        assert_eq!(
-            loaded_partition.messages.is_some(),
-            partition.messages.is_some()
-        );
-        assert_eq!(
-            loaded_partition.messages.unwrap().is_empty(),
-            partition.messages.unwrap().is_empty()
-        );
     }
 }
 
@@ -119,6 +126,1326 @@ async fn should_delete_existing_partition_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_delete_expired_segments_and_start_from_the_new_earliest_offset() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition = Partition::create(1, 1, 1, &setup.path, true, config, storage);
+    partition.persist().await.unwrap();
+    let segments_count = 3;
+    for i in 1..=segments_count {
+        let payload = Bytes::from(format!("message {}", i));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), segments_count);
+    let first_segment_log_path = partition.get_segments()[0].log_path.clone();
+
+    let now = timestamp::get() + 10_000_000;
+    let message_expiry = 1;
+    let start_offsets = partition
+        .get_expired_segments_start_offsets(now, message_expiry)
+        .await
+        .unwrap();
+    assert_eq!(start_offsets, vec![0, 1]);
+    for start_offset in start_offsets {
+        partition.delete_segment(start_offset).await.unwrap();
+    }
+
+    assert_eq!(partition.get_segments().len(), 1);
+    assert!(fs::metadata(&first_segment_log_path).await.is_err());
+    let messages = partition.get_first_messages(10).await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].offset, 2);
+    let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].offset, 2);
+}
+
+#[tokio::test]
+async fn should_compact_segments_keeping_the_newest_message_per_key_and_original_offsets() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    // The empty payload for the message with ID 3 represents the tombstone.
+    let messages = [
+        (1, "a0"),
+        (2, "a1"),
+        (1, "a2"),
+        (2, "a3"),
+        (3, ""),
+        (5, "a5"),
+        (1, "a6"),
+    ];
+    for (id, payload) in messages {
+        let payload = Bytes::from(payload);
+        let message = Message::empty(timestamp::get(), id, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+
+    let removed_messages = partition
+        .compact_segments(timestamp::get(), 1)
+        .await
+        .unwrap();
+    assert_eq!(removed_messages, 3);
+    assert_eq!(partition.get_segments().len(), 2);
+    let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+    assert_offsets(&messages, &[3, 4, 5, 6]);
+
+    let now = timestamp::get() + 10_000_000;
+    let removed_messages = partition.compact_segments(now, 1).await.unwrap();
+    assert_eq!(removed_messages, 1);
+    let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+    assert_offsets(&messages, &[3, 5, 6]);
+    let messages = partition.get_messages_by_offset(4, 2).await.unwrap();
+    assert_offsets(&messages, &[5]);
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.current_offset, 6);
+    assert!(loaded_partition.get_segments()[0].is_closed);
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 10)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &[3, 5, 6]);
+}
+
+#[tokio::test]
+async fn should_compress_closed_segments_and_read_messages_by_offset() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.compression = CompressionAlgorithm::Lz4;
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+
+    for segment in partition.get_segments().iter().take(2) {
+        assert!(segment.is_compressed());
+        assert!(fs::metadata(&segment.block_index_path).await.is_ok());
+        let log_size_bytes = fs::metadata(&segment.log_path).await.unwrap().len();
+        assert!(log_size_bytes < segment.current_size_bytes);
+    }
+    assert!(!partition.get_segments()[2].is_compressed());
+    let messages = partition.get_messages_by_offset(1, 3).await.unwrap();
+    assert_offsets(&messages, &[1, 2, 3]);
+    let messages = partition.get_messages_by_offset(6, 3).await.unwrap();
+    assert_offsets(&messages, &[6, 7, 8]);
+    assert!(messages.iter().all(|message| message.payload.len() == 200));
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.compression = CompressionAlgorithm::Lz4;
+    loaded_partition.load().await.unwrap();
+    assert!(loaded_partition.get_segments()[0].is_compressed());
+    assert_eq!(loaded_partition.current_offset, 11);
+    let messages = loaded_partition.get_messages_by_offset(0, 5).await.unwrap();
+    assert_offsets(&messages, &[0, 1, 2, 3, 4]);
+    let messages = loaded_partition.get_messages_by_offset(7, 5).await.unwrap();
+    assert_offsets(&messages, &[7, 8, 9, 10, 11]);
+}
+
+#[tokio::test]
+async fn should_complete_or_discard_interrupted_compression_when_loading_partition() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+
+    // The first segment is interrupted after saving the block index, and the second one before.
+    for (index, segment) in partition.get_segments_mut().iter_mut().take(2).enumerate() {
+        let log_bytes = fs::read(&segment.log_path).await.unwrap();
+        segment.compress(CompressionAlgorithm::Lz4).await.unwrap();
+        assert!(segment.is_compressed());
+        let compressed_log_path = format!("{}.compressed", segment.log_path);
+        fs::rename(&segment.log_path, &compressed_log_path)
+            .await
+            .unwrap();
+        fs::write(&segment.log_path, &log_bytes).await.unwrap();
+        if index == 1 {
+            fs::remove_file(&segment.block_index_path).await.unwrap();
+        }
+    }
+
+    let mut loaded_partition =
+        Partition::empty(1, 1, 1, &setup.path, config.clone(), storage.clone());
+    loaded_partition.load().await.unwrap();
+    let segments = loaded_partition.get_segments();
+    assert!(segments[0].is_compressed());
+    assert!(!segments[1].is_compressed());
+    for segment in segments.iter().take(2) {
+        assert!(fs::metadata(format!("{}.compressed", segment.log_path))
+            .await
+            .is_err());
+    }
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 12)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &(0..12).collect::<Vec<u64>>());
+
+    // The log which isn't covered by the block index can't be read.
+    let log_path = segments[0].log_path.clone();
+    let log_size_bytes = fs::metadata(&log_path).await.unwrap().len();
+    let log_file = OpenOptions::new()
+        .write(true)
+        .open(&log_path)
+        .await
+        .unwrap();
+    log_file.set_len(log_size_bytes - 1).await.unwrap();
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    let result = loaded_partition.load().await;
+    assert!(matches!(result, Err(Error::CannotReadBlockIndex(_))));
+}
+
+#[tokio::test]
+async fn should_overwrite_compressed_segment_with_uncompressed_messages() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.compression = CompressionAlgorithm::Lz4;
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    let segment = &mut partition.get_segments_mut()[0];
+    assert!(segment.is_compressed());
+    let messages = segment.get_all_messages().await.unwrap();
+    segment.overwrite_messages(&messages[1..]).await.unwrap();
+    assert!(!segment.is_compressed());
+    assert!(fs::metadata(&segment.block_index_path).await.is_err());
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    assert!(!loaded_partition.get_segments()[0].is_compressed());
+    let messages = loaded_partition.get_messages_by_offset(0, 6).await.unwrap();
+    assert_offsets(&messages, &[1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn should_truncate_torn_write_and_rebuild_indexes_when_loading_partition() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=3 {
+        let payload = Bytes::from(format!("message {}", i));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    // The complete message without the indexes, followed by the partially written one.
+    let segment = &partition.get_segments()[0];
+    let mut bytes = Vec::new();
+    for offset in 3..=4 {
+        let payload = Bytes::from(format!("message {}", offset + 1));
+        let message = Message::create(offset, timestamp::get(), offset as u128, payload);
+        message::create_batch(&[Arc::new(message)]).extend(&mut bytes);
+    }
+    let valid_size_bytes = segment.current_size_bytes as usize + bytes.len() / 2;
+    let mut log_file = OpenOptions::new()
+        .append(true)
+        .open(&segment.log_path)
+        .await
+        .unwrap();
+    log_file.write_all(&bytes[..bytes.len() - 5]).await.unwrap();
+    log_file.sync_all().await.unwrap();
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+
+    let segment = &loaded_partition.get_segments()[0];
+    let log_size_bytes = fs::metadata(&segment.log_path).await.unwrap().len();
+    let index_size_bytes = fs::metadata(&segment.index_path).await.unwrap().len();
+    assert_eq!(log_size_bytes, valid_size_bytes as u64);
+    assert_eq!(index_size_bytes, 4 * 12);
+    assert_eq!(loaded_partition.current_offset, 3);
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 10)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &[0, 1, 2, 3]);
+
+    let payload = Bytes::from("message 5");
+    let message = Message::empty(timestamp::get(), 5, payload);
+    loaded_partition
+        .append_messages(vec![message])
+        .await
+        .unwrap();
+    let messages = loaded_partition.get_messages_by_offset(4, 1).await.unwrap();
+    assert_offsets(&messages, &[4]);
+}
+
+#[tokio::test]
+async fn should_rebuild_missing_and_corrupted_indexes_when_loading_partition() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+
+    let segments = partition.get_segments();
+    fs::remove_file(&segments[0].index_path).await.unwrap();
+    fs::remove_file(&segments[0].time_index_path).await.unwrap();
+    let time_index_file = OpenOptions::new()
+        .write(true)
+        .open(&segments[1].time_index_path)
+        .await
+        .unwrap();
+    time_index_file.set_len(7).await.unwrap();
+    fs::write(&segments[1].index_path, [0; 16]).await.unwrap();
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+
+    for segment in loaded_partition.get_segments().iter().take(2) {
+        let index_size_bytes = fs::metadata(&segment.index_path).await.unwrap().len();
+        let time_index_size_bytes = fs::metadata(&segment.time_index_path).await.unwrap().len();
+        assert_eq!(index_size_bytes, 5 * 12);
+        assert_eq!(time_index_size_bytes, 5 * 12);
+    }
+    assert_eq!(loaded_partition.current_offset, 11);
+    let messages = loaded_partition.get_messages_by_offset(1, 3).await.unwrap();
+    assert_offsets(&messages, &[1, 2, 3]);
+    let messages = loaded_partition.get_messages_by_offset(5, 3).await.unwrap();
+    assert_offsets(&messages, &[5, 6, 7]);
+}
+
+#[tokio::test]
+async fn should_rebuild_indexes_on_demand_including_buffered_messages() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 3,
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=5 {
+        let payload = Bytes::from(format!("message {}", i));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    let segment = &partition.get_segments()[0];
+    fs::remove_file(&segment.index_path).await.unwrap();
+    fs::write(&segment.time_index_path, []).await.unwrap();
+
+    partition.rebuild_indexes().await.unwrap();
+
+    let segment = &partition.get_segments()[0];
+    let index_size_bytes = fs::metadata(&segment.index_path).await.unwrap().len();
+    let time_index_size_bytes = fs::metadata(&segment.time_index_path).await.unwrap().len();
+    assert_eq!(index_size_bytes, 5 * 12);
+    assert_eq!(time_index_size_bytes, 5 * 12);
+    assert_eq!(partition.unsaved_messages_count, 0);
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.current_offset, 4);
+    let messages = loaded_partition.get_messages_by_offset(0, 5).await.unwrap();
+    assert_offsets(&messages, &[0, 1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn should_read_messages_by_offset_using_sparse_index() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            index_interval_bytes: 250,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=10 {
+        // Each message takes 100 bytes including the batch header.
+        let payload = Bytes::from("a".repeat(31));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    let segment = &partition.get_segments()[0];
+    let index_size_bytes = fs::metadata(&segment.index_path).await.unwrap().len();
+    let time_index_size_bytes = fs::metadata(&segment.time_index_path).await.unwrap().len();
+    assert_eq!(index_size_bytes, 4 * 12);
+    assert_eq!(time_index_size_bytes, 10 * 12);
+    let messages = partition.get_messages_by_offset(3, 4).await.unwrap();
+    assert_offsets(&messages, &[3, 4, 5, 6]);
+
+    let config = Arc::new(PartitionConfig {
+        segment: Arc::new(SegmentConfig {
+            index_interval_bytes: 250,
+            cache_indexes: false,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    let segment = &loaded_partition.get_segments()[0];
+    let index_size_bytes = fs::metadata(&segment.index_path).await.unwrap().len();
+    assert_eq!(index_size_bytes, 4 * 12);
+    assert_eq!(loaded_partition.current_offset, 9);
+    let messages = loaded_partition.get_messages_by_offset(1, 1).await.unwrap();
+    assert_offsets(&messages, &[1]);
+    let messages = loaded_partition.get_messages_by_offset(7, 3).await.unwrap();
+    assert_offsets(&messages, &[7, 8, 9]);
+}
+
+#[tokio::test]
+async fn should_migrate_v1_logs_and_indexes_and_read_messages_by_offset() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let streams_path = format!("{}/streams", setup.path);
+    let partitions_path = format!("{}/1/topics/1/partitions", streams_path);
+    fs::create_dir_all(&partitions_path).await.unwrap();
+    let mut partition = Partition::create(
+        1,
+        1,
+        1,
+        &partitions_path,
+        true,
+        config.clone(),
+        storage.clone(),
+    );
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    let messages = partition.get_messages_by_offset(0, 12).await.unwrap();
+    assert_eq!(partition.get_segments().len(), 3);
+
+    // Version 1 stored each message with its own checksum, the index positions as u32
+    // and the timestamp of each message in the time index.
+    for segment in partition.get_segments() {
+        let mut log_bytes = Vec::new();
+        let mut index_bytes = Vec::new();
+        let mut time_index_bytes = Vec::new();
+        for message in messages.iter().filter(|message| {
+            message.offset >= segment.start_offset && message.offset <= segment.current_offset
+        }) {
+            index_bytes.extend((log_bytes.len() as u32).to_le_bytes());
+            time_index_bytes.extend(message.timestamp.to_le_bytes());
+            log_bytes.extend(message.offset.to_le_bytes());
+            log_bytes.extend(message.timestamp.to_le_bytes());
+            log_bytes.extend(message.id.to_le_bytes());
+            log_bytes.extend(0u32.to_le_bytes());
+            log_bytes.extend(message.length.to_le_bytes());
+            log_bytes.extend(&message.payload);
+        }
+        fs::write(&segment.log_path, &log_bytes).await.unwrap();
+        fs::write(&segment.index_path, &index_bytes).await.unwrap();
+        fs::write(&segment.time_index_path, &time_index_bytes)
+            .await
+            .unwrap();
+    }
+
+    // The migration is run twice, as it might have been interrupted before the version was saved.
+    let version_path = format!("{}/version", setup.path);
+    for _ in 0..2 {
+        let _ = fs::remove_file(&version_path).await;
+        migration::migrate(&setup.path, &streams_path)
+            .await
+            .unwrap();
+    }
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &partitions_path, config, storage.clone());
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.get_segments().len(), 3);
+    assert_eq!(loaded_partition.current_offset, 11);
+    for segment in loaded_partition.get_segments() {
+        let index_size_bytes = fs::metadata(&segment.index_path).await.unwrap().len();
+        let time_index_size_bytes = fs::metadata(&segment.time_index_path).await.unwrap().len();
+        let messages_count = segment.current_offset - segment.start_offset + 1;
+        // The converted messages of the segment fit into a single batch.
+        assert_eq!(index_size_bytes, 12);
+        assert_eq!(time_index_size_bytes, messages_count * 12);
+    }
+    for segment in loaded_partition.get_segments() {
+        storage.segment.load_checksums(segment).await.unwrap();
+    }
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, 12)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), messages.len());
+    for (loaded_message, message) in loaded_messages.iter().zip(messages.iter()) {
+        assert_eq!(loaded_message.offset, message.offset);
+        assert_eq!(loaded_message.timestamp, message.timestamp);
+        assert_eq!(loaded_message.id, message.id);
+        assert_eq!(loaded_message.payload, message.payload);
+    }
+}
+
+#[tokio::test]
+async fn should_read_messages_by_timestamp_with_and_without_cached_time_indexes() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(i * 1000, i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+    assert_timestamp_lookups(&partition).await;
+
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 10,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            cache_time_indexes: false,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    assert_timestamp_lookups(&loaded_partition).await;
+
+    // The messages which weren't saved yet are missing in the time index file.
+    let payload = Bytes::from("a".repeat(200));
+    let message = Message::empty(13000, 13, payload);
+    loaded_partition
+        .append_messages(vec![message])
+        .await
+        .unwrap();
+    let messages = loaded_partition
+        .get_messages_by_timestamp(12500, 10)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &[12]);
+}
+
+async fn assert_timestamp_lookups(partition: &Partition) {
+    let messages = partition.get_messages_by_timestamp(0, 2).await.unwrap();
+    assert_offsets(&messages, &[0, 1]);
+    let messages = partition.get_messages_by_timestamp(3000, 2).await.unwrap();
+    assert_offsets(&messages, &[2, 3]);
+    let messages = partition.get_messages_by_timestamp(5500, 2).await.unwrap();
+    assert_offsets(&messages, &[5, 6]);
+    let messages = partition.get_messages_by_timestamp(11001, 5).await.unwrap();
+    assert_offsets(&messages, &[11]);
+    let messages = partition.get_messages_by_timestamp(12001, 5).await.unwrap();
+    assert!(messages.is_empty());
+}
+
+#[tokio::test]
+async fn should_archive_old_segments_and_read_them_back_on_demand() {
+    let setup = TestSetup::init().await;
+    let archive_path = format!("{}/archive", setup.path);
+    let remote = Arc::new(ArchiveDirectoryStore::new(&archive_path));
+    let storage = Arc::new(SystemStorage {
+        remote: Some(remote.clone()),
+        ..SystemStorage::default()
+    });
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(i * 1000, i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    let now = timestamp::get();
+    let archived_segments = partition
+        .archive_segments(now, 60, remote.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(archived_segments, 2);
+    for segment in partition.get_segments().iter().take(2) {
+        assert!(segment.is_archived);
+        assert!(fs::metadata(&segment.log_path).await.is_err());
+        assert!(fs::metadata(&segment.index_path).await.is_ok());
+        let archived_log_path = format!("{}/{}", archive_path, segment.get_remote_key());
+        assert!(fs::metadata(&archived_log_path).await.is_ok());
+    }
+    assert!(!partition.get_segments()[2].is_archived);
+
+    // The compaction skips the archived segments, without downloading their logs.
+    partition.compact_segments(now, 0).await.unwrap();
+    for segment in partition.get_segments().iter().take(2) {
+        assert!(segment.is_archived);
+        assert!(fs::metadata(&segment.log_path).await.is_err());
+    }
+
+    let messages = partition.get_messages_by_offset(1, 3).await.unwrap();
+    assert_offsets(&messages, &[1, 2, 3]);
+    assert!(fs::metadata(&partition.get_segments()[0].log_path)
+        .await
+        .is_ok());
+
+    let mut loaded_partition =
+        Partition::empty(1, 1, 1, &setup.path, config.clone(), storage.clone());
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.get_segments().len(), 3);
+    assert!(loaded_partition.get_segments()[0].is_archived);
+    assert!(loaded_partition.get_segments()[1].is_archived);
+    assert_eq!(loaded_partition.current_offset, 11);
+    let messages = loaded_partition.get_messages_by_offset(6, 3).await.unwrap();
+    assert_offsets(&messages, &[6, 7, 8]);
+
+    // The logs downloaded for reading are removed again, without uploading them.
+    let archived_segments = loaded_partition
+        .archive_segments(now, 60, remote.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(archived_segments, 0);
+    for segment in loaded_partition.get_segments().iter().take(2) {
+        assert!(fs::metadata(&segment.log_path).await.is_err());
+    }
+
+    let segment = &loaded_partition.get_segments()[0];
+    let archived_log_path = format!("{}/{}", archive_path, segment.get_remote_key());
+    loaded_partition.delete_segment(0).await.unwrap();
+    assert!(fs::metadata(&archived_log_path).await.is_err());
+}
+
+#[tokio::test]
+async fn should_keep_partition_in_memory_and_load_it_back_without_creating_files() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::memory());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            index_interval_bytes: 500,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(i * 1000, i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    partition
+        .store_offset(PollingConsumer::Consumer(1), 7)
+        .await
+        .unwrap();
+    assert_eq!(partition.get_segments().len(), 3);
+    let messages = partition.get_messages_by_offset(6, 3).await.unwrap();
+    assert_offsets(&messages, &[6, 7, 8]);
+    assert_timestamp_lookups(&partition).await;
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.get_segments().len(), 3);
+    assert_eq!(loaded_partition.current_offset, 11);
+    let offset = loaded_partition
+        .get_offset(PollingConsumer::Consumer(1))
+        .await
+        .unwrap();
+    assert_eq!(offset, 7);
+    let messages = loaded_partition.get_messages_by_offset(6, 3).await.unwrap();
+    assert_offsets(&messages, &[6, 7, 8]);
+    assert_timestamp_lookups(&loaded_partition).await;
+    assert!(fs::metadata(&partition.path).await.is_err());
+}
+
+#[tokio::test]
+async fn should_reject_duplicated_message_ids_within_window_also_once_partition_is_loaded() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let window = DeduplicationWindow {
+        max_ids: Some(3),
+        expiry: Some(3600),
+    };
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.deduplicator = Some(MessageDeduplicator::new(window));
+    partition.persist().await.unwrap();
+    for id in 1..=12 {
+        let payload = Bytes::from("a".repeat(200));
+        let message = Message::empty(timestamp::get(), id, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+
+    // The whole batch is rejected, even though only one of the messages is duplicated.
+    let error = partition
+        .append_messages(get_messages_with_ids(&[13, 12]))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::DuplicateMessageId(12, 1)));
+    assert_eq!(partition.current_offset, 11);
+
+    // The ID is out of the window of the newest 3 IDs.
+    partition
+        .append_messages(get_messages_with_ids(&[5]))
+        .await
+        .unwrap();
+    assert_eq!(partition.current_offset, 12);
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.deduplicator = Some(MessageDeduplicator::new(window));
+    loaded_partition.load().await.unwrap();
+    assert_eq!(
+        loaded_partition
+            .deduplicator
+            .as_ref()
+            .unwrap()
+            .get_ids_count(),
+        3
+    );
+    for id in [11, 12, 5] {
+        let error = loaded_partition
+            .append_messages(get_messages_with_ids(&[id]))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, Error::DuplicateMessageId(duplicated_id, 1) if duplicated_id == id)
+        );
+    }
+
+    loaded_partition
+        .append_messages(get_messages_with_ids(&[10]))
+        .await
+        .unwrap();
+    assert_eq!(loaded_partition.current_offset, 13);
+}
+
+#[tokio::test]
+async fn should_store_sent_batch_as_it_is_with_only_offsets_and_timestamps_assigned() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        ..Default::default()
+    });
+    let mut partition = Partition::create(1, 1, 1, &setup.path, true, config, storage);
+    partition.persist().await.unwrap();
+    partition
+        .append_messages(get_messages_with_ids(&[1]))
+        .await
+        .unwrap();
+
+    let sent_batch = get_sent_batch(&[2, 3, 4]);
+    let sent_compressed_batch = get_sent_batch(&[5, 6])
+        .compress(CompressionAlgorithm::Gzip)
+        .unwrap();
+    for batch in [sent_batch.clone(), sent_compressed_batch] {
+        let messages = get_batch_messages(&batch);
+        partition.append_batch(messages, batch).await.unwrap();
+    }
+
+    let segment = &partition.get_segments()[0];
+    let log_bytes = Bytes::from(fs::read(&segment.log_path).await.unwrap());
+    let batches = MessageBatch::read_all(&log_bytes).unwrap();
+    assert_eq!(batches.len(), 3);
+    let stored_batch = &batches[1];
+    assert_eq!(stored_batch.header.base_offset, 1);
+    assert_eq!(stored_batch.header.attributes, sent_batch.header.attributes);
+    assert_eq!(stored_batch.header.crc, sent_batch.header.crc);
+    assert_eq!(stored_batch.records, sent_batch.records);
+
+    // The batch compressed with the codec other than the one of the topic is created again from the messages.
+    let stored_batch = &batches[2];
+    assert!(!stored_batch.is_compressed());
+    assert_eq!(stored_batch.header.attributes, 0);
+    assert_eq!(stored_batch.header.base_offset, 4);
+    assert_eq!(stored_batch.header.records_count, 2);
+
+    let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+    assert_offsets(&messages, &[0, 1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn should_store_sent_batch_compressed_with_the_codec_of_the_topic() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.compression = CompressionAlgorithm::Gzip;
+    partition.persist().await.unwrap();
+    let sent_batch = get_sent_batch(&[1, 2, 3])
+        .compress(CompressionAlgorithm::Gzip)
+        .unwrap();
+    let messages = get_batch_messages(&sent_batch);
+    partition
+        .append_batch(messages, sent_batch.clone())
+        .await
+        .unwrap();
+
+    let segment = &partition.get_segments()[0];
+    let log_bytes = Bytes::from(fs::read(&segment.log_path).await.unwrap());
+    let batches = MessageBatch::read_all(&log_bytes).unwrap();
+    assert_eq!(batches.len(), 1);
+    assert!(batches[0].is_compressed());
+    assert_eq!(batches[0].header.crc, sent_batch.header.crc);
+    assert_eq!(batches[0].records, sent_batch.records);
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 10)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &[0, 1, 2]);
+}
+
+#[tokio::test]
+async fn should_read_closed_segments_from_memory_maps_and_remap_them_after_compaction() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 200,
+            mmap_reads: true,
+            mmap_max_segments: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    let messages = [
+        (1, "a0"),
+        (2, "a1"),
+        (1, "a2"),
+        (2, "a3"),
+        (5, "a4"),
+        (6, "a5"),
+        (7, "a6"),
+    ];
+    for (id, payload) in messages {
+        let payload = Bytes::from(payload);
+        let message = Message::empty(timestamp::get(), id, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+
+    // Only a single segment is mapped at once, so reading across the segments keeps remapping them.
+    let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+    assert_offsets(&messages, &[0, 1, 2, 3, 4, 5, 6]);
+    let messages = partition.get_messages_by_offset(1, 2).await.unwrap();
+    assert_offsets(&messages, &[1, 2]);
+    assert!(messages.iter().all(|message| message.payload.len() == 2));
+
+    let removed_messages = partition
+        .compact_segments(timestamp::get(), 1)
+        .await
+        .unwrap();
+    assert_eq!(removed_messages, 2);
+    let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+    assert_offsets(&messages, &[2, 3, 4, 5, 6]);
+    assert_eq!(messages[0].payload, Bytes::from("a2"));
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 10)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &[2, 3, 4, 5, 6]);
+}
+
+#[tokio::test]
+async fn should_find_log_slices_of_persisted_messages_matching_polled_messages() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for i in 1..=12 {
+        let payload = Bytes::from(format!("{}", i).repeat(200));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+
+    let consumer = PollingConsumer::Consumer(1);
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::Offset, 3, 5, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(log_slices.len(), 2);
+    let messages = partition.get_messages_by_offset(3, 5).await.unwrap();
+    assert_offsets(&messages, &[3, 4, 5, 6, 7]);
+    // Each message was appended on its own, thus stored in a separate batch.
+    let mut expected_bytes = Vec::new();
+    for message in &messages {
+        message::create_batch(std::slice::from_ref(message)).extend(&mut expected_bytes);
+    }
+    let mut bytes = Vec::new();
+    for log_slice in &log_slices {
+        log_slice.extend(&mut bytes).await.unwrap();
+    }
+    assert_eq!(bytes, expected_bytes);
+
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::Last, 0, 2, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    let offsets = log_slices
+        .iter()
+        .flat_map(|log_slice| {
+            log_slice
+                .batches
+                .iter()
+                .map(|batch| batch.header.base_offset)
+        })
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![10, 11]);
+
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::Offset, 0, 2, 10_000)
+        .await
+        .unwrap();
+    assert!(log_slices.is_none());
+
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 100,
+        ..Default::default()
+    });
+    let mut partition = Partition::create(1, 1, 2, &setup.path, true, config, storage);
+    partition.persist().await.unwrap();
+    let payload = Bytes::from("unsaved");
+    let message = Message::empty(timestamp::get(), 1, payload);
+    partition.append_messages(vec![message]).await.unwrap();
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::First, 0, 1, 0)
+        .await
+        .unwrap();
+    assert!(log_slices.is_none());
+}
+
+#[tokio::test]
+async fn should_trim_log_slice_batches_containing_messages_outside_of_polled_range() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        ..Default::default()
+    });
+    let mut partition = Partition::create(1, 1, 1, &setup.path, true, config, storage);
+    partition.persist().await.unwrap();
+    let timestamp = timestamp::get();
+    let messages = (1..=5)
+        .map(|i| Message::empty(timestamp, i as u128, Bytes::from(format!("message {}", i))))
+        .collect();
+    partition.append_messages(messages).await.unwrap();
+
+    let consumer = PollingConsumer::Consumer(1);
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::Offset, 0, 5, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    let batches = &log_slices[0].batches;
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].header.records_count, 5);
+    assert!(batches[0].trimmed.is_none());
+
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::Offset, 1, 2, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    let batches = &log_slices[0].batches;
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].header.base_offset, 1);
+    assert_eq!(batches[0].header.get_last_offset(), 2);
+    assert!(batches[0].trimmed.is_some());
+    let messages = partition.get_messages_by_offset(1, 2).await.unwrap();
+    let mut expected_bytes = Vec::new();
+    message::create_batch(&messages).extend(&mut expected_bytes);
+    let mut bytes = Vec::new();
+    log_slices[0].extend(&mut bytes).await.unwrap();
+    assert_eq!(bytes, expected_bytes);
+}
+
+#[tokio::test]
+async fn should_handle_corrupted_persisted_messages_according_to_checksum_policy() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        ..Default::default()
+    });
+    let mut partition = Partition::create(1, 1, 1, &setup.path, true, config, storage);
+    partition.persist().await.unwrap();
+    for id in 1..=3 {
+        let message = Message::empty(timestamp::get(), id, Bytes::from("message"));
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    // Flip the first payload byte of the second batch, each message was stored in a separate batch.
+    let segment = &partition.get_segments()[0];
+    let batch_size = segment.current_size_bytes / 3;
+    let position = batch_size + batch_size - "message".len() as u64;
+    let mut log_bytes = fs::read(&segment.log_path).await.unwrap();
+    log_bytes[position as usize] ^= 0xFF;
+    fs::write(&segment.log_path, log_bytes).await.unwrap();
+
+    let messages = partition.get_messages_by_offset(0, 3).await.unwrap();
+    assert_offsets(&messages, &[0, 1, 2]);
+    assert_eq!(partition.get_corrupted_messages_count(), 0);
+
+    partition.set_checksum_policy(ChecksumPolicy::Fail);
+    let result = partition.get_messages_by_offset(0, 3).await;
+    assert!(matches!(result, Err(Error::InvalidBatchChecksum(_, _, _))));
+    assert_eq!(partition.get_corrupted_messages_count(), 1);
+
+    partition.set_checksum_policy(ChecksumPolicy::Skip);
+    let consumer = PollingConsumer::Consumer(1);
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::Offset, 0, 3, 0)
+        .await
+        .unwrap();
+    assert!(log_slices.is_none());
+    let messages = partition.get_messages_by_offset(0, 3).await.unwrap();
+    assert_offsets(&messages, &[0, 2]);
+    assert_eq!(partition.get_corrupted_messages_count(), 1);
+
+    partition.set_checksum_policy(ChecksumPolicy::Log);
+    let messages = partition.get_messages_by_offset(0, 3).await.unwrap();
+    assert_offsets(&messages, &[0, 1, 2]);
+    assert_eq!(partition.get_corrupted_messages_count(), 1);
+}
+
+#[tokio::test]
+async fn should_purge_partition_starting_fresh_segment_and_deleting_consumer_offsets() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 5,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let window = DeduplicationWindow {
+        max_ids: Some(100),
+        expiry: None,
+    };
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.deduplicator = Some(MessageDeduplicator::new(window));
+    partition.persist().await.unwrap();
+    for id in 1..=12 {
+        partition
+            .append_messages(get_messages_with_ids(&[id]))
+            .await
+            .unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+    assert!(partition.unsaved_messages_count > 0);
+    let consumer = PollingConsumer::Consumer(1);
+    let consumer_group = PollingConsumer::ConsumerGroup(1, 1);
+    partition.store_offset(consumer, 10).await.unwrap();
+    partition.store_offset(consumer_group, 5).await.unwrap();
+
+    partition.purge().await.unwrap();
+
+    let segments = partition.get_segments();
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].start_offset, 0);
+    assert_eq!(partition.current_offset, 0);
+    assert_eq!(partition.unsaved_messages_count, 0);
+    assert_eq!(partition.get_size_bytes(), 0);
+    assert!(partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(partition.get_offset(consumer).await.unwrap(), 0);
+    assert_eq!(partition.get_offset(consumer_group).await.unwrap(), 0);
+    for offsets_path in [
+        &partition.consumer_offsets_path,
+        &partition.consumer_group_offsets_path,
+    ] {
+        let mut dir_entries = fs::read_dir(offsets_path).await.unwrap();
+        assert!(dir_entries.next_entry().await.unwrap().is_none());
+    }
+
+    // The IDs of the purged messages can be used again.
+    partition
+        .append_messages(get_messages_with_ids(&[8, 9, 10, 11, 12]))
+        .await
+        .unwrap();
+    assert_eq!(partition.unsaved_messages_count, 0);
+    let messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+    assert_offsets(&messages, &[0, 1, 2, 3, 4]);
+
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.deduplicator = Some(MessageDeduplicator::new(window));
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.get_segments().len(), 1);
+    assert_eq!(loaded_partition.current_offset, 4);
+    assert_eq!(loaded_partition.get_offset(consumer).await.unwrap(), 0);
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &[0, 1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn should_delete_messages_before_offset_and_keep_log_start_offset_once_partition_is_loaded() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(PartitionConfig {
+        messages_required_to_save: 1,
+        segment: Arc::new(SegmentConfig {
+            size_bytes: 1200,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut partition =
+        Partition::create(1, 1, 1, &setup.path, true, config.clone(), storage.clone());
+    partition.persist().await.unwrap();
+    for id in 1..=12 {
+        partition
+            .append_messages(get_messages_with_ids(&[id]))
+            .await
+            .unwrap();
+    }
+    assert_eq!(partition.get_segments().len(), 3);
+    let second_segment_start_offset = partition.get_segments()[1].start_offset;
+    let log_start_offset = second_segment_start_offset + 1;
+
+    let error = partition.delete_messages_before(12).await.unwrap_err();
+    assert!(matches!(error, Error::InvalidOffset(12)));
+
+    // Only the first segment is fully covered, the older messages of the second one are skipped.
+    let deleted_segments = partition
+        .delete_messages_before(log_start_offset)
+        .await
+        .unwrap();
+    assert_eq!(deleted_segments, 1);
+    assert_eq!(partition.log_start_offset, log_start_offset);
+    assert_eq!(partition.get_segments().len(), 2);
+    assert_eq!(
+        partition.get_segments()[0].start_offset,
+        second_segment_start_offset
+    );
+    assert_eq!(partition.delete_messages_before(1).await.unwrap(), 0);
+    assert_eq!(partition.log_start_offset, log_start_offset);
+
+    let expected_offsets = (log_start_offset..=11).collect::<Vec<u64>>();
+    let messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+    assert_offsets(&messages, &expected_offsets);
+    let messages = partition.get_first_messages(100).await.unwrap();
+    assert_offsets(&messages, &expected_offsets);
+    let consumer = PollingConsumer::Consumer(1);
+    let messages = partition.get_next_messages(consumer, 100).await.unwrap();
+    assert_offsets(&messages, &expected_offsets);
+    let log_slices = partition
+        .get_log_slices(consumer, Kind::Offset, 0, 1, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    let offsets = log_slices
+        .iter()
+        .flat_map(|log_slice| {
+            log_slice
+                .batches
+                .iter()
+                .map(|batch| batch.header.base_offset)
+        })
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![log_start_offset]);
+
+    let mut loaded_partition =
+        Partition::empty(1, 1, 1, &setup.path, config.clone(), storage.clone());
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.log_start_offset, log_start_offset);
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_offsets(&messages, &expected_offsets);
+
+    // The purged partition starts from the offset 0 again.
+    loaded_partition.purge().await.unwrap();
+    assert_eq!(loaded_partition.log_start_offset, 0);
+    let mut loaded_partition = Partition::empty(1, 1, 1, &setup.path, config, storage);
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.log_start_offset, 0);
+}
+
+fn get_messages_with_ids(ids: &[u128]) -> Vec<Message> {
+    ids.iter()
+        .map(|id| Message::empty(timestamp::get(), *id, Bytes::from("a".repeat(200))))
+        .collect()
+}
+
+// The batch is encoded the same way as by the client, with the offsets and timestamps assigned by the server.
+// The attributes beyond the codec are set, so that the batch stored as it is can be told apart from the created one.
+fn get_sent_batch(ids: &[u128]) -> MessageBatch {
+    let records = ids
+        .iter()
+        .enumerate()
+        .map(|(index, id)| Record {
+            offset_delta: index as u32,
+            timestamp_delta: 0,
+            id: *id,
+            payload: Bytes::from(format!("message {}", id)),
+        })
+        .collect();
+    MessageBatch::new(0, 0, 0, 0b1000_0000, records)
+}
+
+fn get_batch_messages(batch: &MessageBatch) -> Vec<Message> {
+    let timestamp = timestamp::get();
+    batch
+        .records
+        .iter()
+        .map(|record| Message::empty(timestamp, record.id, record.payload.clone()))
+        .collect()
+}
+
+fn assert_offsets(messages: &[Arc<Message>], offsets: &[u64]) {
+    let message_offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(message_offsets, offsets);
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     let offsets_path = format!("{}/offsets", partition_path);
     let consumer_offsets_path = format!("{}/consumers", offsets_path);
@@ -60,6 +60,41 @@ async fn should_load_existing_stream_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_update_existing_stream_and_load_it_from_disk() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let stream_ids = get_stream_ids();
+    for stream_id in stream_ids {
+        let name = format!("test-{}", stream_id);
+        let mut stream = Stream::create(
+            stream_id,
+            &name,
+            &setup.path,
+            setup.config.stream.clone(),
+            storage.clone(),
+        );
+        stream.persist().await.unwrap();
+
+        let updated_name = format!("updated-{}", stream_id);
+        stream.update(&updated_name).await.unwrap();
+        assert_eq!(stream.name, updated_name);
+        assert!(fs::metadata(format!("{}.tmp", stream.info_path))
+            .await
+            .is_err());
+
+        let mut loaded_stream = Stream::empty(
+            stream_id,
+            &setup.path,
+            setup.config.stream.clone(),
+            storage.clone(),
+        );
+        loaded_stream.load().await.unwrap();
+
+        assert_eq!(loaded_stream.name, updated_name);
+    }
+}
+
 #[tokio::test]
 async fn should_delete_existing_stream_from_disk() {
     let setup = TestSetup::init().await;
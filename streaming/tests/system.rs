@@ -1,8 +1,21 @@
 mod common;
 
 use crate::common::TestSetup;
+use sdk::compression::CompressionAlgorithm;
+use sdk::error::Error;
+use sdk::identifier::Identifier;
+use sdk::topics::create_topic::{ChecksumPolicy, CleanupPolicy, TopicConfigOverrides};
+use std::path::Path;
+use std::sync::Arc;
+use streaming::config::{StorageConfig, StorageKind, SystemConfig};
+use streaming::migration::STORAGE_VERSION;
+use streaming::partitions::partition::Partition;
+use streaming::polling_consumer::PollingConsumer;
+use streaming::segments::segment;
 use streaming::system::System;
+use streaming::topics::topic::Topic;
 use tokio::fs;
+use tokio::sync::RwLockReadGuard;
 
 #[tokio::test]
 async fn should_initialize_system_and_base_directories() {
@@ -11,16 +24,31 @@ async fn should_initialize_system_and_base_directories() {
 
     system.init().await.unwrap();
 
-    let mut dir_entries = fs::read_dir(&setup.config.path).await.unwrap();
-    let entry = dir_entries.next_entry().await.unwrap();
-    assert!(entry.is_some());
-    let entry = entry.unwrap();
-    let metadata = entry.metadata().await.unwrap();
+    let streams_path = format!("{}/{}", setup.config.path, setup.config.stream.path);
+    let metadata = fs::metadata(&streams_path).await.unwrap();
     assert!(metadata.is_dir());
-    assert_eq!(
-        entry.file_name().into_string().unwrap(),
-        setup.config.stream.path
-    );
+    let version = fs::read(format!("{}/version", setup.config.path))
+        .await
+        .unwrap();
+    assert_eq!(version, STORAGE_VERSION.to_le_bytes());
+}
+
+#[tokio::test]
+async fn should_not_initialize_system_with_newer_storage_version() {
+    let setup = TestSetup::init().await;
+    let mut system = System::create(setup.config.clone());
+    system.init().await.unwrap();
+    fs::write(
+        format!("{}/version", setup.config.path),
+        (STORAGE_VERSION + 1).to_le_bytes(),
+    )
+    .await
+    .unwrap();
+
+    let mut system = System::create(setup.config.clone());
+    let result = system.init().await;
+
+    assert!(matches!(result, Err(Error::InvalidStorageVersion(_, _))));
 }
 
 #[tokio::test]
@@ -31,7 +59,10 @@ async fn should_create_and_persist_stream() {
     let stream_name = "test";
     system.init().await.unwrap();
 
-    system.create_stream(stream_id, stream_name).await.unwrap();
+    system
+        .create_stream(Some(stream_id), stream_name)
+        .await
+        .unwrap();
 
     assert_persisted_stream(&system.streams_path, stream_id).await;
 }
@@ -43,15 +74,331 @@ async fn should_delete_persisted_stream() {
     let stream_id = 1;
     let stream_name = "test";
     system.init().await.unwrap();
-    system.create_stream(stream_id, stream_name).await.unwrap();
+    system
+        .create_stream(Some(stream_id), stream_name)
+        .await
+        .unwrap();
     assert_persisted_stream(&system.streams_path, stream_id).await;
-    let stream_path = system.get_stream(stream_id).unwrap().path.clone();
+    let stream_path = system
+        .get_stream(&Identifier::numeric(stream_id))
+        .unwrap()
+        .path
+        .clone();
 
-    system.delete_stream(stream_id).await.unwrap();
+    system
+        .delete_stream(&Identifier::numeric(stream_id))
+        .await
+        .unwrap();
 
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn should_keep_streams_in_memory_without_creating_base_directories() {
+    let setup = TestSetup::init().await;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.clone(),
+        storage: Arc::new(StorageConfig {
+            kind: StorageKind::Memory,
+            memory_streams: Vec::new(),
+        }),
+        ..Default::default()
+    });
+    let mut system = System::create(config);
+    system.init().await.unwrap();
+
+    system.create_stream(Some(1), "test").await.unwrap();
+    let stream = system.get_stream_mut(&Identifier::numeric(1)).unwrap();
+    stream
+        .create_topic(
+            Some(1),
+            "test",
+            2,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        stream
+            .get_topic(&Identifier::numeric(1))
+            .unwrap()
+            .get_partitions()
+            .len(),
+        2
+    );
+    system.delete_stream(&Identifier::numeric(1)).await.unwrap();
+
+    assert!(fs::metadata(&setup.config.path).await.is_err());
+}
+
+#[tokio::test]
+async fn should_keep_only_configured_streams_in_memory() {
+    let setup = TestSetup::init().await;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.clone(),
+        storage: Arc::new(StorageConfig {
+            kind: StorageKind::File,
+            memory_streams: vec![2],
+        }),
+        ..Default::default()
+    });
+    let mut system = System::create(config.clone());
+    system.init().await.unwrap();
+
+    system.create_stream(Some(1), "file").await.unwrap();
+    system.create_stream(Some(2), "memory").await.unwrap();
+
+    assert_persisted_stream(&system.streams_path, 1).await;
+    let stream_path = system
+        .get_stream(&Identifier::numeric(2))
+        .unwrap()
+        .path
+        .clone();
+    assert!(fs::metadata(stream_path).await.is_err());
+
+    let mut system = System::create(config);
+    system.init().await.unwrap();
+    assert!(system.get_stream(&Identifier::numeric(1)).is_ok());
+    assert!(system.get_stream(&Identifier::numeric(2)).is_err());
+}
+
+#[tokio::test]
+async fn should_assign_stream_id_and_get_stream_by_name() {
+    let setup = TestSetup::init().await;
+    let mut system = System::create(setup.config.clone());
+    system.init().await.unwrap();
+
+    system.create_stream(Some(5), "first").await.unwrap();
+    system.create_stream(None, "second").await.unwrap();
+
+    let stream = system.get_stream(&Identifier::named("second")).unwrap();
+    assert_eq!(stream.id, 6);
+    assert_persisted_stream(&system.streams_path, 6).await;
+
+    system
+        .update_stream(&Identifier::named("second"), "renamed")
+        .await
+        .unwrap();
+    assert!(matches!(
+        system.get_stream(&Identifier::named("second")),
+        Err(Error::StreamNameNotFound(_))
+    ));
+
+    let mut system = System::create(setup.config.clone());
+    system.init().await.unwrap();
+    let stream = system.get_stream(&Identifier::named("renamed")).unwrap();
+    assert_eq!(stream.id, 6);
+    assert_eq!(
+        system.get_stream_id(&Identifier::named("first")).unwrap(),
+        5
+    );
+}
+
+#[tokio::test]
+async fn should_not_create_stream_given_existing_name() {
+    let setup = TestSetup::init().await;
+    let mut system = System::create(setup.config.clone());
+    system.init().await.unwrap();
+    system.create_stream(Some(1), "test").await.unwrap();
+
+    let result = system.create_stream(Some(2), "test").await;
+
+    assert!(matches!(result, Err(Error::StreamNameAlreadyExists(_))));
+    assert!(system.get_stream(&Identifier::numeric(2)).is_err());
+}
+
+#[tokio::test]
+async fn should_assign_topic_id_and_get_topic_by_name() {
+    let setup = TestSetup::init().await;
+    let mut system = System::create(setup.config.clone());
+    system.init().await.unwrap();
+    system.create_stream(None, "test").await.unwrap();
+    let stream = system.get_stream_mut(&Identifier::named("test")).unwrap();
+    for name in ["first", "second"] {
+        stream
+            .create_topic(
+                None,
+                name,
+                1,
+                None,
+                None,
+                CleanupPolicy::default(),
+                CompressionAlgorithm::default(),
+                None,
+                ChecksumPolicy::default(),
+                TopicConfigOverrides::default(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let result = stream
+        .create_topic(
+            Some(3),
+            "first",
+            1,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::TopicNameAlreadyExists(_, _))));
+    assert_eq!(
+        stream.get_topic(&Identifier::named("second")).unwrap().id,
+        2
+    );
+
+    let mut system = System::create(setup.config.clone());
+    system.init().await.unwrap();
+    let stream = system.get_stream(&Identifier::named("test")).unwrap();
+    assert_eq!(stream.get_topic(&Identifier::named("first")).unwrap().id, 1);
+    assert!(matches!(
+        stream.get_topic(&Identifier::named("third")),
+        Err(Error::TopicNameNotFound(_, _))
+    ));
+}
+
+#[tokio::test]
+async fn should_migrate_v1_storage_and_load_streams_topics_and_messages() {
+    let setup = TestSetup::init().await;
+    // The fixture was created by the server before the storage version was introduced.
+    let fixture_path = format!("{}/tests/fixtures/v1_storage", env!("CARGO_MANIFEST_DIR"));
+    copy_directory(Path::new(&fixture_path), Path::new(&setup.config.path)).await;
+    // The empty directories aren't kept in the fixture.
+    let topics_path = format!("{}/streams/1/topics", setup.config.path);
+    for partition_path in ["1/partitions/1", "1/partitions/2", "2/partitions/1"] {
+        for offsets_path in ["offsets/consumers", "offsets/groups"] {
+            fs::create_dir_all(format!(
+                "{}/{}/{}",
+                topics_path, partition_path, offsets_path
+            ))
+            .await
+            .unwrap();
+        }
+    }
+    for topic_id in [1, 2] {
+        fs::create_dir_all(format!("{}/{}/groups", topics_path, topic_id))
+            .await
+            .unwrap();
+    }
+    let mut system = System::create(setup.config.clone());
+
+    system.init().await.unwrap();
+
+    let version = fs::read(format!("{}/version", setup.config.path))
+        .await
+        .unwrap();
+    assert_eq!(version, STORAGE_VERSION.to_le_bytes());
+    let stream = system.get_stream(&Identifier::named("baseline")).unwrap();
+    assert_eq!(stream.id, 1);
+    for (topic_id, topic_name, partitions_count) in
+        [(1, "orders-topic-long-name", 2), (2, "topic 1", 1)]
+    {
+        let topic = stream.get_topic(&Identifier::named(topic_name)).unwrap();
+        assert_eq!(topic.id, topic_id);
+        assert_eq!(topic.message_expiry, None);
+        assert_eq!(topic.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(topic.compression, CompressionAlgorithm::None);
+        assert_eq!(topic.checksum_policy, ChecksumPolicy::None);
+        assert_eq!(topic.overrides, TopicConfigOverrides::default());
+        assert_eq!(topic.get_partitions().len(), partitions_count);
+
+        let partition = get_partition(topic, 1).await;
+        let messages = partition.get_messages_by_offset(0, 10).await.unwrap();
+        let payloads = messages
+            .iter()
+            .map(|message| message.payload.as_ref())
+            .collect::<Vec<&[u8]>>();
+        assert_eq!(
+            payloads,
+            [
+                b"hello".as_ref(),
+                b"world".as_ref(),
+                b"lorem ipsum".as_ref()
+            ]
+        );
+        for (offset, message) in messages.iter().enumerate() {
+            assert_eq!(message.offset, offset as u64);
+            assert_eq!(message.id, offset as u128 + 1);
+        }
+    }
+
+    let topic = stream.get_topic(&Identifier::numeric(1)).unwrap();
+    let partition = get_partition(topic, 1).await;
+    assert_eq!(
+        partition
+            .get_offset(PollingConsumer::Consumer(1))
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn should_not_create_topic_with_segment_size_override_exceeding_max_size() {
+    let setup = TestSetup::init().await;
+    let mut system = System::create(setup.config.clone());
+    system.init().await.unwrap();
+    system.create_stream(Some(1), "test").await.unwrap();
+    let stream = system.get_stream_mut(&Identifier::numeric(1)).unwrap();
+
+    let result = stream
+        .create_topic(
+            Some(1),
+            "test",
+            1,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides {
+                segment_size_bytes: Some(segment::MAX_SIZE_BYTES + 1),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::InvalidTopicConfigOverrides)));
+    assert!(stream.get_topic(&Identifier::numeric(1)).is_err());
+}
+
+async fn get_partition(topic: &Topic, partition_id: u32) -> RwLockReadGuard<'_, Partition> {
+    for partition in topic.get_partitions() {
+        let partition = partition.read().await;
+        if partition.id == partition_id {
+            return partition;
+        }
+    }
+
+    panic!("Partition with ID: {} not found.", partition_id);
+}
+
+async fn copy_directory(source_path: &Path, target_path: &Path) {
+    fs::create_dir_all(target_path).await.unwrap();
+    let mut dir_entries = fs::read_dir(source_path).await.unwrap();
+    while let Some(dir_entry) = dir_entries.next_entry().await.unwrap() {
+        let target_path = target_path.join(dir_entry.file_name());
+        if dir_entry.metadata().await.unwrap().is_dir() {
+            Box::pin(copy_directory(&dir_entry.path(), &target_path)).await;
+        } else {
+            fs::copy(dir_entry.path(), target_path).await.unwrap();
+        }
+    }
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
@@ -1,9 +1,23 @@
 mod common;
 
 use crate::common::TestSetup;
+use bytes::Bytes;
+use sdk::compression::CompressionAlgorithm;
+use sdk::error::Error;
+use sdk::messages::poll_messages::Kind;
+use sdk::messages::send_messages::KeyKind;
+use sdk::topics::create_topic::{
+    ChecksumPolicy, CleanupPolicy, DeduplicationWindow, TopicConfigOverrides,
+};
 use std::sync::Arc;
+use streaming::cache::MessagesCache;
+use streaming::config::{PartitionConfig, SegmentConfig, TopicConfig};
+use streaming::message::Message;
+use streaming::migration;
+use streaming::polling_consumer::PollingConsumer;
 use streaming::storage::SystemStorage;
 use streaming::topics::topic::{Topic, TOPIC_INFO};
+use streaming::utils::timestamp;
 use tokio::fs;
 
 #[tokio::test]
@@ -20,6 +34,13 @@ async fn should_persist_topics_with_partitions_directories_and_info_file() {
             topic_id,
             &name,
             partitions_count,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
             &setup.path,
             setup.config.stream.topic.clone(),
             storage.clone(),
@@ -37,6 +58,14 @@ async fn should_load_existing_topic_from_disk() {
     let storage = Arc::new(SystemStorage::default());
     let stream_id = 1;
     let partitions_count = 3;
+    let message_expiry = Some(10);
+    let max_topic_size_bytes = Some(1000);
+    let cleanup_policy = CleanupPolicy::Compact;
+    let compression = CompressionAlgorithm::Lz4;
+    let deduplication = Some(DeduplicationWindow {
+        max_ids: Some(1000),
+        expiry: Some(3600),
+    });
     let topic_ids = get_topic_ids();
     for topic_id in topic_ids {
         let name = format!("test-{}", topic_id);
@@ -45,6 +74,13 @@ async fn should_load_existing_topic_from_disk() {
             topic_id,
             &name,
             partitions_count,
+            message_expiry,
+            max_topic_size_bytes,
+            cleanup_policy,
+            compression,
+            deduplication,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
             &setup.path,
             setup.config.stream.topic.clone(),
             storage.clone(),
@@ -64,9 +100,216 @@ async fn should_load_existing_topic_from_disk() {
         assert_eq!(loaded_topic.stream_id, topic.stream_id);
         assert_eq!(loaded_topic.id, topic.id);
         assert_eq!(loaded_topic.name, topic.name);
+        assert_eq!(loaded_topic.message_expiry, topic.message_expiry);
+        assert_eq!(
+            loaded_topic.max_topic_size_bytes,
+            topic.max_topic_size_bytes
+        );
+        assert_eq!(loaded_topic.cleanup_policy, topic.cleanup_policy);
+        assert_eq!(loaded_topic.compression, topic.compression);
+        assert_eq!(loaded_topic.deduplication, topic.deduplication);
         assert_eq!(loaded_topic.path, topic.path);
         assert_eq!(loaded_topic.get_partitions().len() as u32, partitions_count);
+        for partition in loaded_topic.get_partitions() {
+            assert!(partition.read().await.deduplicator.is_some());
+        }
+    }
+}
+
+#[tokio::test]
+async fn should_migrate_v1_topic_info_containing_only_the_name() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let streams_path = format!("{}/streams", setup.path);
+    let topics_path = format!("{}/1/topics", streams_path);
+    fs::create_dir_all(&topics_path).await.unwrap();
+    for (id, name) in [(1, "short"), (2, "name-longer-than-the-header")] {
+        let topic = Topic::create(
+            1,
+            id,
+            name,
+            1,
+            None,
+            None,
+            CleanupPolicy::Delete,
+            CompressionAlgorithm::None,
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
+            &topics_path,
+            setup.config.stream.topic.clone(),
+            storage.clone(),
+        );
+        topic.persist().await.unwrap();
+        fs::write(format!("{}/{}", topic.path, TOPIC_INFO), name)
+            .await
+            .unwrap();
+    }
+
+    // The data directories created before the storage version was introduced have no version file,
+    // and the migration is run twice, as it might have been interrupted before the version was saved.
+    let version_path = format!("{}/version", setup.path);
+    for _ in 0..2 {
+        let _ = fs::remove_file(&version_path).await;
+        migration::migrate(&setup.path, &streams_path)
+            .await
+            .unwrap();
+    }
+
+    for (id, name) in [(1, "short"), (2, "name-longer-than-the-header")] {
+        let mut loaded_topic = Topic::empty(
+            1,
+            id,
+            &topics_path,
+            setup.config.stream.topic.clone(),
+            storage.clone(),
+        );
+        loaded_topic.load().await.unwrap();
+        assert_eq!(loaded_topic.name, name);
+        assert_eq!(loaded_topic.message_expiry, None);
+        assert_eq!(loaded_topic.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(loaded_topic.compression, CompressionAlgorithm::None);
+        assert_eq!(loaded_topic.deduplication, None);
+        assert_eq!(loaded_topic.checksum_policy, ChecksumPolicy::None);
+        assert_eq!(loaded_topic.overrides, TopicConfigOverrides::default());
+    }
+}
+
+#[tokio::test]
+async fn should_apply_config_overrides_to_partitions_and_load_them_from_disk() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let overrides = TopicConfigOverrides {
+        segment_size_bytes: Some(200),
+        messages_required_to_save: Some(1),
+        enforce_sync: Some(true),
+    };
+    let topic = Topic::create(
+        1,
+        1,
+        "test",
+        1,
+        None,
+        None,
+        CleanupPolicy::default(),
+        CompressionAlgorithm::default(),
+        None,
+        ChecksumPolicy::default(),
+        overrides,
+        &setup.path,
+        setup.config.stream.topic.clone(),
+        storage.clone(),
+    );
+    topic.persist().await.unwrap();
+    for i in 1..=5 {
+        let payload = Bytes::from(format!("{:0>100}", i));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        topic
+            .append_messages(KeyKind::PartitionId, 1, vec![message])
+            .await
+            .unwrap();
+    }
+    let segments_count = topic.get_partitions()[0].read().await.get_segments().len();
+    assert!(segments_count > 1);
+
+    let mut loaded_topic = Topic::empty(
+        1,
+        1,
+        &setup.path,
+        setup.config.stream.topic.clone(),
+        storage,
+    );
+    loaded_topic.load().await.unwrap();
+
+    assert_eq!(loaded_topic.overrides, overrides);
+    let partition_config = loaded_topic.get_partition_config();
+    assert_eq!(partition_config.segment.size_bytes, 200);
+    assert_eq!(partition_config.messages_required_to_save, 1);
+    assert!(partition_config.enforce_sync);
+    let partition = loaded_topic.get_partitions()[0].read().await;
+    assert_eq!(partition.get_segments().len(), segments_count);
+    assert_eq!(partition.current_offset, 4);
+}
+
+#[tokio::test]
+async fn should_update_existing_topic_settings_and_load_them_from_disk() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let stream_id = 1;
+    let topic_id = 1;
+    let partitions_count = 2;
+    let mut topic = Topic::create(
+        stream_id,
+        topic_id,
+        "test",
+        partitions_count,
+        None,
+        None,
+        CleanupPolicy::default(),
+        CompressionAlgorithm::default(),
+        None,
+        ChecksumPolicy::default(),
+        TopicConfigOverrides::default(),
+        &setup.path,
+        setup.config.stream.topic.clone(),
+        storage.clone(),
+    );
+    topic.persist().await.unwrap();
+    let messages = (1..=3)
+        .map(|id| Message::empty(timestamp::get(), id, Bytes::from("test")))
+        .collect();
+    topic
+        .append_messages(KeyKind::PartitionId, 1, messages)
+        .await
+        .unwrap();
+
+    let deduplication = Some(DeduplicationWindow {
+        max_ids: Some(1000),
+        expiry: None,
+    });
+    topic
+        .update(
+            "updated",
+            Some(3600),
+            Some(1000000),
+            CleanupPolicy::Compact,
+            CompressionAlgorithm::Lz4,
+            deduplication,
+            ChecksumPolicy::Skip,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(topic.name, "updated");
+    assert!(fs::metadata(format!("{}/{}.tmp", topic.path, TOPIC_INFO))
+        .await
+        .is_err());
+    let mut message_ids_count = 0;
+    for partition in topic.get_partitions() {
+        let partition = partition.read().await;
+        assert_eq!(partition.compression, CompressionAlgorithm::Lz4);
+        assert_eq!(partition.get_checksum_policy(), ChecksumPolicy::Skip);
+        message_ids_count += partition.deduplicator.as_ref().unwrap().get_ids_count();
     }
+    assert_eq!(message_ids_count, 3);
+
+    let mut loaded_topic = Topic::empty(
+        stream_id,
+        topic_id,
+        &setup.path,
+        setup.config.stream.topic.clone(),
+        storage.clone(),
+    );
+    loaded_topic.load().await.unwrap();
+
+    assert_eq!(loaded_topic.name, "updated");
+    assert_eq!(loaded_topic.message_expiry, Some(3600));
+    assert_eq!(loaded_topic.max_topic_size_bytes, Some(1000000));
+    assert_eq!(loaded_topic.cleanup_policy, CleanupPolicy::Compact);
+    assert_eq!(loaded_topic.compression, CompressionAlgorithm::Lz4);
+    assert_eq!(loaded_topic.deduplication, deduplication);
+    assert_eq!(loaded_topic.checksum_policy, ChecksumPolicy::Skip);
+    assert_eq!(loaded_topic.get_partitions_count(), partitions_count);
 }
 
 #[tokio::test]
@@ -83,6 +326,13 @@ async fn should_delete_existing_topic_from_disk() {
             topic_id,
             &name,
             partitions_count,
+            None,
+            None,
+            CleanupPolicy::default(),
+            CompressionAlgorithm::default(),
+            None,
+            ChecksumPolicy::default(),
+            TopicConfigOverrides::default(),
             &setup.path,
             setup.config.stream.topic.clone(),
             storage.clone(),
@@ -96,6 +346,354 @@ async fn should_delete_existing_topic_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_delete_oldest_segments_when_topic_exceeds_max_size() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let config = Arc::new(TopicConfig {
+        partition: Arc::new(PartitionConfig {
+            messages_required_to_save: 1,
+            segment: Arc::new(SegmentConfig {
+                size_bytes: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let topic = Topic::create(
+        1,
+        1,
+        "test",
+        1,
+        None,
+        Some(200),
+        CleanupPolicy::default(),
+        CompressionAlgorithm::default(),
+        None,
+        ChecksumPolicy::default(),
+        TopicConfigOverrides::default(),
+        &setup.path,
+        config,
+        storage,
+    );
+    topic.persist().await.unwrap();
+    for i in 1..=3 {
+        let payload = Bytes::from(format!("message {}", i));
+        let message = Message::empty(timestamp::get(), i as u128, payload);
+        topic
+            .append_messages(KeyKind::PartitionId, 1, vec![message])
+            .await
+            .unwrap();
+    }
+    assert!(topic.get_size_bytes().await > 200);
+
+    let deleted_segments = topic.delete_oldest_segments().await.unwrap();
+
+    assert_eq!(deleted_segments, 1);
+    assert!(topic.get_size_bytes().await <= 200);
+    let partition = topic.get_partitions()[0].read().await;
+    assert_eq!(partition.get_segments().len(), 2);
+    assert_eq!(partition.get_segments()[0].start_offset, 1);
+}
+
+#[tokio::test]
+async fn should_load_messages_of_last_segments_to_cache_and_remove_them_once_topic_is_deleted() {
+    let setup = TestSetup::init().await;
+    let partitions_count = 2;
+    let messages_count = 10;
+    let config = Arc::new(TopicConfig {
+        partition: Arc::new(PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let topic = Topic::create(
+        1,
+        1,
+        "test",
+        partitions_count,
+        None,
+        None,
+        CleanupPolicy::default(),
+        CompressionAlgorithm::default(),
+        None,
+        ChecksumPolicy::default(),
+        TopicConfigOverrides::default(),
+        &setup.path,
+        config.clone(),
+        Arc::new(SystemStorage::default()),
+    );
+    topic.persist().await.unwrap();
+    for partition_id in 1..=partitions_count {
+        for i in 1..=messages_count {
+            let payload = Bytes::from(format!("message {}", i));
+            let message = Message::empty(timestamp::get(), i as u128, payload);
+            topic
+                .append_messages(KeyKind::PartitionId, partition_id, vec![message])
+                .await
+                .unwrap();
+        }
+    }
+
+    let cache = Arc::new(MessagesCache::new(1024 * 1024));
+    let storage = Arc::new(SystemStorage {
+        cache: cache.clone(),
+        ..Default::default()
+    });
+    let mut loaded_topic = Topic::empty(1, 1, &setup.path, config, storage);
+    loaded_topic.load().await.unwrap();
+
+    assert_eq!(
+        cache.get_messages_count(),
+        (partitions_count * messages_count) as u64
+    );
+    for partition_id in 1..=partitions_count {
+        let messages = loaded_topic
+            .get_messages(
+                PollingConsumer::Consumer(1),
+                partition_id,
+                Kind::Offset,
+                0,
+                messages_count,
+            )
+            .await
+            .unwrap();
+        assert_eq!(messages.len() as u32, messages_count);
+    }
+    assert_eq!(cache.get_hits(), partitions_count as u64);
+    assert_eq!(cache.get_misses(), 0);
+
+    loaded_topic.delete().await.unwrap();
+
+    assert_eq!(cache.get_messages_count(), 0);
+    assert_eq!(cache.get_size_bytes(), 0);
+}
+
+#[tokio::test]
+async fn should_create_partitions_for_existing_topic_and_reassign_consumer_groups_partitions() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let partitions_count = 2;
+    let deduplication = Some(DeduplicationWindow {
+        max_ids: Some(1000),
+        expiry: None,
+    });
+    let mut topic = Topic::create(
+        1,
+        1,
+        "test",
+        partitions_count,
+        None,
+        None,
+        CleanupPolicy::default(),
+        CompressionAlgorithm::default(),
+        deduplication,
+        ChecksumPolicy::default(),
+        TopicConfigOverrides::default(),
+        &setup.path,
+        setup.config.stream.topic.clone(),
+        storage.clone(),
+    );
+    topic.persist().await.unwrap();
+    topic.create_consumer_group(1).await.unwrap();
+    topic.join_consumer_group(1, 1).await.unwrap();
+    topic.join_consumer_group(1, 2).await.unwrap();
+
+    let previous_partitions_count = topic.create_partitions(2).await.unwrap();
+
+    let partitions_count = partitions_count + 2;
+    assert_eq!(previous_partitions_count, 2);
+    assert_eq!(topic.get_partitions_count(), partitions_count);
+    assert_persisted_topic(&topic.path, &topic.get_partitions_path(), partitions_count).await;
+    for partition in topic.get_partitions() {
+        let partition = partition.read().await;
+        assert_eq!(partition.get_segments().len(), 1);
+        assert!(partition.deduplicator.is_some());
+    }
+
+    let consumer_group = topic.get_consumer_group(1).unwrap().read().await;
+    assert_eq!(consumer_group.partitions_count, partitions_count);
+    let mut members_partitions = Vec::new();
+    for member in consumer_group.get_members() {
+        let member_partitions = member.read().await.get_partitions();
+        assert_eq!(member_partitions.len(), 2);
+        members_partitions.extend(member_partitions);
+    }
+    members_partitions.sort();
+    assert_eq!(members_partitions, vec![1, 2, 3, 4]);
+    drop(consumer_group);
+
+    let entity_id = 3;
+    let message = Message::empty(timestamp::get(), 1, Bytes::from("message"));
+    topic
+        .append_messages(KeyKind::EntityId, entity_id, vec![message])
+        .await
+        .unwrap();
+    let messages = topic
+        .get_messages(PollingConsumer::Consumer(1), 3, Kind::Offset, 0, 1)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 1);
+
+    assert!(topic.create_partitions(0).await.is_err());
+
+    let mut loaded_topic = Topic::empty(
+        1,
+        1,
+        &setup.path,
+        setup.config.stream.topic.clone(),
+        storage,
+    );
+    loaded_topic.load().await.unwrap();
+    assert_eq!(loaded_topic.get_partitions_count(), partitions_count);
+    let consumer_group = loaded_topic.get_consumer_group(1).unwrap().read().await;
+    assert_eq!(consumer_group.partitions_count, partitions_count);
+}
+
+#[tokio::test]
+async fn should_drain_partitions_and_delete_them_once_consumed_by_consumer_groups() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let partitions_count = 3;
+    let consumer_group_id = 1;
+    let config = Arc::new(TopicConfig {
+        partition: Arc::new(PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    let mut topic = Topic::create(
+        1,
+        1,
+        "test",
+        partitions_count,
+        None,
+        None,
+        CleanupPolicy::default(),
+        CompressionAlgorithm::default(),
+        None,
+        ChecksumPolicy::default(),
+        TopicConfigOverrides::default(),
+        &setup.path,
+        config.clone(),
+        storage.clone(),
+    );
+    topic.persist().await.unwrap();
+    topic
+        .create_consumer_group(consumer_group_id)
+        .await
+        .unwrap();
+    for partition_id in 2..=partitions_count {
+        let message = Message::empty(timestamp::get(), 1, Bytes::from("message"));
+        topic
+            .append_messages(KeyKind::PartitionId, partition_id, vec![message])
+            .await
+            .unwrap();
+    }
+
+    assert!(topic.delete_partitions(partitions_count).await.is_err());
+    let deleted_partitions = topic.delete_partitions(2).await.unwrap();
+
+    assert_eq!(deleted_partitions, 0);
+    assert_eq!(topic.get_partitions_count(), partitions_count);
+    assert_eq!(topic.get_active_partitions_count().await, 1);
+    let message = Message::empty(timestamp::get(), 2, Bytes::from("message"));
+    assert!(topic
+        .append_messages(KeyKind::PartitionId, 3, vec![message])
+        .await
+        .is_err());
+    let message = Message::empty(timestamp::get(), 3, Bytes::from("message"));
+    topic
+        .append_messages(KeyKind::EntityId, 3, vec![message])
+        .await
+        .unwrap();
+    let messages = topic
+        .get_messages(PollingConsumer::Consumer(1), 1, Kind::Offset, 0, 1)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 1);
+
+    let consumer = PollingConsumer::ConsumerGroup(consumer_group_id, 1);
+    topic.store_offset(consumer, 3, 0).await.unwrap();
+    let deleted_partitions = topic.delete_drained_partitions().await.unwrap();
+
+    assert_eq!(deleted_partitions, 1);
+    assert_eq!(topic.get_partitions_count(), 2);
+    assert!(fs::metadata(format!("{}/3", topic.get_partitions_path()))
+        .await
+        .is_err());
+    let consumer_group = topic.get_consumer_group(consumer_group_id).unwrap();
+    assert_eq!(consumer_group.read().await.partitions_count, 2);
+
+    let mut loaded_topic = Topic::empty(1, 1, &setup.path, config, storage);
+    loaded_topic.load().await.unwrap();
+    assert_eq!(loaded_topic.get_partitions_count(), 2);
+    assert_eq!(loaded_topic.get_active_partitions_count().await, 1);
+
+    loaded_topic.store_offset(consumer, 2, 0).await.unwrap();
+    let deleted_partitions = loaded_topic.delete_drained_partitions().await.unwrap();
+
+    assert_eq!(deleted_partitions, 1);
+    assert_eq!(loaded_topic.get_partitions_count(), 1);
+    let consumer_group = loaded_topic.get_consumer_group(consumer_group_id).unwrap();
+    assert_eq!(consumer_group.read().await.partitions_count, 1);
+}
+
+#[tokio::test]
+async fn should_not_create_partitions_while_partitions_are_draining() {
+    let setup = TestSetup::init().await;
+    let storage = Arc::new(SystemStorage::default());
+    let consumer_group_id = 1;
+    let mut topic = Topic::create(
+        1,
+        1,
+        "test",
+        2,
+        None,
+        None,
+        CleanupPolicy::default(),
+        CompressionAlgorithm::default(),
+        None,
+        ChecksumPolicy::default(),
+        TopicConfigOverrides::default(),
+        &setup.path,
+        setup.config.stream.topic.clone(),
+        storage,
+    );
+    topic.persist().await.unwrap();
+    topic
+        .create_consumer_group(consumer_group_id)
+        .await
+        .unwrap();
+    let message = Message::empty(timestamp::get(), 1, Bytes::from("message"));
+    topic
+        .append_messages(KeyKind::PartitionId, 2, vec![message])
+        .await
+        .unwrap();
+    assert_eq!(topic.delete_partitions(1).await.unwrap(), 0);
+
+    let result = topic.create_partitions(1).await;
+
+    assert!(matches!(result, Err(Error::TopicPartitionsDraining(1, 1))));
+    assert_eq!(topic.get_partitions_count(), 2);
+    assert_eq!(topic.get_active_partitions_count().await, 1);
+
+    let consumer = PollingConsumer::ConsumerGroup(consumer_group_id, 1);
+    topic.store_offset(consumer, 2, 0).await.unwrap();
+    assert_eq!(topic.delete_drained_partitions().await.unwrap(), 1);
+    assert_eq!(topic.create_partitions(1).await.unwrap(), 1);
+    assert_eq!(topic.get_partitions_count(), 2);
+    assert_eq!(topic.get_active_partitions_count().await, 2);
+    let message = Message::empty(timestamp::get(), 2, Bytes::from("message"));
+    topic
+        .append_messages(KeyKind::PartitionId, 2, vec![message])
+        .await
+        .unwrap();
+}
+
 async fn assert_persisted_topic(topic_path: &str, partitions_path: &str, partitions_count: u32) {
     let topic_metadata = fs::metadata(topic_path).await.unwrap();
     assert!(topic_metadata.is_dir());